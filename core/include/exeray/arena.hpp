@@ -1,9 +1,11 @@
 #pragma once
 
+#include <array>
 #include <atomic>
 #include <cstddef>
 #include <cstdint>
 #include <limits>
+#include <mutex>
 #include <new>
 
 namespace exeray {
@@ -13,15 +15,28 @@ namespace exeray {
 /// @note Thread-safe. Uses atomic compare-exchange for lock-free allocation.
 ///       For bulk allocations, consider reserving slots atomically before 
 ///       writing (see EventGraph::push).
+///
+/// The arena can be grown with grow(), which chains an additional block
+/// instead of reallocating, so existing allocations never move. Offsets
+/// form one virtual range across all blocks: use at() and offset_of() to
+/// translate between offsets and pointers.
 class Arena {
 public:
+    /// @brief Maximum number of blocks (the initial one plus grown ones).
+    static constexpr std::size_t kMaxBlocks = 32;
+
     explicit Arena(std::size_t capacity)
         : base_(static_cast<std::uint8_t*>(
               ::operator new(capacity, std::align_val_t{64}))),
-          capacity_(capacity) {}
+          capacity_(capacity) {
+        blocks_[0] = Block{base_, 0, capacity};
+    }
 
     ~Arena() {
-        ::operator delete(base_, std::align_val_t{64});
+        const auto count = block_count_.load(std::memory_order_acquire);
+        for (std::size_t i = 0; i < count; ++i) {
+            ::operator delete(blocks_[i].base, std::align_val_t{64});
+        }
     }
 
     Arena(const Arena&) = delete;
@@ -47,25 +62,113 @@ public:
             aligned_offset = (current + align - 1) & ~(align - 1);
             new_offset = aligned_offset + size;
 
-            if (new_offset > capacity_) {
+            if (new_offset > capacity_.load(std::memory_order_acquire)) {
                 return nullptr;
             }
         } while (!offset_.compare_exchange_weak(current, new_offset,
                                                  std::memory_order_release,
                                                  std::memory_order_relaxed));
 
-        return reinterpret_cast<T*>(base_ + aligned_offset);
+        return reinterpret_cast<T*>(at(aligned_offset));
+    }
+
+    /// @brief Add a block of at least @p additional bytes.
+    ///
+    /// Allocations continue in the new block; the unused tail of the
+    /// previous block is abandoned. Existing allocations stay valid.
+    ///
+    /// @return false if the block limit is reached or allocation fails.
+    bool grow(std::size_t additional) {
+        std::lock_guard lock(grow_mutex_);
+
+        const auto count = block_count_.load(std::memory_order_acquire);
+        if (additional == 0 || count >= kMaxBlocks) {
+            return false;
+        }
+
+        // Keep block starts cache-line aligned so alignment carries over
+        const auto start = (capacity_.load(std::memory_order_acquire) + 63) & ~std::size_t{63};
+        if (additional > (std::numeric_limits<std::size_t>::max)() - start) {
+            return false;
+        }
+
+        auto* base = static_cast<std::uint8_t*>(
+            ::operator new(additional, std::align_val_t{64}, std::nothrow));
+        if (base == nullptr) {
+            return false;
+        }
+
+        blocks_[count] = Block{base, start, additional};
+        block_count_.store(count + 1, std::memory_order_release);
+
+        // Move the bump pointer into the new block before exposing its space
+        std::size_t current = offset_.load(std::memory_order_relaxed);
+        while (current < start &&
+               !offset_.compare_exchange_weak(current, start,
+                                              std::memory_order_release,
+                                              std::memory_order_relaxed)) {
+        }
+        capacity_.store(start + additional, std::memory_order_release);
+        return true;
+    }
+
+    /// @brief Translate a virtual offset into a pointer.
+    [[nodiscard]] std::uint8_t* at(std::size_t offset) const noexcept {
+        const auto count = block_count_.load(std::memory_order_acquire);
+        for (std::size_t i = count; i-- > 1;) {
+            if (offset >= blocks_[i].start) {
+                return blocks_[i].base + (offset - blocks_[i].start);
+            }
+        }
+        return base_ + offset;
+    }
+
+    /// @brief Translate a pointer returned by allocate() into its offset.
+    [[nodiscard]] std::size_t offset_of(const void* ptr) const noexcept {
+        const auto* p = static_cast<const std::uint8_t*>(ptr);
+        const auto count = block_count_.load(std::memory_order_acquire);
+        for (std::size_t i = 1; i < count; ++i) {
+            const auto& block = blocks_[i];
+            if (p >= block.base && p < block.base + block.capacity) {
+                return block.start + static_cast<std::size_t>(p - block.base);
+            }
+        }
+        return static_cast<std::size_t>(p - base_);
+    }
+
+    /// @brief Reset to empty, releasing any grown blocks.
+    void reset() {
+        std::lock_guard lock(grow_mutex_);
+        const auto count = block_count_.load(std::memory_order_acquire);
+        for (std::size_t i = 1; i < count; ++i) {
+            ::operator delete(blocks_[i].base, std::align_val_t{64});
+        }
+        block_count_.store(1, std::memory_order_release);
+        capacity_.store(blocks_[0].capacity, std::memory_order_release);
+        offset_.store(0, std::memory_order_release);
     }
 
-    void reset() { offset_.store(0, std::memory_order_release); }
     std::size_t used() const { return offset_.load(std::memory_order_acquire); }
-    std::size_t capacity() const { return capacity_; }
+    std::size_t capacity() const { return capacity_.load(std::memory_order_acquire); }
     const std::uint8_t* base() const { return base_; }
 
+    /// @brief Number of blocks (1 until the arena is grown).
+    std::size_t blocks() const { return block_count_.load(std::memory_order_acquire); }
+
 private:
+    /// @brief One contiguous allocation covering [start, start + capacity).
+    struct Block {
+        std::uint8_t* base = nullptr;
+        std::size_t start = 0;
+        std::size_t capacity = 0;
+    };
+
     std::uint8_t* base_;
     std::atomic<std::size_t> offset_ = 0;
-    std::size_t capacity_;
+    std::atomic<std::size_t> capacity_;
+    std::array<Block, kMaxBlocks> blocks_{};
+    std::atomic<std::size_t> block_count_{1};
+    std::mutex grow_mutex_;
 };
 
 }
@@ -15,13 +15,17 @@
 #include "exeray/etw/consumer.hpp"
 #include "exeray/etw/session.hpp"
 #include "exeray/process/controller.hpp"
+#include "exeray/process/signature.hpp"
 #include "exeray/thread_pool.hpp"
 #include "exeray/types.hpp"
 #include <atomic>
 #include <chrono>
+#include <condition_variable>
 #include <cstdint>
 #include <memory>
 #include <mutex>
+#include <optional>
+#include <string>
 #include <string_view>
 #include <thread>
 #include <unordered_map>
@@ -40,6 +44,7 @@ struct ProviderConfig {
 struct EngineConfig {
     std::size_t arena_size = 0;   ///< Size of the memory arena in bytes.
     std::size_t num_threads = 0;  ///< Number of worker threads.
+    std::size_t event_capacity = event::EventGraph::kDefaultCapacity;  ///< Event slots in the graph.
     int log_level = 2;            ///< Log level: 0=trace, 1=debug, 2=info, 3=warn, 4=error.
     std::string log_file;         ///< Optional log file path (empty = stderr only).
 
@@ -59,12 +64,38 @@ struct EngineConfig {
                                                      std::size_t num_threads);
 };
 
+/// @brief Legacy task state read as one consistent unit.
+struct TaskState {
+    std::uint64_t generation = 0;  ///< Graph generation.
+    std::uint64_t flags = 0;       ///< StatusFlags bits.
+    float progress = 0.0f;         ///< Task progress in [0, 1].
+};
+
+/// @brief Snapshot of engine counters, read in a single call.
+struct EngineStats {
+    std::uint64_t event_count = 0;       ///< Live events in the graph.
+    std::uint64_t events_dropped = 0;    ///< Events rejected because the graph was full.
+    std::uint64_t buffers_lost = 0;      ///< ETW buffers lost by the session.
+    std::uint64_t busy_threads = 0;      ///< Worker threads running a task.
+    std::uint64_t queue_depth = 0;       ///< Tasks waiting for a worker.
+    std::uint64_t arena_used_bytes = 0;  ///< Arena bytes in use.
+};
+
 /// @brief Core engine integrating ETW tracing and process control.
 ///
 /// Thread-safety model:
 /// - EventGraph access is thread-safe (atomic push, shared mutex for iteration)
 /// - target_pid_ and monitoring_ are atomic for cross-thread access
-/// - ETW thread joins gracefully on stop_monitoring()
+/// - generation(), flags(), progress() and the graph count may be read from
+///   any thread; the generation is the graph's and advances on every
+///   committed event as well as on task completion
+/// - flags_ and progress_ are written under a seqlock (state_seq_ is odd
+///   while a write is in progress), so task_state() never returns a torn
+///   view; event commits advance the generation outside it but never touch
+///   flags or progress
+/// - ETW thread joins gracefully on stop_monitoring() or at the deadline
+/// - the targets are guarded by target_mutex_, the session and its provider
+///   list by providers_mutex_
 class Engine {
 public:
     explicit Engine(EngineConfig config);
@@ -84,21 +115,79 @@ public:
     ///
     /// Launches the executable in suspended mode, creates an ETW session,
     /// enables kernel providers, starts the ETW consumer thread, then resumes
-    /// the target process.
+    /// the target process (unless freeze-on-start is enabled).
     ///
     /// @param exe_path Path to the executable to launch and monitor.
     /// @return true if monitoring started successfully, false on failure.
     bool start_monitoring(std::wstring_view exe_path);
 
+    /// @brief Start monitoring several targets under one ETW session.
+    ///
+    /// All targets are launched suspended before tracing starts; if any
+    /// launch fails the others are terminated. The first target is the
+    /// primary one reported by target_pid() and used for thread and output
+    /// queries; freeze, unfreeze and kill apply to all of them. Events are
+    /// tagged with the PID of the process that emitted them.
+    ///
+    /// @param exe_paths Executables to launch and monitor.
+    /// @return Target PIDs in launch order, or empty on failure.
+    std::vector<uint32_t> start_monitoring_multi(const std::vector<std::wstring>& exe_paths);
+
     /// @brief Stop monitoring and terminate the target process.
     ///
     /// Stops the ETW session (unblocks ProcessTrace), joins the consumer
-    /// thread, and terminates the target process if still running.
+    /// thread, and terminates the target process if still running. Also
+    /// releases the targets of a session that already hit its deadline.
     void stop_monitoring();
 
     /// @brief Check if currently monitoring a process.
     [[nodiscard]] bool is_monitoring() const noexcept;
 
+    /// @brief Force buffered ETW events into the graph.
+    ///
+    /// Flushes the session buffers, then waits briefly until the consumer
+    /// stops committing new events. Call before snapshots or exports so
+    /// the capture is complete.
+    ///
+    /// @return Number of events committed while flushing (0 if not monitoring).
+    std::size_t flush();
+
+    /// @brief Keep the target suspended after launch.
+    ///
+    /// When enabled, start_monitoring() leaves the target frozen at its entry
+    /// point so the very first events can be captured; call unfreeze_target()
+    /// to let it run. Takes effect on the next start_monitoring() call.
+    ///
+    /// @param enabled Whether to freeze the target on start.
+    void set_freeze_on_start(bool enabled) noexcept;
+
+    /// @brief Check if freeze-on-start is enabled.
+    [[nodiscard]] bool freeze_on_start() const noexcept;
+
+    /// @brief Check if the targets are still held at entry by freeze-on-start.
+    ///
+    /// Unlike target_frozen(), a later freeze_target() or breakpoint does not
+    /// set this; the first unfreeze_target() clears it for the session.
+    [[nodiscard]] bool frozen_at_entry() const noexcept;
+
+    /// @brief Redirect the target's stdout/stderr to a pipe at launch.
+    ///
+    /// Only applies to targets launched by start_monitoring(); takes effect
+    /// on the next call.
+    ///
+    /// @param enabled Whether to capture target output.
+    void set_capture_output(bool enabled) noexcept;
+
+    /// @brief Check if output capture is enabled.
+    [[nodiscard]] bool capture_output() const noexcept;
+
+    /// @brief Check if the current target's output is being captured.
+    [[nodiscard]] bool capturing_target_output() const noexcept;
+
+    /// @brief Take the target output lines read since the last call.
+    /// @return Lines oldest first; empty if no target output is captured.
+    [[nodiscard]] std::vector<std::string> take_target_output() const;
+
     // -------------------------------------------------------------------------
     // Process Control (forwarded to Controller)
     // -------------------------------------------------------------------------
@@ -116,6 +205,248 @@ public:
     /// @return PID of the target, or 0 if not monitoring.
     [[nodiscard]] uint32_t target_pid() const noexcept;
 
+    /// @brief Check if the target is currently frozen.
+    /// @return true if the target is suspended by freeze-on-start,
+    ///         freeze_target(), or a breakpoint.
+    [[nodiscard]] bool target_frozen() const noexcept;
+
+    /// @brief Check whether the primary target has exited.
+    ///
+    /// True once the monitored target process is dead (while still
+    /// monitoring), or after simulate_target_exit(). Reset by the next
+    /// start_monitoring().
+    [[nodiscard]] bool target_exited() const;
+
+    /// @brief Record a target exit without a real process (mock path).
+    /// @param pid PID of the exited target.
+    /// @param exit_code Exit code to report.
+    void simulate_target_exit(uint32_t pid, uint32_t exit_code);
+
+    /// @brief Check whether kill_target() was called this session.
+    ///
+    /// Tells a user kill apart from the target failing on its own. Reset by
+    /// the next start_monitoring().
+    [[nodiscard]] bool target_killed() const noexcept;
+
+    /// @brief Get the PID of the exited target (0 if none exited).
+    [[nodiscard]] uint32_t exit_pid() const;
+
+    /// @brief Get the exit code of the exited target (0 if none exited).
+    [[nodiscard]] uint32_t exit_code() const;
+
+    /// @brief Enumerate the target's threads.
+    /// @return Thread snapshot, empty if not monitoring.
+    [[nodiscard]] std::vector<process::ThreadInfo> target_threads() const;
+
+    /// @brief Read the command line the OS recorded for the target.
+    /// @return UTF-8 command line, empty if not monitoring or unreadable.
+    [[nodiscard]] std::string target_command_line() const;
+
+    /// @brief Read the primary target's current directory from its PEB.
+    /// @return UTF-8 path, empty if not monitoring or unreadable.
+    [[nodiscard]] std::string target_working_directory() const;
+
+    /// @brief Read the primary target's environment block from its PEB.
+    /// @return UTF-8 "NAME=value" entries, empty if not monitoring or unreadable.
+    [[nodiscard]] std::vector<std::string> target_environment() const;
+
+    /// @brief Get the primary target's kernel and user CPU time.
+    /// @return Times, or std::nullopt if not monitoring or the query fails.
+    [[nodiscard]] std::optional<process::CpuTimes> target_cpu_times() const;
+
+    /// @brief Get the primary target's token integrity level.
+    /// @return Level, or std::nullopt if not monitoring or the query fails.
+    [[nodiscard]] std::optional<process::IntegrityLevel> target_integrity_level() const;
+
+    /// @brief Get the primary target's Authenticode signature.
+    ///
+    /// Looked up once when the target is launched.
+    ///
+    /// @return Signature status, or std::nullopt if not monitoring.
+    [[nodiscard]] std::optional<process::Signature> target_signature() const;
+
+    // -------------------------------------------------------------------------
+    // Breakpoints
+    // -------------------------------------------------------------------------
+
+    /// @brief Arm a one-shot breakpoint on an event category.
+    ///
+    /// The first event of this category freezes the target and records the
+    /// event ID, after which the breakpoint disarms itself. Arming replaces
+    /// any previous breakpoint and clears a previous hit.
+    ///
+    /// @param cat Category to break on.
+    void break_on(event::Category cat);
+
+    /// @brief Disarm the breakpoint and forget any recorded hit.
+    void clear_breakpoint();
+
+    /// @brief Get the event that triggered the breakpoint.
+    /// @return Event ID of the hit, or INVALID_EVENT if not hit.
+    [[nodiscard]] event::EventId breakpoint_hit() const noexcept;
+
+    /// @brief Freeze the target on the first Suspicious event.
+    ///
+    /// The event is recorded in its own slot, independent of the category
+    /// breakpoint; later Suspicious events do not freeze again until
+    /// clear_suspicious_hit() re-arms the guard. Events already in flight
+    /// when the freeze lands (other consumer threads, ETW buffers) are still
+    /// captured, including further Suspicious ones.
+    ///
+    /// @param enabled Whether to freeze on Suspicious events.
+    void set_freeze_on_suspicious(bool enabled) noexcept;
+
+    /// @brief Check if freeze-on-suspicious is enabled.
+    [[nodiscard]] bool freeze_on_suspicious() const noexcept;
+
+    /// @brief Get the Suspicious event that triggered the guard.
+    /// @return Event ID of the hit, or INVALID_EVENT if not hit.
+    [[nodiscard]] event::EventId suspicious_hit() const noexcept;
+
+    /// @brief Forget the guard's hit so the next Suspicious event freezes again.
+    void clear_suspicious_hit() noexcept;
+
+    /// @brief Count the freezes triggered by the per-event hooks.
+    ///
+    /// Counts breakpoint hits, suspicious guard hits and the event cap, whether
+    /// or not a target was running to suspend.
+    [[nodiscard]] std::uint64_t auto_freeze_count() const noexcept;
+
+    // -------------------------------------------------------------------------
+    // Capture Deadline
+    // -------------------------------------------------------------------------
+
+    /// @brief Limit how long a monitoring session captures.
+    ///
+    /// Applies to sessions started afterwards. When the time is up a
+    /// watchdog thread freezes the targets, stops the ETW session so no
+    /// further events arrive, and sets timed_out(); is_monitoring() turns
+    /// false. The frozen targets stay alive until stop_monitoring().
+    ///
+    /// @param limit Capture duration (zero = unlimited).
+    void set_max_duration(std::chrono::nanoseconds limit) noexcept;
+
+    /// @brief Get the configured capture duration.
+    /// @return Duration, or zero if unlimited.
+    [[nodiscard]] std::chrono::nanoseconds max_duration() const noexcept;
+
+    /// @brief Check if the current session hit its deadline.
+    [[nodiscard]] bool timed_out() const noexcept;
+
+    /// @brief Start a session without a target or ETW (mock path).
+    ///
+    /// Sets is_monitoring(), forgets the previous target's exit and arms
+    /// the deadline watchdog, so session handling can be exercised
+    /// offline. stop_monitoring() ends it.
+    void simulate_session();
+
+    // -------------------------------------------------------------------------
+    // Safety Cap
+    // -------------------------------------------------------------------------
+
+    /// @brief Cap the number of captured events.
+    ///
+    /// When the cap is reached the engine sets StatusFlags::ERRORED, freezes
+    /// the target and rejects further events. Mutually exclusive with ring
+    /// mode, which never stops ingesting.
+    ///
+    /// @param max_events Maximum events (0 = no cap beyond graph capacity).
+    void set_max_events(std::size_t max_events) noexcept;
+
+    /// @brief Get the configured cap.
+    /// @return Maximum events, or 0 if uncapped.
+    [[nodiscard]] std::size_t max_events() const noexcept;
+
+    /// @brief Check if the cap has been reached.
+    ///
+    /// Cleared again once clear_events_before() or evict_oldest_events()
+    /// frees space, so the next time the cap is hit freezes again.
+    [[nodiscard]] bool max_events_reached() const noexcept;
+
+    /// @brief Remove the oldest events timestamped before @p cutoff.
+    /// @return Number of events removed.
+    std::size_t clear_events_before(std::uint64_t cutoff);
+
+    /// @brief Remove up to @p n of the oldest events.
+    /// @return Number of events removed.
+    std::size_t evict_oldest_events(std::size_t n);
+
+    // -------------------------------------------------------------------------
+    // Event Injection
+    // -------------------------------------------------------------------------
+
+    /// @brief Push a synthetic event as if it came from the ETW consumer.
+    ///
+    /// The event carries an empty payload of the given category and runs
+    /// through the same per-event hooks (breakpoints, etc.) as real events.
+    /// Intended for tests and offline tooling on platforms without ETW.
+    ///
+    /// @param cat Event category.
+    /// @param op Category-specific operation code.
+    /// @param status Operation result status.
+    /// @param parent Parent event ID (INVALID_EVENT for root events).
+    /// @param target_pid PID to tag the event with (0 = untagged).
+    /// @return ID of the new event, or INVALID_EVENT if the graph is full.
+    event::EventId inject_event(event::Category cat, uint8_t op,
+                                event::Status status, event::EventId parent,
+                                uint32_t target_pid = 0);
+
+    /// @brief Push a synthetic FileSystem event carrying a file path.
+    ///
+    /// Like inject_event(), with the path interned into the payload.
+    ///
+    /// @param op FileOp code.
+    /// @param status Operation result status.
+    /// @param parent Parent event ID (INVALID_EVENT for root events).
+    /// @param path UTF-8 file path.
+    /// @return ID of the new event, or INVALID_EVENT if the graph is full.
+    event::EventId inject_file_event(uint8_t op, event::Status status,
+                                     event::EventId parent, std::string_view path);
+
+    /// @brief Push a synthetic Network event with a remote endpoint.
+    ///
+    /// The endpoint is stored in network byte order, as the TcpIp provider
+    /// delivers it.
+    ///
+    /// @param op NetOp code.
+    /// @param status Operation result status.
+    /// @param parent Parent event ID (INVALID_EVENT for root events).
+    /// @param remote_addr Remote IPv4 address in host byte order.
+    /// @param remote_port Remote port in host byte order.
+    /// @return ID of the new event, or INVALID_EVENT if the graph is full.
+    event::EventId inject_network_event(uint8_t op, event::Status status,
+                                        event::EventId parent, uint32_t remote_addr,
+                                        uint16_t remote_port);
+
+    /// @brief Push a synthetic Network event with an IPv6 remote endpoint.
+    ///
+    /// The address text is interned into the payload as-is.
+    ///
+    /// @param op NetOp code.
+    /// @param status Operation result status.
+    /// @param parent Parent event ID (INVALID_EVENT for root events).
+    /// @param remote_addr Remote IPv6 address, e.g. "2001:db8::1".
+    /// @param remote_port Remote port in host byte order.
+    /// @return ID of the new event, or INVALID_EVENT if the graph is full.
+    event::EventId inject_network6_event(uint8_t op, event::Status status,
+                                         event::EventId parent, std::string_view remote_addr,
+                                         uint16_t remote_port);
+
+    /// @brief Push a synthetic Process event with its PIDs and image path.
+    ///
+    /// Like inject_event(), with the image path interned into the payload.
+    ///
+    /// @param op ProcessOp code.
+    /// @param status Operation result status.
+    /// @param parent Parent event ID (INVALID_EVENT for root events).
+    /// @param pid Process ID the event describes.
+    /// @param parent_pid Process ID of its creator.
+    /// @param image_path UTF-8 executable path.
+    /// @return ID of the new event, or INVALID_EVENT if the graph is full.
+    event::EventId inject_process_event(uint8_t op, event::Status status,
+                                        event::EventId parent, uint32_t pid,
+                                        uint32_t parent_pid, std::string_view image_path);
+
     // -------------------------------------------------------------------------
     // Legacy Task API (for compatibility)
     // -------------------------------------------------------------------------
@@ -125,6 +456,13 @@ public:
     [[nodiscard]] std::uint64_t timestamp_ns() const;
     [[nodiscard]] std::uint64_t flags() const;
     [[nodiscard]] float progress() const;
+
+    /// @brief Read generation, flags and progress without a torn read.
+    ///
+    /// Retries while a write is in progress or the sequence moved, so task
+    /// completion's flags and generation are seen together or not at all.
+    [[nodiscard]] TaskState task_state() const;
+
     [[nodiscard]] bool idle() const;
     [[nodiscard]] std::size_t threads() const;
 
@@ -138,6 +476,37 @@ public:
     /// @brief Get const reference to the event graph.
     [[nodiscard]] const event::EventGraph& graph() const { return graph_; }
 
+    /// @brief Get the pool resolving payload StringIds.
+    [[nodiscard]] const event::StringPool& strings() const { return strings_; }
+
+    // -------------------------------------------------------------------------
+    // Arena
+    // -------------------------------------------------------------------------
+
+    /// @brief Grow the arena by chaining an additional block.
+    ///
+    /// Existing events and strings stay in place, so event IDs and indices
+    /// are unaffected. The event graph's node capacity is fixed at
+    /// construction; the added space serves string storage.
+    ///
+    /// @param additional_bytes Size of the new block in bytes.
+    /// @return true if the block was allocated.
+    bool grow_arena(std::size_t additional_bytes);
+
+    /// @brief Get the total arena capacity in bytes across all blocks.
+    [[nodiscard]] std::size_t arena_capacity() const noexcept;
+
+    /// @brief Get the number of arena bytes in use.
+    [[nodiscard]] std::size_t arena_used() const noexcept;
+
+    // -------------------------------------------------------------------------
+    // Statistics
+    // -------------------------------------------------------------------------
+
+    /// @brief Collect all engine counters.
+    /// @return Counter snapshot; buffers_lost is 0 when not monitoring.
+    [[nodiscard]] EngineStats stats() const;
+
     // -------------------------------------------------------------------------
     // Event Correlation API
     // -------------------------------------------------------------------------
@@ -186,15 +555,74 @@ public:
     /// @return true if the provider exists and is enabled, false otherwise.
     [[nodiscard]] bool is_provider_enabled(std::string_view name) const;
 
+    /// @brief Enable or disable a provider, applying it to a live session.
+    ///
+    /// Updates the configuration like enable_provider()/disable_provider()
+    /// and, while monitoring, also enables or disables the provider on the
+    /// running ETW session. Events already captured are kept.
+    ///
+    /// @param name Provider name (e.g., "Process", "File", "DNS").
+    /// @param enabled Whether the provider should be enabled.
+    /// @return false if the provider is unknown or the session rejected it.
+    bool update_provider(std::string_view name, bool enabled);
+
+    /// @brief List the providers the running session actually enabled.
+    ///
+    /// Unlike is_provider_enabled(), this reflects the live session: providers
+    /// whose GUID is unknown or whose enable call failed are left out.
+    ///
+    /// @return Sorted provider names, empty if not monitoring.
+    [[nodiscard]] std::vector<std::string> enabled_providers() const;
+
 private:
+    /// @brief Push an injected event and run the per-event hooks.
+    event::EventId push_injected(const event::EventPayload& payload, uint8_t op,
+                                 event::Status status, event::EventId parent,
+                                 uint32_t target_pid);
+
     /// @brief Legacy background processing task.
     void process();
 
+    /// @brief Run `write` as one seqlock write section over the task state.
+    template <typename F>
+    void write_task_state(F&& write) {
+        std::lock_guard lock(state_mutex_);
+        state_seq_.fetch_add(1, std::memory_order_relaxed);
+        std::atomic_thread_fence(std::memory_order_release);
+        write();
+        state_seq_.fetch_add(1, std::memory_order_release);
+    }
+
+    /// @brief Freeze the targets on behalf of a per-event hook.
+    void auto_freeze();
+
+    /// @brief Per-event hook run after each event is pushed to the graph.
+    /// @param id ID of the new event.
+    void on_event(event::EventId id);
+
     /// @brief ETW consumer thread function.
     ///
     /// Calls start_trace_processing() which blocks until the session is stopped.
     void etw_thread_func();
 
+    /// @brief Start the deadline watchdog if a max duration is set.
+    void start_watchdog();
+
+    /// @brief Wake and join the watchdog before the session goes away.
+    void stop_watchdog();
+
+    /// @brief Freeze the targets and stop the session at the deadline.
+    void on_deadline();
+
+    /// @brief Forget the previous target's exit when a session starts.
+    void reset_target_exit();
+
+    /// @brief Stop the ETW session and join its consumer thread.
+    ///
+    /// Safe to call from the watchdog and stop_monitoring(); only the first
+    /// call of a session does anything. Leaves the targets alone.
+    void stop_session();
+
     // Core components
     Arena arena_;
     event::StringPool strings_;
@@ -202,22 +630,53 @@ private:
     event::Correlator correlator_;
     ThreadPool pool_;
 
-    // Legacy task state
-    std::atomic<std::uint64_t> generation_{0};
+    // Legacy task state, written through write_task_state()
     std::atomic<std::uint64_t> flags_{StatusFlags::IDLE};
     std::atomic<float> progress_{0.0f};
+    std::atomic<std::uint64_t> state_seq_{0};
+    std::mutex state_mutex_;
 
     // ETW monitoring state
     std::unique_ptr<etw::Session> etw_session_;
     std::unique_ptr<process::Controller> target_;
+    std::vector<std::unique_ptr<process::Controller>> extra_targets_;
+    std::mutex target_mutex_;  ///< Guards target_ and extra_targets_.
+    std::optional<process::Signature> target_signature_;
     std::thread etw_thread_;
     std::atomic<bool> monitoring_{false};
     std::atomic<uint32_t> target_pid_{0};
+    std::atomic<bool> freeze_on_start_{false};
+    std::atomic<bool> capture_output_{false};
+    std::atomic<bool> target_frozen_{false};
+    std::atomic<bool> frozen_at_entry_{false};
+    std::atomic<bool> target_exited_{false};
+    std::atomic<bool> target_killed_{false};
+    std::atomic<uint32_t> exit_pid_{0};
+    std::atomic<uint32_t> exit_code_{0};
     etw::ConsumerContext consumer_ctx_;
 
+    // Breakpoint state (armed category, or kNoBreakpoint)
+    static constexpr std::uint8_t kNoBreakpoint = 0xFF;
+    std::atomic<std::uint8_t> breakpoint_category_{kNoBreakpoint};
+    std::atomic<bool> freeze_on_suspicious_{false};
+    std::atomic<std::size_t> max_events_{0};
+    std::atomic<bool> max_events_reached_{false};
+    std::atomic<event::EventId> breakpoint_hit_{event::INVALID_EVENT};
+    std::atomic<event::EventId> suspicious_hit_{event::INVALID_EVENT};
+    std::atomic<std::uint64_t> auto_freezes_{0};
+
+    // Capture deadline
+    std::atomic<std::int64_t> max_duration_ns_{0};
+    std::atomic<bool> timed_out_{false};
+    std::thread watchdog_thread_;
+    std::mutex watchdog_mutex_;
+    std::condition_variable watchdog_cv_;
+    bool watchdog_stop_ = false;  ///< Guarded by watchdog_mutex_.
+
     // Provider configuration
     EngineConfig config_;
-    mutable std::mutex providers_mutex_;
+    std::vector<std::string> session_providers_;
+    mutable std::mutex providers_mutex_;  ///< Also guards etw_session_.
 };
 
 }  // namespace exeray
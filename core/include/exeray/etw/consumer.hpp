@@ -17,6 +17,8 @@
 
 #include <atomic>
 #include <cstdint>
+#include <functional>
+#include <vector>
 
 namespace exeray {
 namespace event {
@@ -38,11 +40,21 @@ struct ConsumerContext {
     /// @brief Atomic target PID for filtering (0 = no filter).
     std::atomic<uint32_t>* target_pid = nullptr;
 
+    /// @brief Additional target PIDs accepted by the filter.
+    ///
+    /// Set before the consumer thread starts and cleared after it joins.
+    std::vector<uint32_t> extra_target_pids;
+
     /// @brief Pointer to the string pool for interning paths/strings.
     event::StringPool* strings = nullptr;
 
     /// @brief Pointer to the correlator for building event chains.
     event::Correlator* correlator = nullptr;
+
+    /// @brief Hook invoked with the ID of each event pushed to the graph.
+    ///
+    /// Runs on the ETW consumer thread; must not block.
+    std::function<void(std::uint64_t)> on_event;
 };
 
 /// @brief ETW event record callback function.
@@ -71,6 +83,8 @@ ULONG start_trace_processing(TRACEHANDLE trace_handle);
 // Stub declarations for non-Windows platforms
 #include <atomic>
 #include <cstdint>
+#include <functional>
+#include <vector>
 
 namespace exeray {
 namespace event {
@@ -84,8 +98,10 @@ namespace etw {
 struct ConsumerContext {
     event::EventGraph* graph = nullptr;
     std::atomic<uint32_t>* target_pid = nullptr;
+    std::vector<uint32_t> extra_target_pids;
     event::StringPool* strings = nullptr;
     event::Correlator* correlator = nullptr;
+    std::function<void(std::uint64_t)> on_event;
 };
 
 /// @brief Stub callback for non-Windows.
@@ -12,8 +12,10 @@
 #include <evntrace.h>
 #include <evntcons.h>
 
+#include <array>
 #include <cstdint>
 #include <cstring>
+#include <string>
 #include <string_view>
 
 #include "exeray/event/types.hpp"
@@ -62,6 +64,58 @@ inline std::wstring_view extract_wstring(const uint8_t* data, size_t max_len) {
     return {wdata, len};
 }
 
+/// @brief Format a 16-byte IPv6 address as text (RFC 5952).
+/// @param bytes Address in network byte order.
+/// @return Lower-case text with the longest zero run compressed to "::".
+inline std::string format_ipv6(const uint8_t* bytes) {
+    std::array<uint16_t, 8> groups{};
+    for (size_t i = 0; i < groups.size(); ++i) {
+        groups[i] = static_cast<uint16_t>((bytes[2 * i] << 8) | bytes[2 * i + 1]);
+    }
+
+    // Longest run of at least two zero groups, first one on ties
+    size_t best_start = groups.size();
+    size_t best_len = 1;
+    for (size_t i = 0; i < groups.size();) {
+        if (groups[i] != 0) {
+            ++i;
+            continue;
+        }
+        size_t run = i;
+        while (run < groups.size() && groups[run] == 0) {
+            ++run;
+        }
+        if (run - i > best_len) {
+            best_start = i;
+            best_len = run - i;
+        }
+        i = run;
+    }
+
+    static constexpr char kHex[] = "0123456789abcdef";
+    std::string text;
+    for (size_t i = 0; i < groups.size(); ++i) {
+        if (i == best_start) {
+            text += "::";
+            i += best_len - 1;
+            continue;
+        }
+        if (!text.empty() && text.back() != ':') {
+            text += ':';
+        }
+        bool leading = true;
+        for (int shift = 12; shift >= 0; shift -= 4) {
+            const auto digit = (groups[i] >> shift) & 0xF;
+            if (leading && digit == 0 && shift != 0) {
+                continue;
+            }
+            leading = false;
+            text += kHex[digit];
+        }
+    }
+    return text;
+}
+
 }  // namespace exeray::etw
 
 #else  // !_WIN32
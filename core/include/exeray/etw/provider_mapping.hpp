@@ -2,8 +2,10 @@
 /// @brief Provider name to GUID mapping utility.
 #pragma once
 
+#include <cstdint>
 #include <optional>
 #include <string_view>
+#include "exeray/event/types/category.hpp"
 #include "exeray/platform/guid.hpp"
 
 namespace exeray::etw {
@@ -13,4 +15,18 @@ namespace exeray::etw {
 /// @return GUID if known, nullopt otherwise.
 std::optional<GUID> get_provider_guid(std::string_view name);
 
+/// @brief Bit mask of the categories the ETW parsers can emit.
+///
+/// Bit N is set if Category value N is produced by some parser. Scheduler
+/// and Input have no provider yet and are never emitted.
+constexpr std::uint32_t supported_category_mask() noexcept {
+    std::uint32_t mask = 0;
+    for (auto i = 0u; i < static_cast<unsigned>(event::Category::Count); ++i) {
+        mask |= 1u << i;
+    }
+    mask &= ~(1u << static_cast<unsigned>(event::Category::Scheduler));
+    mask &= ~(1u << static_cast<unsigned>(event::Category::Input));
+    return mask;
+}
+
 }  // namespace exeray::etw
@@ -69,6 +69,14 @@ public:
     /// @param provider_guid GUID of the provider to disable.
     void disable_provider(const GUID& provider_guid);
 
+    /// @brief Flush the session's buffers to the real-time consumer.
+    /// @return true if the flush request succeeded.
+    bool flush();
+
+    /// @brief Query the number of buffers the session has lost.
+    /// @return Real-time plus log buffers lost, or 0 if the query fails.
+    [[nodiscard]] std::uint64_t buffers_lost() const;
+
     /// @brief Get the trace handle for use with ProcessTrace.
     /// @return The consumer trace handle.
     [[nodiscard]] TRACEHANDLE trace_handle() const noexcept { return trace_handle_; }
@@ -139,6 +147,10 @@ public:
 
     void disable_provider(const GUID& /*provider_guid*/) {}
 
+    bool flush() { return false; }
+
+    [[nodiscard]] std::uint64_t buffers_lost() const { return 0; }
+
     [[nodiscard]] TRACEHANDLE trace_handle() const noexcept {
         return INVALID_PROCESSTRACE_HANDLE;
     }
@@ -78,6 +78,9 @@ uint32_t get_uint32_prop(const TdhParsedEvent& event, const std::wstring& name);
 /// @brief Get uint64 property or 0.
 uint64_t get_uint64_prop(const TdhParsedEvent& event, const std::wstring& name);
 
+/// @brief Get binary property or empty.
+std::vector<uint8_t> get_binary_prop(const TdhParsedEvent& event, const std::wstring& name);
+
 }  // namespace tdh::detail
 }  // namespace exeray::etw
 
@@ -21,6 +21,18 @@
 
 namespace exeray::event {
 
+/**
+ * @brief An event copied out of the graph with its per-slot tags.
+ *
+ * Stays valid after the slot is reused in ring mode, unlike an EventView
+ * into the graph.
+ */
+struct EventRecord {
+    EventNode node;
+    std::uint32_t target_pid;  ///< See EventGraph::target_pid()
+    std::uint64_t generation;  ///< See EventGraph::generation()
+};
+
 /**
  * @brief Thread-safe container for event nodes.
  *
@@ -29,10 +41,23 @@ namespace exeray::event {
  * provides thread-safe iteration using a shared mutex.
  *
  * Thread-safety model:
- * - push(): Lock-free using atomic operations
- * - get()/exists(): Lock-free reads
+ * - push()/evict*(): Exclusive lock; a slot is counted (published) only
+ *   after it is fully written, so eviction and exists() never see a
+ *   reserved but unwritten slot
+ * - get()/exists()/target_pid()/generation(): Lock-free reads, only safe
+ *   while the slot cannot be reused; in ring mode a concurrent push may
+ *   overwrite it mid-read
+ * - copy_range(): Acquires shared lock, so copies are whole events whose
+ *   IDs match their position
  * - for_each*(): Acquires shared lock for consistent iteration
  *
+ * Ring mode:
+ * When ring mode is enabled, push() overwrites the oldest event once the
+ * graph is full instead of failing. Event IDs stay unique and monotonic,
+ * but the window of live IDs moves: use oldest_id() to find its start.
+ * Positional indices (0..count) are relative to oldest_id() and therefore
+ * shift as events are evicted.
+ *
  * Usage example:
  * @code
  * Arena arena(1024 * 1024);
@@ -52,14 +77,32 @@ namespace exeray::event {
  */
 class EventGraph {
 public:
+    /// @brief Event slots reserved when no capacity is requested.
+    static constexpr std::size_t kDefaultCapacity = 65536;
+
+    /// String pool budget per slot in ring mode (see set_ring_buffer()).
+    static constexpr std::size_t kRingStringBytesPerEvent = 256;
+
+    /**
+     * @brief Arena bytes taken by the slots of a graph.
+     * @param capacity Number of events.
+     * @return Bytes for the nodes, their target PIDs and generations,
+     *         including alignment.
+     */
+    [[nodiscard]] static constexpr std::size_t bytes_for(std::size_t capacity) noexcept {
+        return capacity * (sizeof(EventNode) + sizeof(std::uint32_t) + sizeof(std::uint64_t)) +
+               3 * alignof(EventNode);
+    }
+
     /**
      * @brief Construct an event graph with specified capacity.
      * @param arena Arena for memory allocation.
      * @param strings String pool for string resolution.
-     * @param capacity Maximum number of events (default: 65536).
+     * @param capacity Maximum number of events.
+     * @throws std::invalid_argument if @p capacity is 0.
      */
     explicit EventGraph(Arena& arena, StringPool& strings,
-                        std::size_t capacity = 65536);
+                        std::size_t capacity = kDefaultCapacity);
 
     // Non-copyable, non-movable
     EventGraph(const EventGraph&) = delete;
@@ -79,11 +122,15 @@ public:
      * @param parent Parent event ID (INVALID_EVENT for root events).
      * @param correlation_id Correlation ID for grouping related events.
      * @param payload Category-specific payload data.
-     * @return Unique event ID, or INVALID_EVENT if capacity exceeded.
+     * @param target_pid PID of the target that emitted the event (0 = untagged).
+     * @param flags EventFlags attribute bits of the event.
+     * @return Unique event ID, or INVALID_EVENT if capacity exceeded
+     *         (outside ring mode).
      */
     EventId push(Category cat, std::uint8_t op, Status status,
                  EventId parent, uint32_t correlation_id,
-                 const EventPayload& payload);
+                 const EventPayload& payload, std::uint32_t target_pid = 0,
+                 std::uint16_t flags = EventFlags::NONE);
 
     /**
      * @brief Get event view by ID (thread-safe read).
@@ -95,6 +142,10 @@ public:
 
     /**
      * @brief Check if an event exists.
+     *
+     * True for IDs in the live window [oldest_id(), oldest_id() + count()),
+     * all of which have been fully written.
+     *
      * @param id Event identifier to check.
      * @return true if event exists, false otherwise.
      */
@@ -106,6 +157,185 @@ public:
      */
     [[nodiscard]] std::size_t count() const noexcept;
 
+    /**
+     * @brief Get the number of pushes rejected because the graph was full.
+     * @return Dropped event count (always 0 in ring mode).
+     */
+    [[nodiscard]] std::uint64_t dropped() const noexcept;
+
+    /**
+     * @brief Get the maximum number of events the graph can hold.
+     * @return Capacity in events.
+     */
+    [[nodiscard]] std::size_t capacity() const noexcept { return capacity_; }
+
+    /**
+     * @brief Cap the number of live events below the capacity.
+     *
+     * Pushes beyond the cap fail and count as dropped. Not applied in ring
+     * mode, which never rejects pushes.
+     *
+     * @param max_events Maximum live events (0 = capacity).
+     */
+    void set_max_events(std::size_t max_events) noexcept;
+
+    /**
+     * @brief Get the effective event cap.
+     * @return Maximum live events (capacity() unless capped).
+     */
+    [[nodiscard]] std::size_t max_events() const noexcept;
+
+    /**
+     * @brief Discard events less severe than @p status (see severity()).
+     *
+     * Discarded pushes fail before touching the arena and are counted by
+     * filtered(), not dropped(). Applies in ring mode too. A discarded
+     * event gets no id, so its children are pushed with INVALID_EVENT as
+     * their parent. Pending events are filtered on push like any other
+     * status, before resolve() could raise them.
+     *
+     * @param status Least severe status kept (Success = keep everything).
+     */
+    void set_min_status(Status status) noexcept;
+
+    /**
+     * @brief Get the least severe status kept.
+     */
+    [[nodiscard]] Status min_status() const noexcept;
+
+    /**
+     * @brief Get the number of events discarded by the status threshold.
+     */
+    [[nodiscard]] std::uint64_t filtered() const noexcept;
+
+    /**
+     * @brief Check if node storage was allocated from the arena.
+     * @return false if the arena was too small for the requested capacity.
+     */
+    [[nodiscard]] bool allocated() const noexcept {
+        return nodes_ != nullptr && target_pids_ != nullptr && generations_ != nullptr;
+    }
+
+    /**
+     * @brief Get the PID of the target that emitted an event.
+     * @param id Event identifier.
+     * @return Target process ID, or 0 if untagged or the event does not exist.
+     */
+    [[nodiscard]] std::uint32_t target_pid(EventId id) const noexcept;
+
+    /**
+     * @brief Get the generation an event was committed in.
+     *
+     * Every push advances the generation by one and tags its event with the
+     * new value, so events with a tag above a previously read
+     * current_generation() are exactly those committed since.
+     *
+     * @param id Event identifier.
+     * @return Generation, or 0 if the event does not exist.
+     */
+    [[nodiscard]] std::uint64_t generation(EventId id) const noexcept;
+
+    /**
+     * @brief Copy consecutive events under the shared lock.
+     *
+     * Positions are relative to oldest_id() as read under the same lock, so
+     * the event copied at position @p start + i has ID oldest_id() + start
+     * + i even while ring-mode pushes evict events concurrently.
+     *
+     * @param start Position of the first event to copy.
+     * @param out Destination for up to @p max events.
+     * @param max Capacity of @p out.
+     * @return Number of events copied.
+     */
+    std::size_t copy_range(std::size_t start, EventRecord* out, std::size_t max) const;
+
+    /**
+     * @brief Get the latest generation.
+     *
+     * Published after the event that advanced it, so every event tagged at
+     * or below the returned value is visible.
+     */
+    [[nodiscard]] std::uint64_t current_generation() const noexcept;
+
+    /**
+     * @brief Advance the generation without committing an event.
+     * @return The new generation.
+     */
+    std::uint64_t advance_generation();
+
+    /**
+     * @brief Resolve a Pending event to its final status (thread-safe).
+     *
+     * Records the resolution time so the operation's latency can be
+     * measured with resolved_at().
+     *
+     * @param id Event identifier.
+     * @param status Final status (must not be Pending).
+     * @return true if the event existed and was Pending, false otherwise.
+     */
+    bool resolve(EventId id, Status status);
+
+    /**
+     * @brief Remove the oldest events timestamped before a cutoff.
+     *
+     * Evicts from the oldest event forward and stops at the first event at
+     * or after @p cutoff. The freed slots take new events; interned strings
+     * stay in the pool. Event IDs are unaffected, but positional indices
+     * shift as with ring-mode eviction.
+     *
+     * @param cutoff Timestamp (same clock as timestamp()).
+     * @return Number of events removed.
+     */
+    std::size_t evict_before(Timestamp cutoff);
+
+    /**
+     * @brief Remove up to @p n of the oldest events.
+     *
+     * Frees slots like evict_before(), regardless of timestamps.
+     *
+     * @param n Maximum number of events to remove.
+     * @return Number of events removed.
+     */
+    std::size_t evict_oldest(std::size_t n);
+
+    /**
+     * @brief Get the time a Pending event was resolved.
+     * @param id Event identifier.
+     * @return Resolution timestamp (same clock as timestamp()), or 0 if the
+     *         event was never resolved.
+     */
+    [[nodiscard]] Timestamp resolved_at(EventId id) const;
+
+    // -------------------------------------------------------------------------
+    // Ring Mode
+    // -------------------------------------------------------------------------
+
+    /**
+     * @brief Enable or disable ring mode.
+     *
+     * Must be configured before events are pushed; switching modes on a
+     * non-empty graph is not supported.
+     *
+     * Arena strings cannot be freed with the events that use them, so ring
+     * mode caps the string pool at kRingStringBytesPerEvent per slot; once
+     * full, new strings resolve as empty. Disabling ring mode lifts the cap.
+     *
+     * @param enabled true to overwrite the oldest event when full.
+     */
+    void set_ring_buffer(bool enabled) noexcept;
+
+    /**
+     * @brief Check if ring mode is enabled.
+     * @return true if the graph overwrites the oldest event when full.
+     */
+    [[nodiscard]] bool ring_buffer() const noexcept;
+
+    /**
+     * @brief Get the ID of the oldest live event.
+     * @return Oldest event ID, or INVALID_EVENT if the graph is empty.
+     */
+    [[nodiscard]] EventId oldest_id() const noexcept;
+
     // -------------------------------------------------------------------------
     // Iteration
     // -------------------------------------------------------------------------
@@ -164,17 +394,38 @@ public:
     StringId intern_string(std::string_view str);
 
 private:
+    /// @brief Drop a slot's entries from the indexes (caller holds mutex_).
+    void unindex_slot(std::size_t index);
+
+    /// @brief Remove the oldest event (caller holds mutex_, count_ > 0).
+    void evict_front();
+
+    /// @brief Get the node at a position relative to the oldest event.
+    [[nodiscard]] const EventNode& node_at(std::size_t position) const noexcept;
+
     Arena& arena_;
     StringPool& strings_;
     EventNode* nodes_;
+    std::uint32_t* target_pids_;  ///< Per-slot target PID, parallel to nodes_
+    std::uint64_t* generations_;  ///< Per-slot engine generation, parallel to nodes_
     std::size_t capacity_;
+    std::atomic<std::size_t> max_events_;
     std::atomic<std::size_t> count_{0};
     std::atomic<EventId> next_id_{1};
+    std::atomic<bool> ring_{false};
+    std::atomic<EventId> oldest_id_{1};
+    std::atomic<std::uint64_t> dropped_{0};
+    std::atomic<Status> min_status_{Status::Success};
+    std::atomic<std::uint64_t> filtered_{0};
+    std::atomic<std::uint64_t> generation_{0};  ///< Written under mutex_
     mutable std::shared_mutex mutex_;
 
     // Indexes for O(1) lookup
     std::unordered_multimap<EventId, std::size_t> parent_index_;
     std::unordered_multimap<uint32_t, std::size_t> correlation_index_;
+
+    // Resolution timestamps of formerly Pending events
+    std::unordered_map<EventId, Timestamp> resolved_;
 };
 
 // =============================================================================
@@ -186,7 +437,7 @@ void EventGraph::for_each(F&& fn) const {
     std::shared_lock lock(mutex_);
     const auto current_count = count_.load(std::memory_order_acquire);
     for (std::size_t i = 0; i < current_count; ++i) {
-        fn(EventView(&nodes_[i]));
+        fn(EventView(&node_at(i)));
     }
 }
 
@@ -195,8 +446,9 @@ void EventGraph::for_each_category(Category cat, F&& fn) const {
     std::shared_lock lock(mutex_);
     const auto current_count = count_.load(std::memory_order_acquire);
     for (std::size_t i = 0; i < current_count; ++i) {
-        if (nodes_[i].payload.category == cat) {
-            fn(EventView(&nodes_[i]));
+        const EventNode& node = node_at(i);
+        if (node.payload.category == cat) {
+            fn(EventView(&node));
         }
     }
 }
@@ -35,18 +35,18 @@ namespace exeray::event {
  *   - correlation_id: 4 bytes (groups related events)
  *   - status:         1 byte  (operation result)
  *   - operation:      1 byte  (category-specific operation code)
- *   - _pad:           2 bytes (explicit padding)
+ *   - flags:          2 bytes (EventFlags attribute bits)
  *   - payload:        32 bytes (category-specific data)
  *   - Total:          64 bytes
  */
 struct alignas(64) EventNode {
     EventId id;              ///< Unique event identifier
     EventId parent_id;       ///< Parent event ID (0 = root event)
-    Timestamp timestamp;     ///< High-resolution timestamp (ns since epoch)
+    Timestamp timestamp;     ///< High-resolution timestamp (see kTimestampEpoch)
     uint32_t correlation_id; ///< Correlation ID for grouping related events
     Status status;           ///< Operation result status
     uint8_t operation;       ///< Category-specific operation code
-    uint8_t _pad[2];         ///< Explicit padding for alignment
+    uint16_t flags;          ///< EventFlags attribute bits
     EventPayload payload;    ///< Category-specific payload data (32 bytes)
 };
 
@@ -119,6 +119,9 @@ public:
     /// Get the raw operation code.
     [[nodiscard]] uint8_t operation() const noexcept { return node_->operation; }
 
+    /// Get the EventFlags attribute bits.
+    [[nodiscard]] uint16_t flags() const noexcept { return node_->flags; }
+
     /// Get the correlation ID for event grouping.
     [[nodiscard]] uint32_t correlation_id() const noexcept { return node_->correlation_id; }
 
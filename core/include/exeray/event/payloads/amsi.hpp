@@ -23,4 +23,33 @@ struct AmsiPayload {
     uint32_t content_size;   ///< Original content size in bytes
 };
 
+/**
+ * @brief AMSI scan result values.
+ *
+ * Based on AMSI_RESULT enumeration from amsi.h.
+ * Values >= 32768 are considered malware.
+ */
+enum class AmsiResult : uint32_t {
+    Clean = 0,                    ///< No threat detected, fully clean
+    NotDetected = 1,              ///< No threat detected (may be uncertain)
+    BlockedByAdminStart = 0x4000, ///< Start of admin-blocked range
+    BlockedByAdminEnd = 0x4FFF,   ///< End of admin-blocked range
+    Malware = 0x8000              ///< Malware detected (threshold)
+};
+
+/// @brief Check if AMSI result indicates malware.
+/// @param result The AMSI_RESULT value.
+/// @return true if result indicates malware detection.
+constexpr bool is_malware(uint32_t result) {
+    return result >= static_cast<uint32_t>(AmsiResult::Malware);
+}
+
+/// @brief Check if AMSI result indicates admin block.
+/// @param result The AMSI_RESULT value.
+/// @return true if result is in admin-blocked range.
+constexpr bool is_blocked_by_admin(uint32_t result) {
+    return result >= static_cast<uint32_t>(AmsiResult::BlockedByAdminStart) &&
+           result <= static_cast<uint32_t>(AmsiResult::BlockedByAdminEnd);
+}
+
 }  // namespace exeray::event
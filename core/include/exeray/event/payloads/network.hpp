@@ -10,19 +10,31 @@
 
 namespace exeray::event {
 
+/**
+ * @brief Address family of a NetworkPayload's remote address.
+ */
+enum class AddressFamily : uint8_t {
+    Unknown = 0,  ///< Not recorded; remote_addr is IPv4 if set
+    IPv4 = 4,     ///< remote_addr is an IPv4 address
+    IPv6 = 6,     ///< remote_addr is the StringId of the textual address
+};
+
 /**
  * @brief Payload for network operations.
  *
  * Contains local/remote addresses, ports, byte count, and protocol.
+ * IPv6 addresses do not fit inline, so for AddressFamily::IPv6 the remote
+ * address is interned as text and remote_addr holds its StringId.
  */
 struct NetworkPayload {
     uint32_t local_addr;   ///< Local IPv4 address
-    uint32_t remote_addr;  ///< Remote IPv4 address
+    uint32_t remote_addr;  ///< Remote IPv4 address, or StringId (see family)
     uint16_t local_port;   ///< Local port number
     uint16_t remote_port;  ///< Remote port number
     uint32_t bytes;        ///< Number of bytes transferred
     uint8_t protocol;      ///< Protocol type (TCP=6, UDP=17)
-    uint8_t _pad[3];       ///< Explicit padding for 4-byte alignment
+    AddressFamily family;  ///< Address family of remote_addr
+    uint8_t _pad[2];       ///< Explicit padding for 4-byte alignment
 };
 
 }  // namespace exeray::event
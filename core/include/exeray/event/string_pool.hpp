@@ -8,6 +8,7 @@
  * contiguous memory arena and returning stable StringId handles.
  */
 
+#include <atomic>
 #include <cstddef>
 #include <cstdint>
 #include <shared_mutex>
@@ -26,7 +27,7 @@ namespace exeray::event {
  * Stores unique strings in Arena memory and provides O(1) average lookup.
  * Strings are stored with length prefix: [len:u32][chars...]
  *
- * StringId is the offset + 1 from arena base (so INVALID_STRING = 0 is never returned).
+ * StringId is the arena offset + 1 (so INVALID_STRING = 0 is never returned).
  *
  * Thread-safety: std::shared_mutex (multiple readers, exclusive writer).
  */
@@ -51,6 +52,14 @@ public:
     /// Bytes used for string storage (length prefixes + data).
     [[nodiscard]] std::size_t bytes_used() const noexcept;
 
+    /// Cap string storage at @p max_bytes (0 = unlimited).
+    /// Once reached, new strings intern as INVALID_STRING; strings already
+    /// in the pool still resolve and deduplicate.
+    void set_max_bytes(std::size_t max_bytes) noexcept;
+
+    /// Storage cap in bytes (0 = unlimited).
+    [[nodiscard]] std::size_t max_bytes() const noexcept;
+
     // Non-copyable, non-movable
     StringPool(const StringPool&) = delete;
     StringPool& operator=(const StringPool&) = delete;
@@ -62,6 +71,7 @@ private:
     std::unordered_map<std::string_view, StringId> strings_;
     mutable std::shared_mutex mutex_;
     std::size_t bytes_used_ = 0;
+    std::atomic<std::size_t> max_bytes_{0};
 };
 
 }  // namespace exeray::event
@@ -13,6 +13,7 @@
 
 // Enums
 #include "types/category.hpp"
+#include "types/flags.hpp"
 #include "types/status.hpp"
 #include "types/operations/all.hpp"
 
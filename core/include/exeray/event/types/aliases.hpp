@@ -15,9 +15,21 @@ using EventId = std::uint64_t;
 /// Interned string identifier for zero-copy string storage.
 using StringId = std::uint32_t;
 
-/// High-resolution timestamp in nanoseconds since epoch.
+/// High-resolution timestamp in nanoseconds since epoch (see kTimestampEpoch).
 using Timestamp = std::uint64_t;
 
+/// @brief What a Timestamp counts from.
+///
+/// Values are stable: they cross the FFI boundary as raw integers.
+enum class TimestampEpoch : std::uint8_t {
+    UnixNanos = 0,        ///< Nanoseconds since 1970-01-01 UTC
+    QpcBootRelative = 1,  ///< Monotonic nanoseconds (steady_clock), roughly since boot
+    EtwFileTime = 2,      ///< 100 ns ticks since 1601-01-01 UTC, as ETW records carry
+};
+
+/// Epoch of every event timestamp: the graph stamps events with steady_clock.
+constexpr TimestampEpoch kTimestampEpoch = TimestampEpoch::QpcBootRelative;
+
 /// Invalid event identifier sentinel.
 constexpr EventId INVALID_EVENT = 0;
 
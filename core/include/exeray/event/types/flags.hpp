@@ -0,0 +1,25 @@
+#pragma once
+
+/**
+ * @file flags.hpp
+ * @brief Event attribute bits stored in EventNode::flags.
+ */
+
+#include <cstdint>
+
+namespace exeray::event {
+
+/**
+ * @brief Attribute bits that refine an event beyond category and status.
+ *
+ * Bits are only set when the source knows them; an event with no bits set
+ * carries no claim either way.
+ */
+struct EventFlags {
+    static constexpr std::uint16_t NONE     = 0;
+    static constexpr std::uint16_t REMOTE   = 1 << 0;  ///< Involves a remote host
+    static constexpr std::uint16_t ELEVATED = 1 << 1;  ///< Emitted by an elevated process
+    static constexpr std::uint16_t INJECTED = 1 << 2;  ///< Synthetic, pushed via inject_*()
+};
+
+}  // namespace exeray::event
@@ -20,4 +20,20 @@ enum class Status : std::uint8_t {
     Suspicious  ///< Operation flagged as potentially malicious
 };
 
+/**
+ * @brief Rank a status by how noteworthy it is.
+ *
+ * Success < Pending < Error < Denied < Suspicious.
+ */
+constexpr std::uint8_t severity(Status status) noexcept {
+    switch (status) {
+        case Status::Success: return 0;
+        case Status::Pending: return 1;
+        case Status::Error: return 2;
+        case Status::Denied: return 3;
+        case Status::Suspicious: return 4;
+    }
+    return 4;
+}
+
 }  // namespace exeray::event
@@ -1,9 +1,18 @@
 #pragma once
 
 #include "exeray/engine.hpp"
+#include "exeray/etw/provider_mapping.hpp"
+#include "exeray/process/validate.hpp"
+#include <algorithm>
+#include <cstring>
+#include <filesystem>
+#include <limits>
 #include <memory>
+#include <new>
 #include <optional>
 #include <string>
+#include <string_view>
+#include <vector>
 
 #ifdef _WIN32
 #include <windows.h>
@@ -49,13 +58,32 @@ inline std::wstring utf8_to_wstring(const std::string& s) {
     return utf8_to_wstring(s.data(), s.size());
 }
 
+/// @brief Build a filesystem path from UTF-8 data.
+inline std::filesystem::path utf8_to_path(const char* data, std::size_t len) {
+#ifdef _WIN32
+    return std::filesystem::path(utf8_to_wstring(data, len));
+#else
+    return std::filesystem::path(std::string(data, len));
+#endif
+}
+
 // Log levels: 0=trace, 1=debug, 2=info, 3=warn, 4=error
 constexpr int kDefaultLogLevel = 2;  // info level
 
+// Shared structs defined by the generated bridge header; functions that
+// use them are defined in crates/exeray-ffi/src/bridge.cc
+struct RawCpuTimes;
+struct RawEvent;
+struct RawStats;
+struct RawTaskState;
+struct RawThread;
+
 class Handle {
 public:
-    Handle(std::size_t arena_mb, std::size_t threads)
-        : engine_(EngineConfig{arena_mb * 1024 * 1024, threads, kDefaultLogLevel, {}, {}}) {}
+    Handle(std::size_t arena_mb, std::size_t threads, std::size_t expected_events = 0)
+        : engine_(make_config(arena_mb, threads, expected_events)),
+          expected_events_(expected_events),
+          unix_offset_ns_(measure_unix_offset()) {}
 
     void submit() { engine_.submit(); }
 
@@ -63,6 +91,7 @@ public:
     std::uint64_t timestamp_ns() const { return engine_.timestamp_ns(); }
     std::uint64_t flags() const { return engine_.flags(); }
     float progress() const { return engine_.progress(); }
+    RawTaskState task_state() const;
 
     bool idle() const { return engine_.idle(); }
     std::size_t threads() const { return engine_.threads(); }
@@ -70,6 +99,7 @@ public:
     // Event graph access
     event::EventGraph& graph() { return engine_.graph(); }
     const event::EventGraph& graph() const { return engine_.graph(); }
+    const event::StringPool& strings() const { return engine_.strings(); }
 
     // -------------------------------------------------------------------------
     // Monitoring Control
@@ -91,9 +121,75 @@ public:
         return engine_.start_monitoring(utf8_to_wstring(exe_path));
     }
 
-    /// @brief Stop monitoring and terminate the target process.
+#ifdef EXERAY_HAS_CXX
+    /// @brief Start monitoring several targets (FFI version with rust::String).
+    /// @param exe_paths UTF-8 encoded paths from Rust.
+    /// @return Target PIDs in launch order, empty on failure.
+    rust::Vec<std::uint32_t> start_monitoring_multi(rust::Slice<const rust::String> exe_paths) {
+        std::vector<std::wstring> paths;
+        for (const auto& path : exe_paths) {
+            paths.push_back(utf8_to_wstring(path.data(), path.length()));
+        }
+        rust::Vec<std::uint32_t> pids;
+        for (auto pid : engine_.start_monitoring_multi(paths)) {
+            pids.push_back(pid);
+        }
+        return pids;
+    }
+#endif
+
+    /// @brief Start monitoring several targets (std::string version).
+    /// @param exe_paths UTF-8 encoded paths to the executables.
+    /// @return Target PIDs in launch order, empty on failure.
+    std::vector<std::uint32_t> start_monitoring_multi(const std::vector<std::string>& exe_paths) {
+        std::vector<std::wstring> paths;
+        for (const auto& path : exe_paths) {
+            paths.push_back(utf8_to_wstring(path));
+        }
+        return engine_.start_monitoring_multi(paths);
+    }
+
+    /// @brief Stop monitoring and terminate the target processes.
     void stop_monitoring() { engine_.stop_monitoring(); }
 
+    /// @brief Check if an ETW capture is active.
+    bool is_monitoring() const noexcept { return engine_.is_monitoring(); }
+
+    /// @brief Force buffered ETW events into the graph.
+    /// @return Number of newly committed events.
+    std::size_t flush() { return engine_.flush(); }
+
+    /// @brief Keep the target suspended after launch until unfreeze_target().
+    void set_freeze_on_start(bool enabled) { engine_.set_freeze_on_start(enabled); }
+
+    /// @brief Check if freeze-on-start is enabled.
+    bool freeze_on_start() const noexcept { return engine_.freeze_on_start(); }
+
+    /// @brief Check if the targets are still held at entry by freeze-on-start.
+    bool frozen_at_entry() const noexcept { return engine_.frozen_at_entry(); }
+
+    /// @brief Redirect the target's stdout/stderr on the next launch.
+    void set_capture_output(bool enabled) { engine_.set_capture_output(enabled); }
+
+    /// @brief Check if output capture is enabled.
+    bool capture_output() const noexcept { return engine_.capture_output(); }
+
+    /// @brief Check if the current target's output is being captured.
+    bool capturing_target_output() const noexcept {
+        return engine_.capturing_target_output();
+    }
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Take the target output lines read since the last call.
+    rust::Vec<rust::String> take_target_output() const {
+        rust::Vec<rust::String> lines;
+        for (auto& line : engine_.take_target_output()) {
+            lines.push_back(rust::String::lossy(line));
+        }
+        return lines;
+    }
+#endif
+
     // -------------------------------------------------------------------------
     // Target Process Control
     // -------------------------------------------------------------------------
@@ -119,14 +215,485 @@ public:
     /// @return true if monitoring and target is running.
     bool target_running() const noexcept { return engine_.is_monitoring(); }
 
+    /// @brief Check whether the primary target has exited this session.
+    bool target_exited() const { return engine_.target_exited(); }
+
+    /// @brief Record a target exit without a real process (mock path).
+    void simulate_target_exit(std::uint32_t pid, std::uint32_t exit_code) {
+        engine_.simulate_target_exit(pid, exit_code);
+    }
+
+    /// @brief Check whether kill_target() was called this session.
+    bool target_killed() const noexcept { return engine_.target_killed(); }
+
+    /// @brief Get the PID of the exited target (0 if none exited).
+    std::uint32_t exit_pid() const { return engine_.exit_pid(); }
+
+    /// @brief Get the exit code of the exited target.
+    std::uint32_t exit_code() const { return engine_.exit_code(); }
+
+    /// @brief Check if the target process is currently frozen.
+    bool target_frozen() const noexcept { return engine_.target_frozen(); }
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Read the target's command line (empty if not monitoring).
+    rust::String target_command_line() const {
+        return rust::String::lossy(engine_.target_command_line());
+    }
+
+    /// @brief Read the target's current directory (empty if not monitoring).
+    rust::String target_working_directory() const {
+        return rust::String::lossy(engine_.target_working_directory());
+    }
+
+    /// @brief Get the target's "NAME=value" environment entries.
+    rust::Vec<rust::String> target_environment() const {
+        rust::Vec<rust::String> entries;
+        for (const auto& entry : engine_.target_environment()) {
+            entries.push_back(rust::String::lossy(entry));
+        }
+        return entries;
+    }
+#endif
+
+    /// @brief Get the target's CPU times (std::nullopt if not monitoring).
+    std::optional<process::CpuTimes> target_cpu_times() const {
+        return engine_.target_cpu_times();
+    }
+
+    /// @brief Get the target's integrity level (0 if not monitoring or unknown).
+    std::uint8_t target_integrity_level() const {
+        const auto level = engine_.target_integrity_level();
+        return level ? static_cast<std::uint8_t>(*level) : 0;
+    }
+
+    /// @brief Check if a target signature was looked up (false if not monitoring).
+    bool has_target_signature() const { return engine_.target_signature().has_value(); }
+
+    /// @brief Check if the target carries an embedded signature.
+    bool target_signed() const {
+        const auto signature = engine_.target_signature();
+        return signature && signature->is_signed;
+    }
+
+    /// @brief Check if the target's signature chains to a trusted root.
+    bool target_signature_verified() const {
+        const auto signature = engine_.target_signature();
+        return signature && signature->verified;
+    }
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Get the target's signer name (empty if unsigned or not monitoring).
+    rust::String target_signature_subject() const {
+        const auto signature = engine_.target_signature();
+        return rust::String::lossy(signature ? signature->subject : std::string());
+    }
+#endif
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Snapshot the target's threads (empty if not monitoring).
+    rust::Vec<RawThread> target_threads() const;
+#endif
+
+    // -------------------------------------------------------------------------
+    // Breakpoints
+    // -------------------------------------------------------------------------
+
+    /// @brief Arm a one-shot breakpoint on a category.
+    /// @param category Raw category value; out-of-range values are ignored.
+    void break_on(std::uint8_t category) {
+        if (category < static_cast<std::uint8_t>(event::Category::Count)) {
+            engine_.break_on(static_cast<event::Category>(category));
+        }
+    }
+
+    /// @brief Disarm the breakpoint and forget any recorded hit.
+    void clear_breakpoint() { engine_.clear_breakpoint(); }
+
+    /// @brief Get the ID of the event that triggered the breakpoint.
+    /// @return Event ID, or 0 if not hit.
+    std::uint64_t breakpoint_hit() const noexcept { return engine_.breakpoint_hit(); }
+
+    /// @brief Freeze the target on the first Suspicious event.
+    void set_freeze_on_suspicious(bool enabled) { engine_.set_freeze_on_suspicious(enabled); }
+
+    /// @brief Check if freeze-on-suspicious is enabled.
+    bool freeze_on_suspicious() const noexcept { return engine_.freeze_on_suspicious(); }
+
+    /// @brief Get the ID of the Suspicious event that triggered the guard.
+    /// @return Event ID, or 0 if not hit.
+    std::uint64_t suspicious_hit() const noexcept { return engine_.suspicious_hit(); }
+
+    /// @brief Re-arm the suspicious guard.
+    void clear_suspicious_hit() { engine_.clear_suspicious_hit(); }
+
+    /// @brief Count the freezes triggered by the per-event hooks.
+    std::uint64_t auto_freeze_count() const noexcept { return engine_.auto_freeze_count(); }
+
+    // -------------------------------------------------------------------------
+    // Safety Cap
+    // -------------------------------------------------------------------------
+
+    /// @brief Cap the number of captured events (0 = uncapped).
+    void set_max_events(std::size_t max_events) { engine_.set_max_events(max_events); }
+
+    /// @brief Get the configured cap (0 if uncapped).
+    std::size_t max_events() const noexcept { return engine_.max_events(); }
+
+    /// @brief Check if the cap has been reached.
+    bool max_events_reached() const noexcept { return engine_.max_events_reached(); }
+
+    // -------------------------------------------------------------------------
+    // Status Threshold
+    // -------------------------------------------------------------------------
+
+    /// @brief Discard events less severe than @p status at capture time.
+    void set_min_status(std::uint8_t status) {
+        engine_.graph().set_min_status(static_cast<event::Status>(status));
+    }
+
+    /// @brief Get the least severe status kept.
+    std::uint8_t min_status() const noexcept {
+        return static_cast<std::uint8_t>(engine_.graph().min_status());
+    }
+
+    /// @brief Get the number of events discarded by the threshold.
+    std::uint64_t events_filtered() const noexcept { return engine_.graph().filtered(); }
+
+    // -------------------------------------------------------------------------
+    // Capture Deadline
+    // -------------------------------------------------------------------------
+
+    /// @brief Limit the capture duration of later sessions (0 = unlimited).
+    void set_max_duration_ns(std::uint64_t ns) {
+        engine_.set_max_duration(std::chrono::nanoseconds(
+            (std::min)(ns, static_cast<std::uint64_t>((std::numeric_limits<std::int64_t>::max)()))));
+    }
+
+    /// @brief Get the capture duration limit in ns (0 if unlimited).
+    std::uint64_t max_duration_ns() const noexcept {
+        return static_cast<std::uint64_t>(engine_.max_duration().count());
+    }
+
+    /// @brief Check if the current session hit its deadline.
+    bool timed_out() const noexcept { return engine_.timed_out(); }
+
+    /// @brief Start a session without a target or ETW (mock path).
+    void simulate_session() { engine_.simulate_session(); }
+
+    // -------------------------------------------------------------------------
+    // Event Injection
+    // -------------------------------------------------------------------------
+
+    /// @brief Push a synthetic event (mock path for tests and offline tooling).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_event(std::uint8_t category, std::uint8_t operation,
+                               std::uint8_t status, std::uint64_t parent_id) {
+        if (category >= static_cast<std::uint8_t>(event::Category::Count) ||
+            status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_event(static_cast<event::Category>(category), operation,
+                                    static_cast<event::Status>(status), parent_id);
+    }
+
+    /// @brief Push a synthetic event tagged with a target PID (mock path).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_target_event(std::uint32_t target_pid, std::uint8_t category,
+                                      std::uint8_t operation, std::uint8_t status,
+                                      std::uint64_t parent_id) {
+        if (category >= static_cast<std::uint8_t>(event::Category::Count) ||
+            status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_event(static_cast<event::Category>(category), operation,
+                                    static_cast<event::Status>(status), parent_id,
+                                    target_pid);
+    }
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Push a synthetic FileSystem event with a path (mock path).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_file_event(std::uint8_t operation, std::uint8_t status,
+                                    std::uint64_t parent_id, rust::Str path) {
+        if (status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_file_event(operation, static_cast<event::Status>(status),
+                                         parent_id,
+                                         std::string_view(path.data(), path.length()));
+    }
+#endif
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Push a synthetic Process event with its PIDs and image (mock path).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_process_event(std::uint8_t operation, std::uint8_t status,
+                                       std::uint64_t parent_id, std::uint32_t pid,
+                                       std::uint32_t parent_pid, rust::Str image_path) {
+        if (status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_process_event(
+            operation, static_cast<event::Status>(status), parent_id, pid, parent_pid,
+            std::string_view(image_path.data(), image_path.length()));
+    }
+#endif
+
+    /// @brief Push a synthetic Network event with a remote endpoint (mock path).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_network_event(std::uint8_t operation, std::uint8_t status,
+                                       std::uint64_t parent_id, std::uint32_t remote_addr,
+                                       std::uint16_t remote_port) {
+        if (status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_network_event(operation, static_cast<event::Status>(status),
+                                            parent_id, remote_addr, remote_port);
+    }
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Push a synthetic Network event with an IPv6 remote endpoint (mock path).
+    /// @return ID of the new event, or 0 on invalid input or full graph.
+    std::uint64_t inject_network6_event(std::uint8_t operation, std::uint8_t status,
+                                        std::uint64_t parent_id, rust::Str remote_addr,
+                                        std::uint16_t remote_port) {
+        if (status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return event::INVALID_EVENT;
+        }
+        return engine_.inject_network6_event(
+            operation, static_cast<event::Status>(status), parent_id,
+            std::string_view(remote_addr.data(), remote_addr.length()), remote_port);
+    }
+#endif
+
+    /// @brief Resolve a Pending event to its final status.
+    /// @return true if the event existed and was Pending.
+    bool resolve_event(std::uint64_t id, std::uint8_t status) {
+        if (status > static_cast<std::uint8_t>(event::Status::Suspicious)) {
+            return false;
+        }
+        return engine_.graph().resolve(id, static_cast<event::Status>(status));
+    }
+
+    /// @brief Get the resolution timestamp of an event.
+    /// @return Timestamp in ns, or 0 if the event was never resolved.
+    std::uint64_t event_resolved_at(std::uint64_t id) const {
+        return engine_.graph().resolved_at(id);
+    }
+
+    // -------------------------------------------------------------------------
+    // Provider Configuration
+    // -------------------------------------------------------------------------
+
+#ifdef EXERAY_HAS_CXX
+    /// @brief Enable or disable a provider by name (FFI version with rust::Str).
+    /// @param name Provider name (e.g., "Process", "File", "DNS").
+    /// @param enabled Whether the provider should be enabled.
+    void set_provider_enabled(rust::Str name, bool enabled) {
+        std::string_view key(name.data(), name.length());
+        if (enabled) {
+            engine_.enable_provider(key);
+        } else {
+            engine_.disable_provider(key);
+        }
+    }
+
+    /// @brief Check if a provider is enabled (FFI version with rust::Str).
+    /// @param name Provider name.
+    /// @return true if the provider exists and is enabled.
+    bool provider_enabled(rust::Str name) const {
+        return engine_.is_provider_enabled(std::string_view(name.data(), name.length()));
+    }
+
+    /// @brief Enable or disable a provider, including on a live session.
+    /// @return false if the provider is unknown or the session rejected it.
+    bool update_provider(rust::Str name, bool enabled) {
+        return engine_.update_provider(std::string_view(name.data(), name.length()), enabled);
+    }
+
+    /// @brief List the providers the running session enabled.
+    rust::Vec<rust::String> enabled_providers() const {
+        rust::Vec<rust::String> names;
+        for (const auto& name : engine_.enabled_providers()) {
+            names.push_back(rust::String(name));
+        }
+        return names;
+    }
+#endif
+
+    // -------------------------------------------------------------------------
+    // Arena
+    // -------------------------------------------------------------------------
+
+    /// @brief Grow the arena by @p additional_mb megabytes.
+    /// @return true if the block was allocated.
+    bool grow_arena(std::size_t additional_mb) {
+        constexpr std::size_t kBytesPerMb = 1024 * 1024;
+        if (additional_mb > (std::numeric_limits<std::size_t>::max)() / kBytesPerMb) {
+            return false;
+        }
+        return engine_.grow_arena(additional_mb * kBytesPerMb);
+    }
+
+    /// @brief Get the total arena capacity in bytes.
+    std::size_t arena_capacity() const noexcept { return engine_.arena_capacity(); }
+
+    /// @brief Get the number of arena bytes in use.
+    std::size_t arena_used() const noexcept { return engine_.arena_used(); }
+
+    /// @brief Collect all engine counters.
+    EngineStats stats() const { return engine_.stats(); }
+
+    // -------------------------------------------------------------------------
+    // Ring Mode
+    // -------------------------------------------------------------------------
+
+    /// @brief Enable or disable ring mode (overwrite oldest events when full).
+    void set_ring_buffer(bool enabled) { engine_.graph().set_ring_buffer(enabled); }
+
+    /// @brief Check if ring mode is enabled.
+    bool ring_buffer() const noexcept { return engine_.graph().ring_buffer(); }
+
+    /// @brief Get the ID of the oldest live event.
+    /// @return Oldest event ID, or 0 if the graph is empty.
+    std::uint64_t oldest_event_id() const noexcept { return engine_.graph().oldest_id(); }
+
+    /// @brief Epoch of event timestamps, as a raw event::TimestampEpoch.
+    std::uint8_t timestamp_epoch() const noexcept {
+        return static_cast<std::uint8_t>(event::kTimestampEpoch);
+    }
+
+    /// @brief Unix time minus steady_clock time in ns, measured at creation.
+    ///
+    /// Fixed per handle so conversions of the same timestamp always agree.
+    std::int64_t unix_offset_ns() const noexcept { return unix_offset_ns_; }
+
+    /// @brief Capacity hint the handle was created with (0 = none).
+    std::size_t expected_events() const { return expected_events_; }
+
+    /// @brief Number of event slots in the graph.
+    std::size_t event_capacity() const { return engine_.graph().capacity(); }
+
+    /// @brief Remove the oldest events timestamped before @p cutoff.
+    /// @return Number of events removed.
+    std::size_t clear_events_before(std::uint64_t cutoff) {
+        return engine_.clear_events_before(cutoff);
+    }
+
+    /// @brief Remove up to @p n of the oldest events.
+    /// @return Number of events removed.
+    std::size_t evict_oldest_events(std::size_t n) {
+        return engine_.evict_oldest_events(n);
+    }
+
 private:
+    /// @brief Build the engine configuration with the default provider set.
+    ///
+    /// An @p expected_events hint raises the event slots to that count and
+    /// sizes the arena for twice their footprint, leaving as much again for
+    /// strings. The larger of that size and @p arena_mb wins.
+    static EngineConfig make_config(std::size_t arena_mb, std::size_t threads,
+                                    std::size_t expected_events) {
+        const auto capacity = (std::max)(expected_events, event::EventGraph::kDefaultCapacity);
+        const auto hinted = expected_events == 0 ? 0 : 2 * event::EventGraph::bytes_for(capacity);
+        auto config = EngineConfig::with_defaults((std::max)(arena_mb * 1024 * 1024, hinted),
+                                                  threads);
+        config.event_capacity = capacity;
+        config.log_level = kDefaultLogLevel;
+        return config;
+    }
+
+    /// @brief Sample both clocks back to back.
+    static std::int64_t measure_unix_offset() {
+        using std::chrono::duration_cast;
+        using std::chrono::nanoseconds;
+        const auto wall = duration_cast<nanoseconds>(
+            std::chrono::system_clock::now().time_since_epoch());
+        const auto steady = duration_cast<nanoseconds>(
+            std::chrono::steady_clock::now().time_since_epoch());
+        return (wall - steady).count();
+    }
+
     Engine engine_;
+    std::size_t expected_events_;
+    std::int64_t unix_offset_ns_;
 };
 
+/// @brief Create an engine handle sized for an expected number of events.
+/// @param expected_events Capacity hint (0 = default slots, arena from
+///        @p arena_mb alone).
+/// @return Handle, or nullptr if the arena could not be allocated or is too
+///         small to hold the event graph.
+inline std::unique_ptr<Handle> create_sized(std::size_t arena_mb, std::size_t threads,
+                                            std::size_t expected_events) {
+    constexpr std::size_t kBytesPerMb = 1024 * 1024;
+    constexpr std::size_t kMax = (std::numeric_limits<std::size_t>::max)();
+    if (arena_mb > kMax / kBytesPerMb ||
+        expected_events > kMax / (4 * (sizeof(event::EventNode) + sizeof(std::uint32_t)))) {
+        return nullptr;
+    }
+    try {
+        auto handle = std::make_unique<Handle>(arena_mb, threads, expected_events);
+        if (!handle->graph().allocated()) {
+            return nullptr;
+        }
+        return handle;
+    } catch (const std::bad_alloc&) {
+        return nullptr;
+    }
+}
+
+/// @brief Create an engine handle.
+/// @return Handle, or nullptr if the arena could not be allocated or is too
+///         small to hold the event graph.
 inline std::unique_ptr<Handle> create(std::size_t arena_mb, std::size_t threads) {
-    return std::make_unique<Handle>(arena_mb, threads);
+    return create_sized(arena_mb, threads, 0);
+}
+
+/// @brief Get the categories this core can emit.
+/// @return Bit N set if Category value N is supported.
+inline std::uint32_t supported_category_mask() {
+    return etw::supported_category_mask();
+}
+
+/// @brief Check whether this core was built with the ETW session.
+/// @return true on Windows builds; elsewhere monitoring cannot start.
+inline bool has_monitoring_support() {
+#ifdef _WIN32
+    return true;
+#else
+    return false;
+#endif
 }
 
+#ifdef EXERAY_HAS_CXX
+/// @brief Validate a target executable without launching it.
+/// @param exe_path UTF-8 encoded path from Rust &str.
+/// @return process::Validation value as a raw integer.
+inline std::uint8_t validate_target(rust::Str exe_path) {
+    return static_cast<std::uint8_t>(
+        process::validate_executable(utf8_to_path(exe_path.data(), exe_path.length())));
+}
+#endif
+
+/// @brief Validate a target executable without launching it (std::string version).
+inline std::uint8_t validate_target(const std::string& exe_path) {
+    return static_cast<std::uint8_t>(
+        process::validate_executable(utf8_to_path(exe_path.data(), exe_path.size())));
+}
+
+#ifdef EXERAY_HAS_CXX
+/// @brief Collect all engine counters in one call.
+RawStats get_stats(const Handle& h);
+#endif
+
+#ifdef EXERAY_HAS_CXX
+/// @brief Get the target's CPU times.
+/// @return Times with `available` false if not monitoring or the query fails.
+RawCpuTimes target_cpu_times(const Handle& h);
+#endif
+
 // Event accessor functions for FFI
 inline std::size_t event_count(const Handle& h) {
     return h.graph().count();
@@ -137,43 +704,202 @@ namespace detail {
 /// @brief Private helper to get EventView by index with bounds checking.
 /// @param h Handle reference.
 /// @param index Zero-based event index.
+/// @param copy Storage the returned view points into.
 /// @return Optional EventView, empty if index is out of bounds.
-inline std::optional<event::EventView> get_event_view(const Handle& h, std::size_t index) {
-    if (index >= h.graph().count()) return std::nullopt;
-    // IDs are 1-indexed, so we need id = index + 1
-    return h.graph().get(static_cast<event::EventId>(index + 1));
+inline std::optional<event::EventView> get_event_view(const Handle& h, std::size_t index,
+                                                      event::EventRecord& copy) {
+    // Copied under the graph lock, so a ring-mode push cannot overwrite it
+    // mid-read; indices are relative to the oldest live event
+    if (h.graph().copy_range(index, &copy, 1) == 0) return std::nullopt;
+    return event::EventView(&copy.node);
 }
 
 } // namespace detail
 
-inline std::uint64_t event_get_id(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? ev->id() : 0;
+#ifdef EXERAY_HAS_CXX
+/// @brief Read every fixed field of an event in one call.
+/// @return Fields, all zero for an out-of-range index.
+RawEvent event_at(const Handle& h, std::size_t index);
+
+/// @brief Fill @p out with consecutive events starting at index @p start.
+/// @return Number of events written.
+std::size_t get_events(const Handle& h, std::size_t start, rust::Slice<RawEvent> out);
+#endif
+
+/// @brief Explain why a Denied event was denied.
+///
+/// AMSI denials come from a malware verdict or an administrator block,
+/// security denials from failed logons; other categories report a plain
+/// access denial.
+///
+/// @return Static reason text, empty if the event is not Denied.
+inline std::string_view event_denial_reason(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->status() != event::Status::Denied) {
+        return {};
+    }
+    switch (ev->category()) {
+        case event::Category::Amsi: {
+            const auto result = ev->as_amsi().scan_result;
+            if (event::is_malware(result)) {
+                return "detected as malware";
+            }
+            if (event::is_blocked_by_admin(result)) {
+                return "blocked by policy";
+            }
+            return "blocked by AMSI";
+        }
+        case event::Category::Security:
+            return "logon failed";
+        default:
+            return "access denied";
+    }
+}
+
+#ifdef EXERAY_HAS_CXX
+inline rust::String event_get_denial_reason(const Handle& h, std::size_t index) {
+    const auto reason = event_denial_reason(h, index);
+    return rust::String(reason.data(), reason.size());
+}
+#endif
+
+/// @brief Get the engine generation an event was added in.
+/// @return Generation, or 0 for an out-of-range index.
+inline std::uint64_t event_get_generation(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev) {
+        return 0;
+    }
+    return copy.generation;
+}
+
+/// @brief Get the file path of a FileSystem event.
+/// @return Interned path, empty for other categories or unknown paths.
+inline std::string_view event_file_path(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::FileSystem) {
+        return {};
+    }
+    return h.strings().get(ev->as_file().path);
+}
+
+#ifdef EXERAY_HAS_CXX
+inline rust::String event_get_file_path(const Handle& h, std::size_t index) {
+    const auto path = event_file_path(h, index);
+    return rust::String::lossy(path.data(), path.size());
+}
+#endif
+
+/// @brief Get the process ID a Process event describes.
+/// @return PID, or 0 for other categories.
+inline std::uint32_t event_get_process_pid(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Process) {
+        return 0;
+    }
+    return ev->as_process().pid;
+}
+
+/// @brief Get the creator's process ID of a Process event.
+/// @return Parent PID, or 0 for other categories.
+inline std::uint32_t event_get_process_parent_pid(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Process) {
+        return 0;
+    }
+    return ev->as_process().parent_pid;
+}
+
+/// @brief Get the executable path of a Process event.
+/// @return Interned path, empty for other categories or unknown images.
+inline std::string_view event_image_path(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Process) {
+        return {};
+    }
+    return h.strings().get(ev->as_process().image_path);
+}
+
+#ifdef EXERAY_HAS_CXX
+inline rust::String event_get_image_path(const Handle& h, std::size_t index) {
+    const auto path = event_image_path(h, index);
+    return rust::String::lossy(path.data(), path.size());
+}
+#endif
+
+namespace detail {
+
+/// @brief Read a network byte order field as a host order integer.
+template <typename T>
+inline T from_network_order(const T& value) {
+    unsigned char bytes[sizeof(T)];
+    std::memcpy(bytes, &value, sizeof(T));
+    T result = 0;
+    for (unsigned char byte : bytes) {
+        result = static_cast<T>((result << 8) | byte);
+    }
+    return result;
 }
 
-inline std::uint64_t event_get_parent(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? ev->parent_id() : 0;
+} // namespace detail
+
+/// @brief Get the remote IPv4 address of a Network event in host order.
+/// @return Address, or 0 for other categories and IPv6 endpoints.
+inline std::uint32_t event_get_remote_addr(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Network ||
+        ev->as_network().family == event::AddressFamily::IPv6) {
+        return 0;
+    }
+    return detail::from_network_order(ev->as_network().remote_addr);
 }
 
-inline std::uint64_t event_get_timestamp(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? ev->timestamp() : 0;
+/// @brief Get the address family of a Network event's remote endpoint.
+/// @return AddressFamily value, or 0 (Unknown) for other categories.
+inline std::uint8_t event_get_address_family(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Network) {
+        return 0;
+    }
+    return static_cast<std::uint8_t>(ev->as_network().family);
 }
 
-inline std::uint8_t event_get_category(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? static_cast<std::uint8_t>(ev->category()) : 0;
+/// @brief Get the textual remote IPv6 address of a Network event.
+/// @return Interned address, empty for other categories and IPv4 endpoints.
+inline std::string_view event_remote_addr6(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Network ||
+        ev->as_network().family != event::AddressFamily::IPv6) {
+        return {};
+    }
+    return h.strings().get(ev->as_network().remote_addr);
 }
 
-inline std::uint8_t event_get_status(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? static_cast<std::uint8_t>(ev->status()) : 0;
+#ifdef EXERAY_HAS_CXX
+inline rust::String event_get_remote_addr6(const Handle& h, std::size_t index) {
+    const auto addr = event_remote_addr6(h, index);
+    return rust::String::lossy(addr.data(), addr.size());
 }
+#endif
 
-inline std::uint8_t event_get_operation(const Handle& h, std::size_t index) {
-    auto ev = detail::get_event_view(h, index);
-    return ev ? ev->operation() : 0;
+/// @brief Get the remote port of a Network event in host order.
+/// @return Port, or 0 for other categories.
+inline std::uint16_t event_get_remote_port(const Handle& h, std::size_t index) {
+    event::EventRecord copy{};
+    auto ev = detail::get_event_view(h, index, copy);
+    if (!ev || ev->category() != event::Category::Network) {
+        return 0;
+    }
+    return detail::from_network_order(ev->as_network().remote_port);
 }
 
 }
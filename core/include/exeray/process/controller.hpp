@@ -6,12 +6,40 @@
 /// Uses Windows APIs: CreateProcessW, Job Objects for process isolation.
 /// On non-Windows platforms, provides stub implementations.
 
+#include <atomic>
+#include <chrono>
 #include <cstdint>
 #include <memory>
+#include <mutex>
+#include <optional>
+#include <string>
 #include <string_view>
+#include <thread>
+#include <vector>
 
 namespace exeray::process {
 
+/// @brief Snapshot of one thread of a controlled process.
+struct ThreadInfo {
+    std::uint32_t tid{0};              ///< Thread ID
+    bool suspended{false};             ///< Suspend count is nonzero
+    std::uint64_t start_address{0};    ///< Win32 start address (0 if unknown)
+};
+
+/// @brief CPU time a process has consumed so far.
+struct CpuTimes {
+    std::chrono::nanoseconds kernel{0};  ///< Time spent in kernel mode
+    std::chrono::nanoseconds user{0};    ///< Time spent in user mode
+};
+
+/// @brief Mandatory integrity level of a process token.
+enum class IntegrityLevel : std::uint8_t {
+    Low = 1,     ///< Low or untrusted (sandboxed)
+    Medium = 2,  ///< Standard user
+    High = 3,    ///< Elevated administrator
+    System = 4,  ///< Local system or protected process
+};
+
 /// @brief Controls a launched process with suspend/resume/terminate capabilities.
 ///
 /// Processes are launched in suspended mode and must be explicitly resumed.
@@ -24,11 +52,13 @@ public:
     /// @param exe_path Path to the executable.
     /// @param args Command-line arguments (optional).
     /// @param working_dir Working directory (optional, defaults to current).
+    /// @param capture_output Redirect stdout/stderr to a pipe read by take_output().
     /// @return Unique pointer to Controller, or nullptr on failure.
     [[nodiscard]] static std::unique_ptr<Controller> launch(
         std::wstring_view exe_path,
         std::wstring_view args = L"",
-        std::wstring_view working_dir = L""
+        std::wstring_view working_dir = L"",
+        bool capture_output = false
     );
 
     /// @brief Destructor terminates process and closes handles.
@@ -67,6 +97,48 @@ public:
     /// @brief Get the exit code (only valid if !is_running()).
     [[nodiscard]] std::uint32_t exit_code() const;
 
+    /// @brief Enumerate the threads of the process.
+    ///
+    /// Suspend counts are queried, never probed, so running threads are not
+    /// disturbed.
+    /// @return One entry per live thread, empty on failure.
+    [[nodiscard]] std::vector<ThreadInfo> threads() const;
+
+    /// @brief Read the command line recorded in the process PEB.
+    /// @return UTF-8 command line, empty on failure.
+    [[nodiscard]] std::string command_line() const;
+
+    /// @brief Read the current directory recorded in the process PEB.
+    /// @return UTF-8 path, empty on failure.
+    [[nodiscard]] std::string working_directory() const;
+
+    /// @brief Read the environment block recorded in the process PEB.
+    /// @return UTF-8 "NAME=value" entries in block order, empty on failure.
+    [[nodiscard]] std::vector<std::string> environment() const;
+
+    /// @brief Query the kernel and user CPU time of the process.
+    /// @return Times, or std::nullopt if the query fails.
+    [[nodiscard]] std::optional<CpuTimes> cpu_times() const;
+
+    /// @brief Query the integrity level of the process token.
+    /// @return Level, or std::nullopt if the token cannot be read.
+    [[nodiscard]] std::optional<IntegrityLevel> integrity_level() const;
+
+    // -------------------------------------------------------------------------
+    // Output Capture
+    // -------------------------------------------------------------------------
+
+    /// @brief Check if stdout/stderr were redirected at launch.
+    [[nodiscard]] bool capturing_output() const noexcept { return capturing_output_; }
+
+    /// @brief Take the output lines read since the last call.
+    ///
+    /// Only the newest lines are kept between calls, and a line longer than
+    /// the internal limit is split, so an unpolled or flooding target cannot
+    /// grow host memory without bound.
+    /// @return Complete lines without terminators, oldest first.
+    [[nodiscard]] std::vector<std::string> take_output();
+
     // -------------------------------------------------------------------------
     // Job Object Control (Resource Limits)
     // -------------------------------------------------------------------------
@@ -86,14 +158,26 @@ private:
     /// @brief Private constructor, use launch() factory.
     Controller() = default;
 
+    /// @brief Reader thread: split pipe data into lines until EOF or until
+    ///        the destructor cancels the blocked read.
+    /// @param pipe Read end of the output pipe.
+    void read_output(void* pipe);
+
     // Use void* to avoid Windows header pollution in public header.
     // These are HANDLE types on Windows.
 #ifdef _WIN32
     void* process_handle_{nullptr};
     void* thread_handle_{nullptr};
     void* job_handle_{nullptr};
+    void* output_read_{nullptr};
 #endif
     std::uint32_t pid_{0};
+    bool capturing_output_{false};
+    std::atomic<bool> output_closed_{false};
+    std::atomic<bool> output_done_{false};  ///< Set when read_output() returns
+    std::thread output_thread_;
+    std::mutex output_mutex_;
+    std::vector<std::string> output_lines_;
 };
 
 }  // namespace exeray::process
@@ -0,0 +1,27 @@
+#pragma once
+
+/// @file signature.hpp
+/// @brief Authenticode signature lookup for executables.
+
+#include <filesystem>
+#include <string>
+
+namespace exeray::process {
+
+/// @brief Authenticode status of an executable.
+struct Signature {
+    bool is_signed = false;  ///< An embedded signature is present.
+    std::string subject;     ///< Signer certificate display name (UTF-8), if found.
+    bool verified = false;   ///< The signature chains to a trusted root.
+};
+
+/// @brief Read the embedded signature of an executable.
+///
+/// Verification skips revocation checks so it stays offline. Always
+/// reports an unsigned file on non-Windows platforms.
+///
+/// @param path Path to the executable.
+/// @return Signature status; is_signed is false if none was found.
+[[nodiscard]] Signature query_signature(const std::filesystem::path& path);
+
+}  // namespace exeray::process
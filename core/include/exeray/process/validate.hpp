@@ -0,0 +1,31 @@
+#pragma once
+
+/// @file validate.hpp
+/// @brief Dry-run validation of a target executable without launching it.
+
+#include <cstdint>
+#include <filesystem>
+
+namespace exeray::process {
+
+/// @brief Outcome of validate_executable().
+///
+/// Values are stable: they cross the FFI boundary as raw integers.
+enum class Validation : std::uint8_t {
+    Ok = 0,                       ///< Looks launchable
+    NotFound = 1,                 ///< Path does not exist or is not a file
+    Unreadable = 2,               ///< File could not be opened or read
+    NotPe = 3,                    ///< Missing or malformed MZ/PE headers
+    UnsupportedArchitecture = 4,  ///< Machine type is not x86, x64 or ARM64
+    NotExecutable = 5,            ///< Image is a DLL or not marked executable
+};
+
+/// @brief Check that a path is an existing, readable, executable PE image.
+///
+/// Reads only the DOS and COFF headers; the file is never executed.
+///
+/// @param path Path to the executable.
+/// @return Validation::Ok, or the first problem found.
+[[nodiscard]] Validation validate_executable(const std::filesystem::path& path);
+
+}  // namespace exeray::process
@@ -1,5 +1,6 @@
 #pragma once
 
+#include <atomic>
 #include <condition_variable>
 #include <functional>
 #include <mutex>
@@ -14,7 +15,9 @@ public:
     using Task = std::function<void()>;
 
     explicit ThreadPool(std::size_t num_threads = 0) : running_(true) {
-        auto count = num_threads > 0 ? num_threads : std::thread::hardware_concurrency();
+        // hardware_concurrency() may report 0 when unknown; keep one worker
+        std::size_t count = num_threads > 0 ? num_threads : std::thread::hardware_concurrency();
+        count = count > 0 ? count : 1;
         workers_.reserve(count);
         for (std::size_t i = 0; i < count; ++i) {
             workers_.emplace_back([this] { run(); });
@@ -47,6 +50,15 @@ public:
 
     std::size_t size() const { return workers_.size(); }
 
+    /// @brief Number of workers currently running a task.
+    std::size_t busy() const { return busy_.load(std::memory_order_relaxed); }
+
+    /// @brief Number of tasks waiting for a worker.
+    std::size_t pending() const {
+        std::lock_guard lock(mutex_);
+        return tasks_.size();
+    }
+
 private:
     void run() {
         while (true) {
@@ -57,15 +69,18 @@ private:
                 if (!running_ && tasks_.empty()) return;
                 task = std::move(tasks_.front());
                 tasks_.pop();
+                busy_.fetch_add(1, std::memory_order_relaxed);
             }
             task();
+            busy_.fetch_sub(1, std::memory_order_relaxed);
         }
     }
 
     std::vector<std::thread> workers_;
     std::queue<Task> tasks_;
-    std::mutex mutex_;
+    mutable std::mutex mutex_;
     std::condition_variable cv_;
+    std::atomic<std::size_t> busy_{0};
     bool running_;
 };
 
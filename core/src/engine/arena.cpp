@@ -0,0 +1,27 @@
+/// @file engine/arena.cpp
+/// @brief Arena management: grow_arena, arena_capacity, arena_used.
+
+#include "exeray/engine.hpp"
+#include "exeray/logging.hpp"
+
+namespace exeray {
+
+bool Engine::grow_arena(std::size_t additional_bytes) {
+    if (!arena_.grow(additional_bytes)) {
+        EXERAY_WARN("Engine: Failed to grow arena by {} bytes", additional_bytes);
+        return false;
+    }
+    EXERAY_DEBUG("Engine: Arena grown to {} bytes ({} blocks)",
+                 arena_.capacity(), arena_.blocks());
+    return true;
+}
+
+std::size_t Engine::arena_capacity() const noexcept {
+    return arena_.capacity();
+}
+
+std::size_t Engine::arena_used() const noexcept {
+    return arena_.used();
+}
+
+}  // namespace exeray
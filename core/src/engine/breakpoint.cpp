@@ -0,0 +1,48 @@
+/// @file engine/breakpoint.cpp
+/// @brief One-shot category breakpoints: break_on, clear_breakpoint, breakpoint_hit,
+///        the freeze-on-suspicious guard, and the hooks' freeze counter.
+
+#include "exeray/engine.hpp"
+
+namespace exeray {
+
+void Engine::break_on(event::Category cat) {
+    breakpoint_hit_.store(event::INVALID_EVENT, std::memory_order_release);
+    breakpoint_category_.store(static_cast<std::uint8_t>(cat), std::memory_order_release);
+}
+
+void Engine::clear_breakpoint() {
+    breakpoint_category_.store(kNoBreakpoint, std::memory_order_release);
+    breakpoint_hit_.store(event::INVALID_EVENT, std::memory_order_release);
+}
+
+event::EventId Engine::breakpoint_hit() const noexcept {
+    return breakpoint_hit_.load(std::memory_order_acquire);
+}
+
+void Engine::set_freeze_on_suspicious(bool enabled) noexcept {
+    freeze_on_suspicious_.store(enabled, std::memory_order_release);
+}
+
+bool Engine::freeze_on_suspicious() const noexcept {
+    return freeze_on_suspicious_.load(std::memory_order_acquire);
+}
+
+event::EventId Engine::suspicious_hit() const noexcept {
+    return suspicious_hit_.load(std::memory_order_acquire);
+}
+
+void Engine::clear_suspicious_hit() noexcept {
+    suspicious_hit_.store(event::INVALID_EVENT, std::memory_order_release);
+}
+
+std::uint64_t Engine::auto_freeze_count() const noexcept {
+    return auto_freezes_.load(std::memory_order_acquire);
+}
+
+void Engine::auto_freeze() {
+    auto_freezes_.fetch_add(1, std::memory_order_acq_rel);
+    freeze_target();
+}
+
+}  // namespace exeray
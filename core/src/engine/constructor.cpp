@@ -9,7 +9,7 @@ namespace exeray {
 Engine::Engine(EngineConfig config)
     : arena_(config.arena_size),
       strings_(arena_),
-      graph_(arena_, strings_),
+      graph_(arena_, strings_, config.event_capacity),
       correlator_(),
       pool_(config.num_threads),
       config_(std::move(config)) {
@@ -18,13 +18,13 @@ Engine::Engine(EngineConfig config)
     consumer_ctx_.target_pid = &target_pid_;
     consumer_ctx_.strings = &strings_;
     consumer_ctx_.correlator = &correlator_;
+    consumer_ctx_.on_event = [this](event::EventId id) { on_event(id); };
 }
 
 Engine::~Engine() {
-    // Ensure cleanup on destruction
-    if (monitoring_.load(std::memory_order_acquire)) {
-        stop_monitoring();
-    }
+    // Ensure cleanup on destruction, including the targets and watchdog of
+    // a session that already hit its deadline
+    stop_monitoring();
 }
 
 }  // namespace exeray
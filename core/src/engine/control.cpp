@@ -1,30 +1,141 @@
 /// @file engine/control.cpp
-/// @brief Process control: freeze, unfreeze, kill, target_pid.
+/// @brief Process control: freeze, unfreeze, kill, target_pid, target_frozen,
+///        target exit, target_threads, target_command_line,
+///        target_working_directory, target_environment,
+///        target_signature, target_cpu_times, target_integrity_level. Freeze,
+///        unfreeze and kill apply to every target.
 
 #include "exeray/engine.hpp"
 
 namespace exeray {
 
 void Engine::freeze_target() {
+    std::lock_guard lock(target_mutex_);
     if (target_ && target_->is_running()) {
         target_->suspend();
+        target_frozen_.store(true, std::memory_order_release);
+    }
+    for (auto& extra : extra_targets_) {
+        if (extra->is_running()) {
+            extra->suspend();
+        }
     }
 }
 
 void Engine::unfreeze_target() {
+    frozen_at_entry_.store(false, std::memory_order_release);
+    std::lock_guard lock(target_mutex_);
     if (target_ && target_->is_running()) {
         target_->resume();
+        target_frozen_.store(false, std::memory_order_release);
+    }
+    for (auto& extra : extra_targets_) {
+        if (extra->is_running()) {
+            extra->resume();
+        }
     }
 }
 
 void Engine::kill_target() {
+    // Recorded even without a live target, so the exit reads as a kill
+    target_killed_.store(true, std::memory_order_release);
+    std::lock_guard lock(target_mutex_);
     if (target_) {
         target_->terminate();
     }
+    for (auto& extra : extra_targets_) {
+        extra->terminate();
+    }
 }
 
 uint32_t Engine::target_pid() const noexcept {
     return target_pid_.load(std::memory_order_acquire);
 }
 
+bool Engine::target_frozen() const noexcept {
+    return target_frozen_.load(std::memory_order_acquire);
+}
+
+bool Engine::target_exited() const {
+    if (target_exited_.load(std::memory_order_acquire)) {
+        return true;
+    }
+    return monitoring_.load(std::memory_order_acquire) && target_ &&
+           !target_->is_running();
+}
+
+void Engine::simulate_target_exit(uint32_t pid, uint32_t exit_code) {
+    // Publish PID and code before the flag so readers see a complete exit
+    exit_pid_.store(pid, std::memory_order_relaxed);
+    exit_code_.store(exit_code, std::memory_order_relaxed);
+    target_exited_.store(true, std::memory_order_release);
+}
+
+bool Engine::target_killed() const noexcept {
+    return target_killed_.load(std::memory_order_acquire);
+}
+
+uint32_t Engine::exit_pid() const {
+    if (target_exited_.load(std::memory_order_acquire)) {
+        return exit_pid_.load(std::memory_order_relaxed);
+    }
+    return target_exited() ? target_->pid() : 0;
+}
+
+uint32_t Engine::exit_code() const {
+    if (target_exited_.load(std::memory_order_acquire)) {
+        return exit_code_.load(std::memory_order_relaxed);
+    }
+    return target_exited() ? target_->exit_code() : 0;
+}
+
+std::vector<process::ThreadInfo> Engine::target_threads() const {
+    if (!target_) {
+        return {};
+    }
+    return target_->threads();
+}
+
+std::string Engine::target_command_line() const {
+    if (!target_ || !is_monitoring()) {
+        return {};
+    }
+    return target_->command_line();
+}
+
+std::string Engine::target_working_directory() const {
+    if (!target_ || !is_monitoring()) {
+        return {};
+    }
+    return target_->working_directory();
+}
+
+std::vector<std::string> Engine::target_environment() const {
+    if (!target_ || !is_monitoring()) {
+        return {};
+    }
+    return target_->environment();
+}
+
+std::optional<process::CpuTimes> Engine::target_cpu_times() const {
+    if (!target_ || !is_monitoring()) {
+        return std::nullopt;
+    }
+    return target_->cpu_times();
+}
+
+std::optional<process::IntegrityLevel> Engine::target_integrity_level() const {
+    if (!target_ || !is_monitoring()) {
+        return std::nullopt;
+    }
+    return target_->integrity_level();
+}
+
+std::optional<process::Signature> Engine::target_signature() const {
+    if (!target_ || !is_monitoring()) {
+        return std::nullopt;
+    }
+    return target_signature_;
+}
+
 }  // namespace exeray
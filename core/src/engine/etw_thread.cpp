@@ -11,11 +11,18 @@ namespace exeray {
 
 void Engine::process() {
     for (int i = 0; i <= 100; ++i) {
-        progress_.store(static_cast<float>(i) / 100.0f, std::memory_order_relaxed);
+        write_task_state([this, i] {
+            progress_.store(static_cast<float>(i) / 100.0f, std::memory_order_relaxed);
+        });
         std::this_thread::sleep_for(std::chrono::milliseconds(10));
     }
-    generation_.fetch_add(1, std::memory_order_release);
-    flags_.store(StatusFlags::COMPLETE | StatusFlags::READY, std::memory_order_release);
+    // Completion is one write: readers see the flags and the new generation
+    // together or neither. ERRORED survives, as in submit().
+    write_task_state([this] {
+        flags_.fetch_and(StatusFlags::ERRORED, std::memory_order_relaxed);
+        flags_.fetch_or(StatusFlags::COMPLETE | StatusFlags::READY, std::memory_order_relaxed);
+        graph_.advance_generation();
+    });
 }
 
 void Engine::etw_thread_func() {
@@ -0,0 +1,147 @@
+/// @file engine/hooks.cpp
+/// @brief Per-event hooks, the event cap, and synthetic event injection.
+
+#include "exeray/engine.hpp"
+#include "exeray/logging.hpp"
+
+#include <array>
+#include <cstring>
+
+namespace exeray {
+
+void Engine::on_event(event::EventId id) {
+    if (!graph_.exists(id)) {
+        return;
+    }
+    const auto view = graph_.get(id);
+
+    // One-shot breakpoint: only the thread that disarms it freezes the target
+    auto armed = static_cast<std::uint8_t>(view.category());
+    if (breakpoint_category_.compare_exchange_strong(armed, kNoBreakpoint,
+                                                     std::memory_order_acq_rel)) {
+        breakpoint_hit_.store(id, std::memory_order_release);
+        auto_freeze();
+    }
+
+    // Suspicious guard: only the first unrecorded hit freezes, so racing
+    // consumer threads never suspend the target twice
+    if (view.status() == event::Status::Suspicious &&
+        freeze_on_suspicious_.load(std::memory_order_acquire)) {
+        auto none = event::INVALID_EVENT;
+        if (suspicious_hit_.compare_exchange_strong(none, id, std::memory_order_acq_rel)) {
+            auto_freeze();
+        }
+    }
+
+    // Safety cap: the graph already rejects further pushes; flag and freeze once
+    const auto cap = max_events_.load(std::memory_order_acquire);
+    if (cap != 0 && graph_.count() >= cap &&
+        !max_events_reached_.exchange(true, std::memory_order_acq_rel)) {
+        write_task_state([this] {
+            flags_.fetch_or(StatusFlags::ERRORED, std::memory_order_relaxed);
+        });
+        auto_freeze();
+        EXERAY_WARN("Engine: Event cap of {} reached, target frozen", cap);
+    }
+}
+
+void Engine::set_max_events(std::size_t max_events) noexcept {
+    max_events_.store(max_events, std::memory_order_release);
+    graph_.set_max_events(max_events);
+}
+
+std::size_t Engine::max_events() const noexcept {
+    return max_events_.load(std::memory_order_acquire);
+}
+
+bool Engine::max_events_reached() const noexcept {
+    return max_events_reached_.load(std::memory_order_acquire);
+}
+
+std::size_t Engine::clear_events_before(std::uint64_t cutoff) {
+    const auto removed = graph_.evict_before(cutoff);
+    if (removed > 0) {
+        max_events_reached_.store(false, std::memory_order_release);
+    }
+    return removed;
+}
+
+std::size_t Engine::evict_oldest_events(std::size_t n) {
+    const auto removed = graph_.evict_oldest(n);
+    if (removed > 0) {
+        max_events_reached_.store(false, std::memory_order_release);
+    }
+    return removed;
+}
+
+event::EventId Engine::inject_event(event::Category cat, uint8_t op,
+                                    event::Status status, event::EventId parent,
+                                    uint32_t target_pid) {
+    event::EventPayload payload{};
+    payload.category = cat;
+    return push_injected(payload, op, status, parent, target_pid);
+}
+
+event::EventId Engine::inject_file_event(uint8_t op, event::Status status,
+                                         event::EventId parent, std::string_view path) {
+    event::EventPayload payload{};
+    payload.category = event::Category::FileSystem;
+    payload.file.path = strings_.intern(path);
+    return push_injected(payload, op, status, parent, 0);
+}
+
+event::EventId Engine::inject_network_event(uint8_t op, event::Status status,
+                                            event::EventId parent, uint32_t remote_addr,
+                                            uint16_t remote_port) {
+    event::EventPayload payload{};
+    payload.category = event::Category::Network;
+    // Lay the bytes out most significant first, matching the wire format
+    const std::array<uint8_t, 4> addr{
+        static_cast<uint8_t>(remote_addr >> 24), static_cast<uint8_t>(remote_addr >> 16),
+        static_cast<uint8_t>(remote_addr >> 8), static_cast<uint8_t>(remote_addr)};
+    const std::array<uint8_t, 2> port{static_cast<uint8_t>(remote_port >> 8),
+                                      static_cast<uint8_t>(remote_port)};
+    std::memcpy(&payload.network.remote_addr, addr.data(), addr.size());
+    std::memcpy(&payload.network.remote_port, port.data(), port.size());
+    payload.network.family = event::AddressFamily::IPv4;
+    return push_injected(payload, op, status, parent, 0);
+}
+
+event::EventId Engine::inject_network6_event(uint8_t op, event::Status status,
+                                             event::EventId parent,
+                                             std::string_view remote_addr,
+                                             uint16_t remote_port) {
+    event::EventPayload payload{};
+    payload.category = event::Category::Network;
+    payload.network.remote_addr = strings_.intern(remote_addr);
+    const std::array<uint8_t, 2> port{static_cast<uint8_t>(remote_port >> 8),
+                                      static_cast<uint8_t>(remote_port)};
+    std::memcpy(&payload.network.remote_port, port.data(), port.size());
+    payload.network.family = event::AddressFamily::IPv6;
+    return push_injected(payload, op, status, parent, 0);
+}
+
+event::EventId Engine::inject_process_event(uint8_t op, event::Status status,
+                                            event::EventId parent, uint32_t pid,
+                                            uint32_t parent_pid,
+                                            std::string_view image_path) {
+    event::EventPayload payload{};
+    payload.category = event::Category::Process;
+    payload.process.pid = pid;
+    payload.process.parent_pid = parent_pid;
+    payload.process.image_path = strings_.intern(image_path);
+    return push_injected(payload, op, status, parent, 0);
+}
+
+event::EventId Engine::push_injected(const event::EventPayload& payload, uint8_t op,
+                                     event::Status status, event::EventId parent,
+                                     uint32_t target_pid) {
+    const auto id = graph_.push(payload.category, op, status, parent, 0, payload, target_pid,
+                                event::EventFlags::INJECTED);
+    if (id != event::INVALID_EVENT) {
+        on_event(id);
+    }
+    return id;
+}
+
+}  // namespace exeray
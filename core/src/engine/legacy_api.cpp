@@ -4,16 +4,22 @@
 #include "exeray/engine.hpp"
 
 #include <chrono>
+#include <thread>
 
 namespace exeray {
 
 void Engine::submit() {
-    flags_.store(StatusFlags::PENDING, std::memory_order_release);
+    // A new task replaces the previous one's state, but an error raised by
+    // the event hooks (e.g. the event cap) stays visible
+    write_task_state([this] {
+        flags_.fetch_and(StatusFlags::ERRORED, std::memory_order_relaxed);
+        flags_.fetch_or(StatusFlags::PENDING, std::memory_order_relaxed);
+    });
     pool_.submit([this] { process(); });
 }
 
 std::uint64_t Engine::generation() const {
-    return generation_.load(std::memory_order_acquire);
+    return graph_.current_generation();
 }
 
 std::uint64_t Engine::timestamp_ns() const {
@@ -29,6 +35,23 @@ float Engine::progress() const {
     return progress_.load(std::memory_order_relaxed);
 }
 
+TaskState Engine::task_state() const {
+    for (;;) {
+        const auto seq = state_seq_.load(std::memory_order_acquire);
+        if (seq % 2 != 0) {
+            std::this_thread::yield();
+            continue;
+        }
+        TaskState state{graph_.current_generation(),
+                        flags_.load(std::memory_order_relaxed),
+                        progress_.load(std::memory_order_relaxed)};
+        std::atomic_thread_fence(std::memory_order_acquire);
+        if (state_seq_.load(std::memory_order_relaxed) == seq) {
+            return state;
+        }
+    }
+}
+
 bool Engine::idle() const {
     return flags_.load(std::memory_order_acquire) == StatusFlags::IDLE;
 }
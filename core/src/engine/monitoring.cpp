@@ -1,31 +1,85 @@
 /// @file engine/monitoring.cpp
-/// @brief Process monitoring implementation: start, stop, status.
+/// @brief Process monitoring implementation: start, stop, status, flush.
 
 #include "exeray/engine.hpp"
 #include "exeray/etw/provider_mapping.hpp"
 #include "exeray/etw/session.hpp"
 #include "exeray/logging.hpp"
 #include "exeray/process/controller.hpp"
+#include "exeray/process/signature.hpp"
+
+#include <algorithm>
+#include <chrono>
+#include <filesystem>
+#include <iterator>
 
 namespace exeray {
 
+namespace {
+
+/// Poll interval while waiting for a flush to drain.
+constexpr auto kFlushPollInterval = std::chrono::milliseconds(10);
+
+/// Upper bound on how long flush() waits for the consumer.
+constexpr auto kFlushTimeout = std::chrono::milliseconds(500);
+
+/// @brief Total number of events ever committed to the graph.
+std::size_t committed_events(const event::EventGraph& graph) {
+    const auto oldest = graph.oldest_id();
+    const auto evicted = oldest == event::INVALID_EVENT ? 0 : oldest - 1;
+    return static_cast<std::size_t>(evicted) + graph.count();
+}
+
+}  // namespace
+
 bool Engine::start_monitoring(std::wstring_view exe_path) {
+    return !start_monitoring_multi({std::wstring(exe_path)}).empty();
+}
+
+std::vector<uint32_t> Engine::start_monitoring_multi(
+    const std::vector<std::wstring>& exe_paths) {
     // Don't start if already monitoring
     if (monitoring_.load(std::memory_order_acquire)) {
         EXERAY_ERROR("Engine: Already monitoring a process");
-        return false;
+        return {};
+    }
+    if (exe_paths.empty()) {
+        EXERAY_ERROR("Engine: No targets to monitor");
+        return {};
     }
 
 #ifdef _WIN32
-    // Step 1: Launch target process in suspended mode
-    target_ = process::Controller::launch(exe_path);
-    if (!target_) {
-        EXERAY_ERROR("Engine: Failed to launch target process");
-        return false;
+    reset_target_exit();
+
+    // Step 1: Launch all targets in suspended mode
+    const bool capture = capture_output_.load(std::memory_order_acquire);
+    std::vector<std::unique_ptr<process::Controller>> launched;
+    for (const auto& exe_path : exe_paths) {
+        auto controller = process::Controller::launch(exe_path, L"", L"", capture);
+        if (!controller) {
+            EXERAY_ERROR("Engine: Failed to launch target process");
+            return {};  // Controller destructors terminate the earlier targets
+        }
+        launched.push_back(std::move(controller));
     }
 
-    // Store target PID for event filtering
-    target_pid_.store(target_->pid(), std::memory_order_release);
+    std::vector<uint32_t> pids;
+    for (const auto& controller : launched) {
+        pids.push_back(controller->pid());
+    }
+
+    // Look the primary image's signature up once, before it can be replaced
+    target_signature_ = process::query_signature(std::filesystem::path(exe_paths.front()));
+
+    // Store target PIDs for event filtering; the first target is the primary
+    {
+        std::lock_guard lock(target_mutex_);
+        target_ = std::move(launched.front());
+        extra_targets_.assign(std::make_move_iterator(launched.begin() + 1),
+                              std::make_move_iterator(launched.end()));
+    }
+    target_pid_.store(pids.front(), std::memory_order_release);
+    consumer_ctx_.extra_target_pids.assign(pids.begin() + 1, pids.end());
 
     // Step 2: Create ETW session with callback and context
     etw_session_ = etw::Session::create(
@@ -35,14 +89,20 @@ bool Engine::start_monitoring(std::wstring_view exe_path) {
     );
     if (!etw_session_) {
         EXERAY_ERROR("Engine: Failed to create ETW session");
-        target_.reset();
+        {
+            std::lock_guard lock(target_mutex_);
+            target_.reset();
+            extra_targets_.clear();
+        }
+        consumer_ctx_.extra_target_pids.clear();
         target_pid_.store(0, std::memory_order_release);
-        return false;
+        return {};
     }
 
     // Step 3: Enable providers based on configuration
     {
         std::lock_guard lock(providers_mutex_);
+        session_providers_.clear();
         for (const auto& [name, cfg] : config_.providers) {
             if (!cfg.enabled) {
                 EXERAY_DEBUG("Provider {} is disabled, skipping", name);
@@ -57,61 +117,174 @@ bool Engine::start_monitoring(std::wstring_view exe_path) {
 
             // Use configured keywords, or all keywords if 0
             uint64_t keywords = (cfg.keywords == 0) ? 0xFFFFFFFFFFFFFFFF : cfg.keywords;
-            etw_session_->enable_provider(*guid, cfg.level, keywords);
+            if (!etw_session_->enable_provider(*guid, cfg.level, keywords)) {
+                EXERAY_WARN("Failed to enable provider {}", name);
+                continue;
+            }
+            session_providers_.push_back(name);
             EXERAY_DEBUG("Enabled provider {} (level={}, keywords=0x{:x})",
                          name, cfg.level, keywords);
         }
+        std::sort(session_providers_.begin(), session_providers_.end());
     }
 
     // Step 4: Set monitoring flag before starting thread
     monitoring_.store(true, std::memory_order_release);
 
-    // Step 5: Start ETW consumer thread
+    // Step 5: Start ETW consumer thread and the deadline watchdog
     etw_thread_ = std::thread(&Engine::etw_thread_func, this);
+    start_watchdog();
 
-    // Step 6: Resume the target process to start execution
-    target_->resume();
+    // Step 6: Resume the targets to start execution, or keep them
+    // frozen at entry until unfreeze_target()
+    if (freeze_on_start_.load(std::memory_order_acquire)) {
+        target_frozen_.store(true, std::memory_order_release);
+        frozen_at_entry_.store(true, std::memory_order_release);
+        EXERAY_INFO("Engine: {} target(s) frozen at entry", pids.size());
+    } else {
+        target_->resume();
+        for (auto& extra : extra_targets_) {
+            extra->resume();
+        }
+    }
 
-    return true;
+    return pids;
 #else
     // ETW not available on non-Windows platforms
-    (void)exe_path;
     EXERAY_ERROR("Engine: ETW monitoring not available on this platform");
-    return false;
+    return {};
 #endif
 }
 
 void Engine::stop_monitoring() {
-    if (!monitoring_.load(std::memory_order_acquire)) {
-        return;
+    // The watchdog may be stopping the session itself, so it finishes first
+    stop_watchdog();
+    stop_session();
+
+#ifdef _WIN32
+    // Terminate target processes if still running; a session that hit its
+    // deadline has already stopped capturing but still owns its targets
+    {
+        std::lock_guard lock(target_mutex_);
+        if (target_ && target_->is_running()) {
+            target_->terminate();
+        }
+        target_.reset();
+        for (auto& extra : extra_targets_) {
+            if (extra->is_running()) {
+                extra->terminate();
+            }
+        }
+        extra_targets_.clear();
     }
+    target_signature_.reset();
+    consumer_ctx_.extra_target_pids.clear();
+#endif
 
-    // Clear monitoring flag first
-    monitoring_.store(false, std::memory_order_release);
+    // Clear target PID
+    target_pid_.store(0, std::memory_order_release);
+    target_frozen_.store(false, std::memory_order_release);
+    frozen_at_entry_.store(false, std::memory_order_release);
+}
 
+void Engine::stop_session() {
 #ifdef _WIN32
-    // Step 1: Stop ETW session - this will cause ProcessTrace to return
-    // The Session destructor handles StopTrace/CloseTrace
-    etw_session_.reset();
+    std::unique_ptr<etw::Session> session;
+#endif
+    {
+        std::lock_guard lock(providers_mutex_);
+        if (!monitoring_.exchange(false, std::memory_order_acq_rel)) {
+            return;
+        }
+        session_providers_.clear();
+#ifdef _WIN32
+        session = std::move(etw_session_);
+#endif
+    }
 
-    // Step 2: Wait for ETW thread to finish
+#ifdef _WIN32
+    // Stopping the session makes ProcessTrace return. The lock is released
+    // first: the consumer thread may still be running event hooks.
+    session.reset();
     if (etw_thread_.joinable()) {
         etw_thread_.join();
     }
+#endif
+}
 
-    // Step 3: Terminate target process if still running
-    if (target_ && target_->is_running()) {
-        target_->terminate();
+void Engine::simulate_session() {
+    if (monitoring_.exchange(true, std::memory_order_acq_rel)) {
+        return;
     }
-    target_.reset();
-#endif
+    reset_target_exit();
+    start_watchdog();
+}
 
-    // Clear target PID
-    target_pid_.store(0, std::memory_order_release);
+void Engine::reset_target_exit() {
+    target_exited_.store(false, std::memory_order_release);
+    target_killed_.store(false, std::memory_order_release);
+    exit_pid_.store(0, std::memory_order_relaxed);
+    exit_code_.store(0, std::memory_order_relaxed);
 }
 
 bool Engine::is_monitoring() const noexcept {
     return monitoring_.load(std::memory_order_acquire);
 }
 
+std::size_t Engine::flush() {
+    const auto before = committed_events(graph_);
+    {
+        std::lock_guard lock(providers_mutex_);
+        if (!monitoring_.load(std::memory_order_acquire) || !etw_session_ ||
+            !etw_session_->flush()) {
+            return 0;
+        }
+    }
+
+    // Wait until the consumer goes one poll interval without new events
+    const auto deadline = std::chrono::steady_clock::now() + kFlushTimeout;
+    auto last = committed_events(graph_);
+    while (std::chrono::steady_clock::now() < deadline) {
+        std::this_thread::sleep_for(kFlushPollInterval);
+        const auto current = committed_events(graph_);
+        if (current == last) {
+            break;
+        }
+        last = current;
+    }
+
+    return last - before;
+}
+
+void Engine::set_freeze_on_start(bool enabled) noexcept {
+    freeze_on_start_.store(enabled, std::memory_order_release);
+}
+
+bool Engine::freeze_on_start() const noexcept {
+    return freeze_on_start_.load(std::memory_order_acquire);
+}
+
+bool Engine::frozen_at_entry() const noexcept {
+    return frozen_at_entry_.load(std::memory_order_acquire);
+}
+
+void Engine::set_capture_output(bool enabled) noexcept {
+    capture_output_.store(enabled, std::memory_order_release);
+}
+
+bool Engine::capture_output() const noexcept {
+    return capture_output_.load(std::memory_order_acquire);
+}
+
+bool Engine::capturing_target_output() const noexcept {
+    return target_ && target_->capturing_output();
+}
+
+std::vector<std::string> Engine::take_target_output() const {
+    if (!capturing_target_output()) {
+        return {};
+    }
+    return target_->take_output();
+}
+
 }  // namespace exeray
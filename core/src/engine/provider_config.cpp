@@ -1,9 +1,14 @@
 /// @file engine/provider_config.cpp
-/// @brief Provider configuration API: enable_provider, disable_provider, is_provider_enabled.
+/// @brief Provider configuration API: enable_provider, disable_provider, is_provider_enabled,
+///        update_provider, enabled_providers.
 
 #include "exeray/engine.hpp"
+#include "exeray/etw/provider_mapping.hpp"
+#include "exeray/etw/session.hpp"
 #include "exeray/logging.hpp"
 
+#include <algorithm>
+
 namespace exeray {
 
 void Engine::enable_provider(std::string_view name) {
@@ -40,4 +45,49 @@ bool Engine::is_provider_enabled(std::string_view name) const {
     return false;
 }
 
+bool Engine::update_provider(std::string_view name, bool enabled) {
+    std::lock_guard lock(providers_mutex_);
+    auto it = config_.providers.find(std::string(name));
+    if (it == config_.providers.end()) {
+        EXERAY_WARN("update_provider: Unknown provider '{}'", name);
+        return false;
+    }
+    it->second.enabled = enabled;
+
+#ifdef _WIN32
+    if (!is_monitoring() || !etw_session_) {
+        return true;
+    }
+    auto guid = etw::get_provider_guid(name);
+    if (!guid) {
+        return false;
+    }
+
+    auto live = std::lower_bound(session_providers_.begin(), session_providers_.end(), it->first);
+    const bool running = live != session_providers_.end() && *live == it->first;
+    if (enabled && !running) {
+        const auto& cfg = it->second;
+        uint64_t keywords = (cfg.keywords == 0) ? 0xFFFFFFFFFFFFFFFF : cfg.keywords;
+        if (!etw_session_->enable_provider(*guid, cfg.level, keywords)) {
+            EXERAY_WARN("Failed to enable provider {}", name);
+            return false;
+        }
+        session_providers_.insert(live, it->first);
+    } else if (!enabled && running) {
+        etw_session_->disable_provider(*guid);
+        session_providers_.erase(live);
+    }
+    EXERAY_DEBUG("Provider {} {} on the live session", name, enabled ? "enabled" : "disabled");
+#endif
+    return true;
+}
+
+std::vector<std::string> Engine::enabled_providers() const {
+    if (!is_monitoring()) {
+        return {};
+    }
+    std::lock_guard lock(providers_mutex_);
+    return session_providers_;
+}
+
 }  // namespace exeray
@@ -0,0 +1,19 @@
+/// @file engine/stats.cpp
+/// @brief Engine counter snapshot: stats.
+
+#include "exeray/engine.hpp"
+
+namespace exeray {
+
+EngineStats Engine::stats() const {
+    EngineStats stats;
+    stats.event_count = graph_.count();
+    stats.events_dropped = graph_.dropped();
+    stats.buffers_lost = etw_session_ ? etw_session_->buffers_lost() : 0;
+    stats.busy_threads = pool_.busy();
+    stats.queue_depth = pool_.pending();
+    stats.arena_used_bytes = arena_.used();
+    return stats;
+}
+
+}  // namespace exeray
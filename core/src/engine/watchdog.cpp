@@ -0,0 +1,67 @@
+/// @file engine/watchdog.cpp
+/// @brief Capture deadline: a watchdog thread that ends capture after
+///        max_duration().
+
+#include "exeray/engine.hpp"
+#include "exeray/logging.hpp"
+
+namespace exeray {
+
+void Engine::set_max_duration(std::chrono::nanoseconds limit) noexcept {
+    max_duration_ns_.store(limit.count(), std::memory_order_release);
+}
+
+std::chrono::nanoseconds Engine::max_duration() const noexcept {
+    return std::chrono::nanoseconds(max_duration_ns_.load(std::memory_order_acquire));
+}
+
+bool Engine::timed_out() const noexcept {
+    return timed_out_.load(std::memory_order_acquire);
+}
+
+void Engine::start_watchdog() {
+    timed_out_.store(false, std::memory_order_release);
+    const auto limit = max_duration();
+    if (limit <= std::chrono::nanoseconds::zero()) {
+        return;
+    }
+
+    // A watchdog that already fired has finished but is still joinable
+    if (watchdog_thread_.joinable()) {
+        watchdog_thread_.join();
+    }
+    {
+        std::lock_guard lock(watchdog_mutex_);
+        watchdog_stop_ = false;
+    }
+    watchdog_thread_ = std::thread([this, limit] {
+        std::unique_lock lock(watchdog_mutex_);
+        if (watchdog_cv_.wait_for(lock, limit, [this] { return watchdog_stop_; })) {
+            return;
+        }
+        lock.unlock();
+        on_deadline();
+    });
+}
+
+void Engine::stop_watchdog() {
+    {
+        std::lock_guard lock(watchdog_mutex_);
+        watchdog_stop_ = true;
+    }
+    watchdog_cv_.notify_all();
+    if (watchdog_thread_.joinable()) {
+        watchdog_thread_.join();
+    }
+}
+
+void Engine::on_deadline() {
+    timed_out_.store(true, std::memory_order_release);
+    freeze_target();
+    stop_session();
+
+    EXERAY_WARN("Engine: Capture deadline of {} ms reached, target frozen and capture stopped",
+                std::chrono::duration_cast<std::chrono::milliseconds>(max_duration()).count());
+}
+
+}  // namespace exeray
@@ -19,6 +19,7 @@
 #include "exeray/event/graph.hpp"
 #include "exeray/event/types.hpp"
 
+#include <algorithm>
 #include <atomic>
 #include <cstdint>
 #include <iostream>
@@ -97,8 +98,10 @@ void WINAPI event_record_callback(PEVENT_RECORD record) {
     const uint32_t target = ctx->target_pid->load(std::memory_order_acquire);
     
     // If target_pid is 0, accept all events (no filter)
-    // Otherwise, only accept events from the target process
-    if (target != 0 && event_pid != target) {
+    // Otherwise, only accept events from the target processes
+    if (target != 0 && event_pid != target &&
+        std::find(ctx->extra_target_pids.begin(), ctx->extra_target_pids.end(),
+                  event_pid) == ctx->extra_target_pids.end()) {
         return;
     }
 
@@ -121,14 +124,16 @@ void WINAPI event_record_callback(PEVENT_RECORD record) {
         correlation_id = ctx->correlator->get_correlation_id(pid, parent_pid);
     }
 
-    // Push to the event graph
+    // Push to the event graph, tagged with the emitting process so targets
+    // can be separated
     event::EventId event_id = ctx->graph->push(
         parsed.category,
         parsed.operation,
         parsed.status,
         parent_event,
         correlation_id,
-        parsed.payload
+        parsed.payload,
+        event_pid
     );
 
     // Register the event for future correlation lookups
@@ -139,6 +144,10 @@ void WINAPI event_record_callback(PEVENT_RECORD record) {
             ctx->correlator->register_process(parsed.payload.process.pid, event_id);
         }
     }
+
+    if (ctx->on_event && event_id != event::INVALID_EVENT) {
+        ctx->on_event(event_id);
+    }
 }
 
 ULONG start_trace_processing(TRACEHANDLE trace_handle) {
@@ -8,6 +8,7 @@
 #include "exeray/etw/parser_utils.hpp"
 #include "exeray/etw/session.hpp"
 #include "exeray/etw/tdh_parser.hpp"
+#include "exeray/event/string_pool.hpp"
 
 #include <cstring>
 
@@ -28,19 +29,86 @@ void init_network_payload(ParsedEvent& result) {
     result.payload.network.remote_port = 0;
     result.payload.network.bytes = 0;
     result.payload.network.protocol = 0;
+    result.payload.network.family = event::AddressFamily::Unknown;
     std::memset(result.payload.network._pad, 0, sizeof(result.payload.network._pad));
 }
 
-/// @brief Parse TCP connection event.
+/// Address families (winsock values).
+constexpr uint16_t FAMILY_INET = 2;
+constexpr uint16_t FAMILY_INET6 = 23;
+
+/// @brief Store a 16-byte remote address as its interned text.
 ///
-/// Common UserData layout for TCP events:
-///   ProcessId: UINT32
+/// Without a string pool the address cannot be kept, so the family stays
+/// Unknown and the payload carries no remote address.
+void set_remote_ipv6(ParsedEvent& result, const uint8_t* addr, event::StringPool* strings) {
+    if (strings == nullptr) {
+        return;
+    }
+    result.payload.network.remote_addr = strings->intern(format_ipv6(addr));
+    result.payload.network.family = event::AddressFamily::IPv6;
+}
+
+/// @brief Parse the address block shared by TCP events.
+///
+/// Layout from @p offset:
 ///   AddressFamily: UINT16
 ///   LocalAddr: 4 bytes (IPv4) or 16 bytes (IPv6)
 ///   LocalPort: UINT16
 ///   RemoteAddr: 4 bytes (IPv4) or 16 bytes (IPv6)
 ///   RemotePort: UINT16
-ParsedEvent parse_tcp_connect(const EVENT_RECORD* record) {
+///
+/// IPv6 local addresses are not kept; local_addr only holds IPv4.
+/// Leaves the payload untouched if the block is truncated or the family
+/// is neither IPv4 nor IPv6.
+void parse_endpoints(ParsedEvent& result, const uint8_t* data, size_t len, size_t offset,
+                     event::StringPool* strings) {
+    if (offset + sizeof(uint16_t) > len) {
+        return;
+    }
+    uint16_t af = 0;
+    std::memcpy(&af, data + offset, sizeof(uint16_t));
+    offset += sizeof(uint16_t);
+
+    size_t addr_size = 0;
+    if (af == FAMILY_INET) {
+        addr_size = sizeof(uint32_t);
+    } else if (af == FAMILY_INET6) {
+        addr_size = 16;
+    } else {
+        return;
+    }
+    if (offset + 2 * (addr_size + sizeof(uint16_t)) > len) {
+        return;
+    }
+
+    const uint8_t* local_addr = data + offset;
+    offset += addr_size;
+    uint16_t local_port = 0;
+    std::memcpy(&local_port, data + offset, sizeof(uint16_t));
+    offset += sizeof(uint16_t);
+    const uint8_t* remote_addr = data + offset;
+    offset += addr_size;
+    uint16_t remote_port = 0;
+    std::memcpy(&remote_port, data + offset, sizeof(uint16_t));
+
+    result.payload.network.local_port = local_port;
+    result.payload.network.remote_port = remote_port;
+    if (af == FAMILY_INET) {
+        std::memcpy(&result.payload.network.local_addr, local_addr, sizeof(uint32_t));
+        std::memcpy(&result.payload.network.remote_addr, remote_addr, sizeof(uint32_t));
+        result.payload.network.family = event::AddressFamily::IPv4;
+    } else {
+        set_remote_ipv6(result, remote_addr, strings);
+    }
+}
+
+/// @brief Parse TCP connection event.
+///
+/// Common UserData layout for TCP events:
+///   ProcessId: UINT32
+///   AddressFamily, addresses and ports (see parse_endpoints)
+ParsedEvent parse_tcp_connect(const EVENT_RECORD* record, event::StringPool* strings) {
     ParsedEvent result{};
     extract_common(record, result, event::Category::Network);
     result.operation = static_cast<uint8_t>(event::NetworkOp::Connect);
@@ -56,46 +124,20 @@ ParsedEvent parse_tcp_connect(const EVENT_RECORD* record) {
         return result;
     }
 
-    size_t offset = 0;
-
-    // PID
-    uint32_t pid = 0;
-    std::memcpy(&pid, data + offset, sizeof(uint32_t));
-    result.payload.network.local_addr = 0;  // Will be populated below
-    offset += sizeof(uint32_t);
-
-    // Address family
-    uint16_t af = 0;
-    std::memcpy(&af, data + offset, sizeof(uint16_t));
-    offset += sizeof(uint16_t);
-
-    // IPv4 only (af == 2)
-    if (af == 2 && offset + 12 <= len) {
-        uint32_t local_addr = 0;
-        uint16_t local_port = 0;
-        uint32_t remote_addr = 0;
-        uint16_t remote_port = 0;
-
-        std::memcpy(&local_addr, data + offset, sizeof(uint32_t));
-        offset += sizeof(uint32_t);
-        std::memcpy(&local_port, data + offset, sizeof(uint16_t));
-        offset += sizeof(uint16_t);
-        std::memcpy(&remote_addr, data + offset, sizeof(uint32_t));
-        offset += sizeof(uint32_t);
-        std::memcpy(&remote_port, data + offset, sizeof(uint16_t));
-
-        result.payload.network.local_addr = local_addr;
-        result.payload.network.local_port = local_port;
-        result.payload.network.remote_addr = remote_addr;
-        result.payload.network.remote_port = remote_port;
-    }
+    parse_endpoints(result, data, len, sizeof(uint32_t), strings);
 
     result.valid = true;
     return result;
 }
 
 /// @brief Parse TCP data transfer event (send/receive).
-ParsedEvent parse_tcp_transfer(const EVENT_RECORD* record, event::NetworkOp op) {
+///
+/// UserData layout:
+///   ProcessId: UINT32
+///   Size: UINT32
+///   AddressFamily, addresses and ports (see parse_endpoints), if present
+ParsedEvent parse_tcp_transfer(const EVENT_RECORD* record, event::NetworkOp op,
+                               event::StringPool* strings) {
     ParsedEvent result{};
     extract_common(record, result, event::Category::Network);
     result.operation = static_cast<uint8_t>(op);
@@ -110,13 +152,11 @@ ParsedEvent parse_tcp_transfer(const EVENT_RECORD* record, event::NetworkOp op)
         return result;
     }
 
-    // Transfer events typically have: pid, size, connection info
-    // Extract size from common position
     uint32_t bytes = 0;
-    if (len >= 8) {
-        std::memcpy(&bytes, data + 4, sizeof(uint32_t));
-        result.payload.network.bytes = bytes;
-    }
+    std::memcpy(&bytes, data + 4, sizeof(uint32_t));
+    result.payload.network.bytes = bytes;
+
+    parse_endpoints(result, data, len, 2 * sizeof(uint32_t), strings);
 
     result.valid = true;
     return result;
@@ -145,11 +185,11 @@ ParsedEvent parse_network_event(const EVENT_RECORD* record, event::StringPool* s
     switch (event_id) {
         case ids::network::TCP_CONNECT:
         case ids::network::TCP_ACCEPT:
-            return parse_tcp_connect(record);
+            return parse_tcp_connect(record, strings);
         case ids::network::TCP_SEND:
-            return parse_tcp_transfer(record, event::NetworkOp::Send);
+            return parse_tcp_transfer(record, event::NetworkOp::Send, strings);
         case ids::network::TCP_RECEIVE:
-            return parse_tcp_transfer(record, event::NetworkOp::Receive);
+            return parse_tcp_transfer(record, event::NetworkOp::Receive, strings);
         case ids::network::UDP_SEND:
             return parse_udp_event(record, event::NetworkOp::Send);
         case ids::network::UDP_RECEIVE:
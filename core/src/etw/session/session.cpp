@@ -1,5 +1,5 @@
 /// @file session.cpp
-/// @brief Session class constructors, destructor, and operators.
+/// @brief Session class constructors, destructor, operators, flush, and loss queries.
 
 #ifdef _WIN32
 
@@ -74,6 +74,43 @@ Session::~Session() {
     }
 }
 
+bool Session::flush() {
+    if (session_handle_ == 0) {
+        return false;
+    }
+
+    std::vector<uint8_t> buffer(session::properties_buffer_size(), 0);
+    auto* props = reinterpret_cast<EVENT_TRACE_PROPERTIES*>(buffer.data());
+    props->Wnode.BufferSize = static_cast<ULONG>(buffer.size());
+    props->LoggerNameOffset = sizeof(EVENT_TRACE_PROPERTIES);
+
+    ULONG status = ControlTraceW(session_handle_, nullptr, props,
+                                 EVENT_TRACE_CONTROL_FLUSH);
+    if (status != ERROR_SUCCESS) {
+        session::log_error(L"FlushTrace", status);
+        return false;
+    }
+    return true;
+}
+
+std::uint64_t Session::buffers_lost() const {
+    if (session_handle_ == 0) {
+        return 0;
+    }
+
+    std::vector<uint8_t> buffer(session::properties_buffer_size(), 0);
+    auto* props = reinterpret_cast<EVENT_TRACE_PROPERTIES*>(buffer.data());
+    props->Wnode.BufferSize = static_cast<ULONG>(buffer.size());
+    props->LoggerNameOffset = sizeof(EVENT_TRACE_PROPERTIES);
+
+    if (ControlTraceW(session_handle_, nullptr, props,
+                      EVENT_TRACE_CONTROL_QUERY) != ERROR_SUCCESS) {
+        return 0;
+    }
+    return static_cast<std::uint64_t>(props->RealTimeBuffersLost) +
+           props->LogBuffersLost;
+}
+
 }  // namespace exeray::etw
 
 #endif  // _WIN32
@@ -6,6 +6,7 @@
 #include "exeray/etw/tdh/converters.hpp"
 #include "exeray/etw/tdh/internal.hpp"
 #include "exeray/etw/parser_utils.hpp"
+#include "exeray/event/string_pool.hpp"
 
 namespace exeray::etw {
 
@@ -14,7 +15,7 @@ using namespace tdh::detail;
 ParsedEvent convert_tdh_to_network(
     const TdhParsedEvent& tdh_event,
     const EVENT_RECORD* record,
-    event::StringPool* /*strings*/
+    event::StringPool* strings
 ) {
     ParsedEvent result{};
     extract_common(record, result, event::Category::Network);
@@ -44,8 +45,19 @@ ParsedEvent convert_tdh_to_network(
         get_uint32_prop(tdh_event, L"sport"));
     result.payload.network.remote_port = static_cast<uint16_t>(
         get_uint32_prop(tdh_event, L"dport"));
-    result.payload.network.local_addr = get_uint32_prop(tdh_event, L"saddr");
-    result.payload.network.remote_addr = get_uint32_prop(tdh_event, L"daddr");
+
+    // Events 26-29 are the IPv6 variants, with 16-byte binary addresses
+    if (tdh_event.event_id >= 26) {
+        const auto daddr = get_binary_prop(tdh_event, L"daddr");
+        if (daddr.size() == 16 && strings != nullptr) {
+            result.payload.network.remote_addr = strings->intern(format_ipv6(daddr.data()));
+            result.payload.network.family = event::AddressFamily::IPv6;
+        }
+    } else {
+        result.payload.network.local_addr = get_uint32_prop(tdh_event, L"saddr");
+        result.payload.network.remote_addr = get_uint32_prop(tdh_event, L"daddr");
+        result.payload.network.family = event::AddressFamily::IPv4;
+    }
     
     result.valid = true;
     return result;
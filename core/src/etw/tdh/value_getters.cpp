@@ -46,6 +46,16 @@ uint64_t get_uint64_prop(const TdhParsedEvent& event, const std::wstring& name)
     return 0;
 }
 
+std::vector<uint8_t> get_binary_prop(const TdhParsedEvent& event, const std::wstring& name) {
+    auto it = event.properties.find(name);
+    if (it != event.properties.end()) {
+        if (auto* bytes = std::get_if<std::vector<uint8_t>>(&it->second)) {
+            return *bytes;
+        }
+    }
+    return {};
+}
+
 }  // namespace exeray::etw::tdh::detail
 
 #else  // !_WIN32
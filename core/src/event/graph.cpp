@@ -3,6 +3,7 @@
 #include <cassert>
 #include <chrono>
 #include <cstring>
+#include <stdexcept>
 
 namespace exeray::event {
 
@@ -10,67 +11,100 @@ EventGraph::EventGraph(Arena& arena, StringPool& strings, std::size_t capacity)
     : arena_(arena),
       strings_(strings),
       nodes_(arena.allocate<EventNode>(capacity)),
-      capacity_(capacity) {
+      target_pids_(arena.allocate<std::uint32_t>(capacity)),
+      generations_(arena.allocate<std::uint64_t>(capacity)),
+      capacity_(capacity),
+      max_events_(capacity) {
+    if (capacity == 0) {
+        throw std::invalid_argument("EventGraph capacity must be non-zero");
+    }
     // Initialize nodes memory to zero for debug consistency
     if (nodes_ != nullptr) {
         std::memset(nodes_, 0, sizeof(EventNode) * capacity);
     }
+    if (target_pids_ != nullptr) {
+        std::memset(target_pids_, 0, sizeof(std::uint32_t) * capacity);
+    }
+    if (generations_ != nullptr) {
+        std::memset(generations_, 0, sizeof(std::uint64_t) * capacity);
+    }
 }
 
 EventId EventGraph::push(Category cat, std::uint8_t op, Status status,
                          EventId parent, uint32_t correlation_id,
-                         const EventPayload& payload) {
-    // Reserve a slot atomically
-    const auto index = count_.fetch_add(1, std::memory_order_acq_rel);
-
-    // Check capacity
-    if (index >= capacity_) {
-        // Rollback count if we exceeded capacity
-        count_.fetch_sub(1, std::memory_order_relaxed);
+                         const EventPayload& payload, std::uint32_t target_pid,
+                         std::uint16_t flags) {
+    if (severity(status) < severity(min_status_.load(std::memory_order_relaxed))) {
+        filtered_.fetch_add(1, std::memory_order_relaxed);
         return INVALID_EVENT;
     }
+    if (!allocated()) {
+        return INVALID_EVENT;
+    }
+
+    // The slot is reserved, written and published under the lock, so
+    // eviction never sees a slot that is reserved but not yet written
+    std::unique_lock lock(mutex_);
 
-    // Generate unique ID atomically
+    const bool ring = ring_.load(std::memory_order_acquire);
+    const auto live = count_.load(std::memory_order_relaxed);
+    if (!ring && (live >= capacity_ || live >= max_events_.load(std::memory_order_relaxed))) {
+        dropped_.fetch_add(1, std::memory_order_relaxed);
+        return INVALID_EVENT;
+    }
+
+    // IDs map to slots as (id - 1) % capacity, so slots freed by eviction
+    // are reused; outside ring mode the next slot is always free
     const auto id = next_id_.fetch_add(1, std::memory_order_relaxed);
+    const auto slot = static_cast<std::size_t>((id - 1) % capacity_);
+    EventNode& node = nodes_[slot];
+
+    // Ring mode: evict the previous occupant of this slot
+    const bool overwrite = node.id != INVALID_EVENT;
+    if (overwrite) {
+        unindex_slot(slot);
+        oldest_id_.store(node.id + 1, std::memory_order_release);
+    }
 
-    // Get current timestamp
     const auto now = std::chrono::steady_clock::now();
-    const auto timestamp = static_cast<Timestamp>(
+    node.id = id;
+    node.parent_id = parent;
+    node.timestamp = static_cast<Timestamp>(
         std::chrono::duration_cast<std::chrono::nanoseconds>(
             now.time_since_epoch())
             .count());
-
-    // Write event data to reserved slot
-    EventNode& node = nodes_[index];
-    node.id = id;
-    node.parent_id = parent;
-    node.timestamp = timestamp;
     node.status = status;
     node.operation = op;
     node.correlation_id = correlation_id;
-    std::memset(node._pad, 0, sizeof(node._pad));
+    node.flags = flags;
+    const auto generation = generation_.load(std::memory_order_relaxed) + 1;
+    target_pids_[slot] = target_pid;
+    generations_[slot] = generation;
 
     // Copy payload - category must already match the expected category
     assert(payload.category == cat && "payload.category must match cat parameter");
     node.payload = payload;
 
-    // Update indexes under lock
-    {
-        std::unique_lock lock(mutex_);
-        if (parent != INVALID_EVENT) {
-            parent_index_.emplace(parent, index);
-        }
-        if (correlation_id != 0) {
-            correlation_index_.emplace(correlation_id, index);
-        }
+    if (parent != INVALID_EVENT) {
+        parent_index_.emplace(parent, slot);
+    }
+    if (correlation_id != 0) {
+        correlation_index_.emplace(correlation_id, slot);
     }
 
+    // Publish last: readers checking exists() only see written slots, and
+    // readers of the generation see every event tagged up to it
+    if (!overwrite) {
+        count_.fetch_add(1, std::memory_order_release);
+    }
+    generation_.store(generation, std::memory_order_release);
+
     return id;
 }
 
 EventView EventGraph::get(EventId id) const {
-    // EventId starts at 1, so index = id - 1
-    const auto index = id - 1;
+    // EventId starts at 1, so index = id - 1 (wrapped in ring mode)
+    const auto index = static_cast<std::size_t>((id - 1) % capacity_);
     return EventView(&nodes_[index]);
 }
 
@@ -78,15 +112,182 @@ bool EventGraph::exists(EventId id) const noexcept {
     if (id == INVALID_EVENT) {
         return false;
     }
+    // Live IDs are contiguous from the oldest (1 until something is evicted)
+    const auto current_count = count_.load(std::memory_order_acquire);
+    const auto oldest = oldest_id_.load(std::memory_order_acquire);
+    return id >= oldest && id < oldest + current_count;
+}
+
+std::size_t EventGraph::evict_before(Timestamp cutoff) {
+    std::unique_lock lock(mutex_);
+
+    std::size_t evicted = 0;
+    while (count_.load(std::memory_order_acquire) > 0) {
+        const auto oldest = oldest_id_.load(std::memory_order_acquire);
+        const auto index = static_cast<std::size_t>((oldest - 1) % capacity_);
+        if (nodes_[index].timestamp >= cutoff) {
+            break;
+        }
+        evict_front();
+        ++evicted;
+    }
+    return evicted;
+}
+
+std::size_t EventGraph::evict_oldest(std::size_t n) {
+    std::unique_lock lock(mutex_);
+
+    std::size_t evicted = 0;
+    while (evicted < n && count_.load(std::memory_order_acquire) > 0) {
+        evict_front();
+        ++evicted;
+    }
+    return evicted;
+}
+
+void EventGraph::evict_front() {
+    const auto oldest = oldest_id_.load(std::memory_order_acquire);
+    const auto index = static_cast<std::size_t>((oldest - 1) % capacity_);
+    unindex_slot(index);
+    nodes_[index].id = INVALID_EVENT;
+    target_pids_[index] = 0;
+    generations_[index] = 0;
+    oldest_id_.store(oldest + 1, std::memory_order_release);
+    count_.fetch_sub(1, std::memory_order_acq_rel);
+}
+
+void EventGraph::unindex_slot(std::size_t index) {
+    const EventNode& node = nodes_[index];
+    auto erase_slot = [index](auto& multimap, auto key) {
+        auto [first, last] = multimap.equal_range(key);
+        for (auto it = first; it != last; ++it) {
+            if (it->second == index) {
+                multimap.erase(it);
+                return;
+            }
+        }
+    };
+    if (node.parent_id != INVALID_EVENT) {
+        erase_slot(parent_index_, node.parent_id);
+    }
+    if (node.correlation_id != 0) {
+        erase_slot(correlation_index_, node.correlation_id);
+    }
+    resolved_.erase(node.id);
+}
+
+bool EventGraph::resolve(EventId id, Status status) {
+    if (status == Status::Pending || !exists(id)) {
+        return false;
+    }
+
+    std::unique_lock lock(mutex_);
+    EventNode& node = nodes_[static_cast<std::size_t>((id - 1) % capacity_)];
+    if (node.id != id || node.status != Status::Pending) {
+        return false;
+    }
+
+    const auto now = std::chrono::steady_clock::now();
+    node.status = status;
+    resolved_[id] = static_cast<Timestamp>(
+        std::chrono::duration_cast<std::chrono::nanoseconds>(
+            now.time_since_epoch())
+            .count());
+    return true;
+}
+
+Timestamp EventGraph::resolved_at(EventId id) const {
+    std::shared_lock lock(mutex_);
+    auto it = resolved_.find(id);
+    return it != resolved_.end() ? it->second : 0;
+}
+
+std::uint32_t EventGraph::target_pid(EventId id) const noexcept {
+    if (!exists(id)) {
+        return 0;
+    }
+    return target_pids_[static_cast<std::size_t>((id - 1) % capacity_)];
+}
+
+std::uint64_t EventGraph::generation(EventId id) const noexcept {
+    if (!exists(id)) {
+        return 0;
+    }
+    return generations_[static_cast<std::size_t>((id - 1) % capacity_)];
+}
+
+std::size_t EventGraph::copy_range(std::size_t start, EventRecord* out,
+                                   std::size_t max) const {
+    std::shared_lock lock(mutex_);
     const auto current_count = count_.load(std::memory_order_acquire);
-    // ID starts at 1, so valid IDs are 1..current_count
-    return id <= current_count;
+    const auto oldest = oldest_id_.load(std::memory_order_acquire);
+    std::size_t copied = 0;
+    for (std::size_t position = start; position < current_count && copied < max; ++position) {
+        const auto slot = static_cast<std::size_t>((oldest - 1 + position) % capacity_);
+        out[copied++] = EventRecord{nodes_[slot], target_pids_[slot], generations_[slot]};
+    }
+    return copied;
+}
+
+std::uint64_t EventGraph::current_generation() const noexcept {
+    return generation_.load(std::memory_order_acquire);
+}
+
+std::uint64_t EventGraph::advance_generation() {
+    std::unique_lock lock(mutex_);
+    return generation_.fetch_add(1, std::memory_order_release) + 1;
 }
 
 std::size_t EventGraph::count() const noexcept {
     return count_.load(std::memory_order_acquire);
 }
 
+std::uint64_t EventGraph::dropped() const noexcept {
+    return dropped_.load(std::memory_order_relaxed);
+}
+
+void EventGraph::set_max_events(std::size_t max_events) noexcept {
+    const auto cap = (max_events == 0 || max_events > capacity_) ? capacity_ : max_events;
+    max_events_.store(cap, std::memory_order_relaxed);
+}
+
+std::size_t EventGraph::max_events() const noexcept {
+    return max_events_.load(std::memory_order_relaxed);
+}
+
+void EventGraph::set_min_status(Status status) noexcept {
+    min_status_.store(status, std::memory_order_relaxed);
+}
+
+Status EventGraph::min_status() const noexcept {
+    return min_status_.load(std::memory_order_relaxed);
+}
+
+std::uint64_t EventGraph::filtered() const noexcept {
+    return filtered_.load(std::memory_order_relaxed);
+}
+
+void EventGraph::set_ring_buffer(bool enabled) noexcept {
+    strings_.set_max_bytes(enabled ? capacity_ * kRingStringBytesPerEvent : 0);
+    ring_.store(enabled, std::memory_order_release);
+}
+
+bool EventGraph::ring_buffer() const noexcept {
+    return ring_.load(std::memory_order_acquire);
+}
+
+EventId EventGraph::oldest_id() const noexcept {
+    if (count_.load(std::memory_order_acquire) == 0) {
+        return INVALID_EVENT;
+    }
+    return oldest_id_.load(std::memory_order_acquire);
+}
+
+const EventNode& EventGraph::node_at(std::size_t position) const noexcept {
+    const auto oldest = oldest_id_.load(std::memory_order_acquire);
+    return nodes_[static_cast<std::size_t>((oldest - 1 + position) % capacity_)];
+}
+
 std::string_view EventGraph::resolve_string(StringId id) const {
     return strings_.get(id);
 }
@@ -30,6 +30,10 @@ StringId StringPool::intern(std::string_view str) {
     // Allocate: [len:u32][chars...]
     const auto len = static_cast<std::uint32_t>(str.size());
     const std::size_t total_size = sizeof(std::uint32_t) + str.size();
+    const auto max_bytes = max_bytes_.load(std::memory_order_relaxed);
+    if (max_bytes != 0 && bytes_used_ + total_size > max_bytes) {
+        return INVALID_STRING;
+    }
 
     auto* storage = arena_.allocate<std::uint8_t>(total_size);
     if (storage == nullptr) {
@@ -38,7 +42,7 @@ StringId StringPool::intern(std::string_view str) {
 
     // StringId = offset + 1 (so offset 0 maps to ID 1, never returning 0)
     const auto id = static_cast<StringId>(
-        static_cast<std::size_t>(storage - arena_.base()) + 1);
+        arena_.offset_of(storage) + 1);
 
     // Write length prefix
     std::memcpy(storage, &len, sizeof(len));
@@ -129,7 +133,7 @@ std::string_view StringPool::get(StringId id) const noexcept {
     }
 
     // ID = offset + 1, so offset = ID - 1
-    const auto* storage = arena_.base() + (id - 1);
+    const auto* storage = arena_.at(id - 1);
 
     std::uint32_t len = 0;
     std::memcpy(&len, storage, sizeof(len));
@@ -147,4 +151,12 @@ std::size_t StringPool::bytes_used() const noexcept {
     return bytes_used_;
 }
 
+void StringPool::set_max_bytes(std::size_t max_bytes) noexcept {
+    max_bytes_.store(max_bytes, std::memory_order_relaxed);
+}
+
+std::size_t StringPool::max_bytes() const noexcept {
+    return max_bytes_.load(std::memory_order_relaxed);
+}
+
 }  // namespace exeray::event
@@ -4,13 +4,18 @@
 #include "exeray/process/controller.hpp"
 #include "exeray/logging.hpp"
 
+#include <cstddef>
+#include <iterator>
 #include <string>
+#include <utility>
 
 #ifdef _WIN32
 #ifndef WIN32_LEAN_AND_MEAN
 #define WIN32_LEAN_AND_MEAN
 #endif
 #include <windows.h>
+#include <tlhelp32.h>
+#include <winternl.h>
 #endif
 
 namespace exeray::process {
@@ -23,6 +28,136 @@ void log_error(const char* function) {
     DWORD error = GetLastError();
     EXERAY_ERROR("[exeray::process] {} failed with error {}", function, error);
 }
+
+/// @brief NtQueryInformationThread signature (resolved from ntdll at runtime).
+using NtQueryInformationThreadFn = LONG(NTAPI*)(HANDLE, ULONG, PVOID, ULONG, PULONG);
+
+/// @brief THREADINFOCLASS value for the Win32 start address.
+constexpr ULONG kThreadQuerySetWin32StartAddress = 9;
+
+/// @brief THREADINFOCLASS value for the suspend count (Windows 8.1+).
+constexpr ULONG kThreadSuspendCount = 35;
+
+/// @brief Query one fixed-size THREADINFOCLASS value.
+/// @return false if ntdll lacks the call or the query failed.
+bool query_thread(HANDLE thread, ULONG info_class, void* out, ULONG size) {
+    static const auto query = reinterpret_cast<NtQueryInformationThreadFn>(
+        GetProcAddress(GetModuleHandleW(L"ntdll.dll"), "NtQueryInformationThread"));
+    return query != nullptr && query(thread, info_class, out, size, nullptr) == 0;
+}
+
+/// @brief Query the Win32 start address of a thread.
+/// @return Start address, or 0 if unavailable.
+std::uint64_t thread_start_address(HANDLE thread) {
+    PVOID address = nullptr;
+    if (!query_thread(thread, kThreadQuerySetWin32StartAddress, &address, sizeof(address))) {
+        return 0;
+    }
+    return reinterpret_cast<std::uint64_t>(address);
+}
+
+/// @brief Check whether a thread's suspend count is nonzero.
+///
+/// Reads the count instead of probing with SuspendThread/ResumeThread,
+/// which would briefly stop a running thread.
+/// @return false if the thread is running or the count is unavailable.
+bool thread_suspended(HANDLE thread) {
+    ULONG count = 0;
+    return query_thread(thread, kThreadSuspendCount, &count, sizeof(count)) && count > 0;
+}
+
+/// @brief NtQueryInformationProcess signature (resolved from ntdll at runtime).
+using NtQueryInformationProcessFn = LONG(NTAPI*)(HANDLE, ULONG, PVOID, ULONG, PULONG);
+
+/// @brief CURDIR of the real RTL_USER_PROCESS_PARAMETERS.
+struct CurrentDirectory {
+    UNICODE_STRING dos_path;
+    HANDLE handle;
+};
+
+/// @brief Leading fields of the real RTL_USER_PROCESS_PARAMETERS.
+///
+/// winternl.h hides CurrentDirectory in reserved fields and stops the
+/// structure at CommandLine, so the layout is spelled out up to Environment.
+struct ProcessParameters {
+    ULONG maximum_length;
+    ULONG length;
+    ULONG flags;
+    ULONG debug_flags;
+    HANDLE console_handle;
+    ULONG console_flags;
+    HANDLE standard_input;
+    HANDLE standard_output;
+    HANDLE standard_error;
+    CurrentDirectory current_directory;
+    UNICODE_STRING dll_path;
+    UNICODE_STRING image_path_name;
+    UNICODE_STRING command_line;
+    PVOID environment;
+};
+static_assert(offsetof(ProcessParameters, image_path_name) ==
+              offsetof(RTL_USER_PROCESS_PARAMETERS, ImagePathName));
+static_assert(offsetof(ProcessParameters, command_line) ==
+              offsetof(RTL_USER_PROCESS_PARAMETERS, CommandLine));
+
+/// @brief Output lines kept until take_output(); older lines are dropped.
+constexpr std::size_t kMaxOutputLines = 10000;
+
+/// @brief Longest unterminated output line; longer runs are split.
+constexpr std::size_t kMaxOutputLineBytes = 64 * 1024;
+
+/// @brief Upper bound on the environment block read from a target.
+constexpr std::size_t kMaxEnvironmentBytes = 1 << 20;
+
+/// @brief Read the process parameters via PEB -> ProcessParameters.
+/// @return false if the target's memory cannot be read.
+bool read_process_parameters(HANDLE process, ProcessParameters& out) {
+    static const auto query = reinterpret_cast<NtQueryInformationProcessFn>(
+        GetProcAddress(GetModuleHandleW(L"ntdll.dll"), "NtQueryInformationProcess"));
+    if (query == nullptr) {
+        return false;
+    }
+    PROCESS_BASIC_INFORMATION basic{};
+    if (query(process, ProcessBasicInformation, &basic, sizeof(basic), nullptr) != 0 ||
+        basic.PebBaseAddress == nullptr) {
+        return false;
+    }
+    PEB peb{};
+    if (!ReadProcessMemory(process, basic.PebBaseAddress, &peb, sizeof(peb), nullptr)) {
+        log_error("ReadProcessMemory");
+        return false;
+    }
+    if (!ReadProcessMemory(process, peb.ProcessParameters, &out, sizeof(out), nullptr)) {
+        log_error("ReadProcessMemory");
+        return false;
+    }
+    return true;
+}
+
+/// @brief Convert a UTF-16 buffer to UTF-8.
+std::string narrow(const wchar_t* data, int len) {
+    if (len <= 0) {
+        return {};
+    }
+    int size = WideCharToMultiByte(CP_UTF8, 0, data, len, nullptr, 0, nullptr, nullptr);
+    if (size <= 0) {
+        return {};
+    }
+    std::string result(static_cast<std::size_t>(size), '\0');
+    WideCharToMultiByte(CP_UTF8, 0, data, len, result.data(), size, nullptr, nullptr);
+    return result;
+}
+
+/// @brief Read a UNICODE_STRING whose buffer lives in the target.
+/// @return UTF-8 text, empty on failure.
+std::string read_remote_string(HANDLE process, const UNICODE_STRING& str) {
+    std::wstring buffer(str.Length / sizeof(wchar_t), L'\0');
+    if (buffer.empty() ||
+        !ReadProcessMemory(process, str.Buffer, buffer.data(), str.Length, nullptr)) {
+        return {};
+    }
+    return narrow(buffer.data(), static_cast<int>(buffer.size()));
+}
 #endif
 
 }  // namespace
@@ -34,7 +169,8 @@ void log_error(const char* function) {
 std::unique_ptr<Controller> Controller::launch(
     [[maybe_unused]] std::wstring_view exe_path,
     [[maybe_unused]] std::wstring_view args,
-    [[maybe_unused]] std::wstring_view working_dir
+    [[maybe_unused]] std::wstring_view working_dir,
+    [[maybe_unused]] bool capture_output
 ) {
 #ifdef _WIN32
     // Create Job Object for process isolation
@@ -57,8 +193,50 @@ std::unique_ptr<Controller> Controller::launch(
     }
 
     // Prepare startup info
-    STARTUPINFOW si{};
-    si.cb = sizeof(si);
+    STARTUPINFOEXW si{};
+    si.StartupInfo.cb = sizeof(si);
+
+    // Optionally redirect stdout/stderr to an inheritable pipe
+    HANDLE output_read = nullptr;
+    HANDLE output_write = nullptr;
+    std::vector<std::uint8_t> attribute_storage;
+    if (capture_output) {
+        SECURITY_ATTRIBUTES sa{};
+        sa.nLength = sizeof(sa);
+        sa.bInheritHandle = TRUE;
+        if (!CreatePipe(&output_read, &output_write, &sa, 0)) {
+            log_error("CreatePipe");
+            CloseHandle(job);
+            return nullptr;
+        }
+        SetHandleInformation(output_read, HANDLE_FLAG_INHERIT, 0);
+
+        // Restrict inheritance to the write end; bInheritHandles alone would
+        // hand the target every inheritable handle of this process
+        SIZE_T attribute_size = 0;
+        InitializeProcThreadAttributeList(nullptr, 1, 0, &attribute_size);
+        attribute_storage.resize(attribute_size);
+        si.lpAttributeList =
+            reinterpret_cast<LPPROC_THREAD_ATTRIBUTE_LIST>(attribute_storage.data());
+        if (!InitializeProcThreadAttributeList(si.lpAttributeList, 1, 0, &attribute_size) ||
+            !UpdateProcThreadAttribute(si.lpAttributeList, 0, PROC_THREAD_ATTRIBUTE_HANDLE_LIST,
+                                       &output_write, sizeof(output_write), nullptr, nullptr)) {
+            log_error("UpdateProcThreadAttribute");
+            if (si.lpAttributeList != nullptr) {
+                DeleteProcThreadAttributeList(si.lpAttributeList);
+            }
+            CloseHandle(output_read);
+            CloseHandle(output_write);
+            CloseHandle(job);
+            return nullptr;
+        }
+
+        // The target gets no stdin, only the listed pipe is inherited
+        si.StartupInfo.dwFlags |= STARTF_USESTDHANDLES;
+        si.StartupInfo.hStdInput = nullptr;
+        si.StartupInfo.hStdOutput = output_write;
+        si.StartupInfo.hStdError = output_write;
+    }
 
     PROCESS_INFORMATION pi{};
 
@@ -76,16 +254,28 @@ std::unique_ptr<Controller> Controller::launch(
         cmd_line.data(),                  // lpCommandLine (writable)
         nullptr,                          // lpProcessAttributes
         nullptr,                          // lpThreadAttributes
-        FALSE,                            // bInheritHandles
-        CREATE_SUSPENDED,                 // dwCreationFlags
+        capture_output ? TRUE : FALSE,    // bInheritHandles (handle list only)
+        CREATE_SUSPENDED | (capture_output ? EXTENDED_STARTUPINFO_PRESENT : 0),
         nullptr,                          // lpEnvironment
         work_dir_ptr,                     // lpCurrentDirectory
-        &si,                              // lpStartupInfo
+        &si.StartupInfo,                  // lpStartupInfo
         &pi                               // lpProcessInformation
     );
 
+    if (si.lpAttributeList != nullptr) {
+        DeleteProcThreadAttributeList(si.lpAttributeList);
+    }
+
+    // The target holds its own copy of the write end now
+    if (output_write != nullptr) {
+        CloseHandle(output_write);
+    }
+
     if (!success) {
         log_error("CreateProcessW");
+        if (output_read != nullptr) {
+            CloseHandle(output_read);
+        }
         CloseHandle(job);
         return nullptr;
     }
@@ -96,6 +286,9 @@ std::unique_ptr<Controller> Controller::launch(
         TerminateProcess(pi.hProcess, 1);
         CloseHandle(pi.hThread);
         CloseHandle(pi.hProcess);
+        if (output_read != nullptr) {
+            CloseHandle(output_read);
+        }
         CloseHandle(job);
         return nullptr;
     }
@@ -107,6 +300,13 @@ std::unique_ptr<Controller> Controller::launch(
     controller->job_handle_ = job;
     controller->pid_ = pi.dwProcessId;
 
+    if (output_read != nullptr) {
+        controller->output_read_ = output_read;
+        controller->capturing_output_ = true;
+        controller->output_thread_ =
+            std::thread(&Controller::read_output, controller.get(), output_read);
+    }
+
     return controller;
 #else
     // Non-Windows: not supported
@@ -124,6 +324,23 @@ Controller::~Controller() {
     if (is_running()) {
         terminate(1);
     }
+    if (output_thread_.joinable()) {
+        // Descendants may still hold the write end, so EOF is not guaranteed.
+        // Cancel the blocked read until the reader sees the flag; a cancel
+        // issued between two reads is lost, hence the retry.
+        output_closed_.store(true, std::memory_order_release);
+        auto reader = static_cast<HANDLE>(output_thread_.native_handle());
+        while (!output_done_.load(std::memory_order_acquire)) {
+            CancelSynchronousIo(reader);
+            Sleep(1);
+        }
+        output_thread_.join();
+    }
+    // Closed only after the reader is gone, so its handle value is not reused
+    if (output_read_ != nullptr) {
+        CloseHandle(static_cast<HANDLE>(output_read_));
+        output_read_ = nullptr;
+    }
     if (thread_handle_ != nullptr) {
         CloseHandle(static_cast<HANDLE>(thread_handle_));
     }
@@ -208,6 +425,242 @@ std::uint32_t Controller::exit_code() const {
 #endif
 }
 
+std::vector<ThreadInfo> Controller::threads() const {
+    std::vector<ThreadInfo> result;
+#ifdef _WIN32
+    if (pid_ == 0) {
+        return result;
+    }
+
+    HANDLE snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+    if (snapshot == INVALID_HANDLE_VALUE) {
+        log_error("CreateToolhelp32Snapshot");
+        return result;
+    }
+
+    THREADENTRY32 entry{};
+    entry.dwSize = sizeof(entry);
+    for (BOOL more = Thread32First(snapshot, &entry); more;
+         more = Thread32Next(snapshot, &entry)) {
+        if (entry.th32OwnerProcessID != pid_) {
+            continue;
+        }
+
+        ThreadInfo info;
+        info.tid = entry.th32ThreadID;
+
+        HANDLE thread = OpenThread(THREAD_QUERY_INFORMATION, FALSE, entry.th32ThreadID);
+        if (thread != nullptr) {
+            info.suspended = thread_suspended(thread);
+            info.start_address = thread_start_address(thread);
+            CloseHandle(thread);
+        }
+        result.push_back(info);
+    }
+
+    CloseHandle(snapshot);
+#endif
+    return result;
+}
+
+std::string Controller::command_line() const {
+#ifdef _WIN32
+    ProcessParameters params{};
+    if (process_handle_ == nullptr ||
+        !read_process_parameters(static_cast<HANDLE>(process_handle_), params)) {
+        return {};
+    }
+
+    return read_remote_string(static_cast<HANDLE>(process_handle_), params.command_line);
+#else
+    return {};
+#endif
+}
+
+std::string Controller::working_directory() const {
+#ifdef _WIN32
+    ProcessParameters params{};
+    if (process_handle_ == nullptr ||
+        !read_process_parameters(static_cast<HANDLE>(process_handle_), params)) {
+        return {};
+    }
+
+    return read_remote_string(static_cast<HANDLE>(process_handle_),
+                              params.current_directory.dos_path);
+#else
+    return {};
+#endif
+}
+
+std::vector<std::string> Controller::environment() const {
+#ifdef _WIN32
+    ProcessParameters params{};
+    if (process_handle_ == nullptr ||
+        !read_process_parameters(static_cast<HANDLE>(process_handle_), params) ||
+        params.environment == nullptr) {
+        return {};
+    }
+
+    // The block is "NAME=value\0...\0\0" of unknown size: read up to each
+    // page end until the double terminator, so an unmapped page after the
+    // block only ends the scan
+    constexpr std::uintptr_t kPageSize = 4096;
+    auto address = reinterpret_cast<std::uintptr_t>(params.environment);
+    std::wstring block;
+    while (block.find(std::wstring_view(L"\0\0", 2)) == std::wstring::npos &&
+           block.size() * sizeof(wchar_t) < kMaxEnvironmentBytes) {
+        const auto bytes = kPageSize - address % kPageSize;
+        const auto offset = block.size();
+        block.resize(offset + bytes / sizeof(wchar_t));
+        if (!ReadProcessMemory(static_cast<HANDLE>(process_handle_),
+                               reinterpret_cast<LPCVOID>(address), block.data() + offset,
+                               bytes, nullptr)) {
+            block.resize(offset);
+            break;
+        }
+        address += bytes;
+    }
+
+    std::vector<std::string> entries;
+    std::size_t start = 0;
+    while (start < block.size()) {
+        const auto end = block.find(L'\0', start);
+        if (end == start || end == std::wstring::npos) {
+            break;
+        }
+        entries.push_back(narrow(block.data() + start, static_cast<int>(end - start)));
+        start = end + 1;
+    }
+    return entries;
+#else
+    return {};
+#endif
+}
+
+std::optional<CpuTimes> Controller::cpu_times() const {
+#ifdef _WIN32
+    if (process_handle_ == nullptr) {
+        return std::nullopt;
+    }
+    FILETIME created{};
+    FILETIME exited{};
+    FILETIME kernel{};
+    FILETIME user{};
+    if (!GetProcessTimes(static_cast<HANDLE>(process_handle_), &created, &exited, &kernel,
+                         &user)) {
+        log_error("GetProcessTimes");
+        return std::nullopt;
+    }
+    // FILETIME durations count 100 ns ticks
+    const auto ticks = [](const FILETIME& ft) {
+        const auto value = (static_cast<std::uint64_t>(ft.dwHighDateTime) << 32) |
+                           ft.dwLowDateTime;
+        return std::chrono::nanoseconds(static_cast<std::int64_t>(value) * 100);
+    };
+    return CpuTimes{ticks(kernel), ticks(user)};
+#else
+    return std::nullopt;
+#endif
+}
+
+std::optional<IntegrityLevel> Controller::integrity_level() const {
+#ifdef _WIN32
+    if (process_handle_ == nullptr) {
+        return std::nullopt;
+    }
+    HANDLE token = nullptr;
+    if (!OpenProcessToken(static_cast<HANDLE>(process_handle_), TOKEN_QUERY, &token)) {
+        log_error("OpenProcessToken");
+        return std::nullopt;
+    }
+    DWORD size = 0;
+    GetTokenInformation(token, TokenIntegrityLevel, nullptr, 0, &size);
+    std::vector<std::uint8_t> buffer(size);
+    if (size == 0 ||
+        !GetTokenInformation(token, TokenIntegrityLevel, buffer.data(), size, &size)) {
+        log_error("GetTokenInformation");
+        CloseHandle(token);
+        return std::nullopt;
+    }
+    CloseHandle(token);
+
+    // The level is the last sub-authority of the label SID
+    const auto* label = reinterpret_cast<const TOKEN_MANDATORY_LABEL*>(buffer.data());
+    const auto count = *GetSidSubAuthorityCount(label->Label.Sid);
+    const auto rid = *GetSidSubAuthority(label->Label.Sid, count - 1u);
+    if (rid >= SECURITY_MANDATORY_SYSTEM_RID) {
+        return IntegrityLevel::System;
+    }
+    if (rid >= SECURITY_MANDATORY_HIGH_RID) {
+        return IntegrityLevel::High;
+    }
+    if (rid >= SECURITY_MANDATORY_MEDIUM_RID) {
+        return IntegrityLevel::Medium;
+    }
+    return IntegrityLevel::Low;
+#else
+    return std::nullopt;
+#endif
+}
+
+// -----------------------------------------------------------------------------
+// Output Capture
+// -----------------------------------------------------------------------------
+
+std::vector<std::string> Controller::take_output() {
+    std::lock_guard lock(output_mutex_);
+    return std::exchange(output_lines_, {});
+}
+
+void Controller::read_output([[maybe_unused]] void* pipe) {
+#ifdef _WIN32
+    std::string pending;
+    char buffer[4096];
+    DWORD read = 0;
+    while (!output_closed_.load(std::memory_order_acquire) &&
+           ReadFile(static_cast<HANDLE>(pipe), buffer, sizeof(buffer), &read, nullptr) &&
+           read > 0) {
+        pending.append(buffer, read);
+
+        std::vector<std::string> lines;
+        std::size_t start = 0;
+        for (std::size_t end = pending.find('\n'); end != std::string::npos;
+             end = pending.find('\n', start)) {
+            std::size_t len = end - start;
+            if (len > 0 && pending[end - 1] == '\r') {
+                --len;
+            }
+            lines.emplace_back(pending, start, len);
+            start = end + 1;
+        }
+        pending.erase(0, start);
+        // A target writing without newlines must not grow the buffer forever
+        while (pending.size() >= kMaxOutputLineBytes) {
+            lines.emplace_back(pending, 0, kMaxOutputLineBytes);
+            pending.erase(0, kMaxOutputLineBytes);
+        }
+
+        if (!lines.empty()) {
+            std::lock_guard lock(output_mutex_);
+            output_lines_.insert(output_lines_.end(),
+                                 std::make_move_iterator(lines.begin()),
+                                 std::make_move_iterator(lines.end()));
+            if (output_lines_.size() > kMaxOutputLines) {
+                output_lines_.erase(output_lines_.begin(),
+                                    output_lines_.end() - kMaxOutputLines);
+            }
+        }
+    }
+
+    // Keep a final unterminated line
+    if (!pending.empty()) {
+        std::lock_guard lock(output_mutex_);
+        output_lines_.push_back(std::move(pending));
+    }
+#endif
+    output_done_.store(true, std::memory_order_release);
+}
+
 // -----------------------------------------------------------------------------
 // Job Object Control
 // -----------------------------------------------------------------------------
@@ -0,0 +1,112 @@
+/// @file signature.cpp
+/// @brief Authenticode signature lookup via WinVerifyTrust and CryptQueryObject.
+
+#include "exeray/process/signature.hpp"
+
+#ifdef _WIN32
+#include <windows.h>
+#include <softpub.h>
+#include <wincrypt.h>
+#include <wintrust.h>
+
+#include <vector>
+#endif
+
+namespace exeray::process {
+
+#ifdef _WIN32
+namespace {
+
+/// @brief Check the signature chain without prompting or going online.
+bool verify_trust(const std::wstring& path) {
+    WINTRUST_FILE_INFO file{};
+    file.cbStruct = sizeof(file);
+    file.pcwszFilePath = path.c_str();
+
+    WINTRUST_DATA data{};
+    data.cbStruct = sizeof(data);
+    data.dwUIChoice = WTD_UI_NONE;
+    data.fdwRevocationChecks = WTD_REVOKE_NONE;
+    data.dwUnionChoice = WTD_CHOICE_FILE;
+    data.pFile = &file;
+    data.dwStateAction = WTD_STATEACTION_VERIFY;
+
+    GUID action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+    const LONG status = WinVerifyTrust(static_cast<HWND>(INVALID_HANDLE_VALUE), &action, &data);
+
+    data.dwStateAction = WTD_STATEACTION_CLOSE;
+    WinVerifyTrust(static_cast<HWND>(INVALID_HANDLE_VALUE), &action, &data);
+    return status == ERROR_SUCCESS;
+}
+
+/// @brief Display name of the certificate that signed @p msg.
+std::string signer_subject(HCERTSTORE store, HCRYPTMSG msg) {
+    DWORD size = 0;
+    if (!CryptMsgGetParam(msg, CMSG_SIGNER_INFO_PARAM, 0, nullptr, &size) || size == 0) {
+        return {};
+    }
+    std::vector<BYTE> buffer(size);
+    if (!CryptMsgGetParam(msg, CMSG_SIGNER_INFO_PARAM, 0, buffer.data(), &size)) {
+        return {};
+    }
+    const auto* signer = reinterpret_cast<const CMSG_SIGNER_INFO*>(buffer.data());
+
+    CERT_INFO info{};
+    info.Issuer = signer->Issuer;
+    info.SerialNumber = signer->SerialNumber;
+    PCCERT_CONTEXT cert = CertFindCertificateInStore(
+        store, X509_ASN_ENCODING | PKCS_7_ASN_ENCODING, 0, CERT_FIND_SUBJECT_CERT, &info,
+        nullptr);
+    if (cert == nullptr) {
+        return {};
+    }
+
+    std::string subject;
+    const DWORD chars =
+        CertGetNameStringW(cert, CERT_NAME_SIMPLE_DISPLAY_TYPE, 0, nullptr, nullptr, 0);
+    if (chars > 1) {
+        std::wstring name(chars, L'\0');
+        CertGetNameStringW(cert, CERT_NAME_SIMPLE_DISPLAY_TYPE, 0, nullptr, name.data(), chars);
+        name.resize(chars - 1);  // Drop the terminator
+        const int len = WideCharToMultiByte(CP_UTF8, 0, name.data(),
+                                            static_cast<int>(name.size()), nullptr, 0,
+                                            nullptr, nullptr);
+        subject.resize(static_cast<std::size_t>(len > 0 ? len : 0));
+        if (len > 0) {
+            WideCharToMultiByte(CP_UTF8, 0, name.data(), static_cast<int>(name.size()),
+                                subject.data(), len, nullptr, nullptr);
+        }
+    }
+    CertFreeCertificateContext(cert);
+    return subject;
+}
+
+}  // namespace
+#endif
+
+Signature query_signature(const std::filesystem::path& path) {
+    Signature result;
+#ifdef _WIN32
+    const std::wstring wide = path.wstring();
+    HCERTSTORE store = nullptr;
+    HCRYPTMSG msg = nullptr;
+    if (!CryptQueryObject(CERT_QUERY_OBJECT_FILE, wide.c_str(),
+                          CERT_QUERY_CONTENT_FLAG_PKCS7_SIGNED_EMBED,
+                          CERT_QUERY_FORMAT_FLAG_BINARY, 0, nullptr, nullptr, nullptr, &store,
+                          &msg, nullptr)) {
+        return result;  // No embedded signature
+    }
+
+    result.is_signed = true;
+    result.subject = signer_subject(store, msg);
+    result.verified = verify_trust(wide);
+
+    CryptMsgClose(msg);
+    CertCloseStore(store, 0);
+#else
+    (void)path;
+#endif
+    return result;
+}
+
+}  // namespace exeray::process
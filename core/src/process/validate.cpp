@@ -0,0 +1,82 @@
+/// @file validate.cpp
+/// @brief PE header checks for dry-run target validation.
+
+#include "exeray/process/validate.hpp"
+
+#include <array>
+#include <fstream>
+#include <system_error>
+
+namespace exeray::process {
+
+namespace {
+
+constexpr std::uint16_t kDosMagic = 0x5A4D;         // "MZ"
+constexpr std::uint32_t kPeSignature = 0x00004550;  // "PE\0\0"
+constexpr std::streamoff kLfanewOffset = 0x3C;
+
+constexpr std::uint16_t kMachineI386 = 0x014C;
+constexpr std::uint16_t kMachineAmd64 = 0x8664;
+constexpr std::uint16_t kMachineArm64 = 0xAA64;
+
+constexpr std::uint16_t kCharExecutableImage = 0x0002;
+constexpr std::uint16_t kCharDll = 0x2000;
+
+/// @brief Read a little-endian integer at @p offset.
+template <typename T>
+bool read_at(std::ifstream& file, std::streamoff offset, T& out) {
+    std::array<unsigned char, sizeof(T)> bytes{};
+    file.seekg(offset);
+    if (!file.read(reinterpret_cast<char*>(bytes.data()), bytes.size())) {
+        return false;
+    }
+    out = 0;
+    for (std::size_t i = 0; i < bytes.size(); ++i) {
+        out = static_cast<T>(out | (static_cast<T>(bytes[i]) << (8 * i)));
+    }
+    return true;
+}
+
+}  // namespace
+
+Validation validate_executable(const std::filesystem::path& path) {
+    std::error_code ec;
+    if (!std::filesystem::is_regular_file(path, ec)) {
+        return Validation::NotFound;
+    }
+
+    std::ifstream file(path, std::ios::binary);
+    if (!file) {
+        return Validation::Unreadable;
+    }
+
+    std::uint16_t dos_magic = 0;
+    std::uint32_t lfanew = 0;
+    if (!read_at(file, 0, dos_magic) || dos_magic != kDosMagic ||
+        !read_at(file, kLfanewOffset, lfanew)) {
+        return Validation::NotPe;
+    }
+
+    // COFF header follows the signature: Machine at +4, Characteristics at +22
+    const auto pe = static_cast<std::streamoff>(lfanew);
+    std::uint32_t signature = 0;
+    std::uint16_t machine = 0;
+    std::uint16_t characteristics = 0;
+    if (!read_at(file, pe, signature) || signature != kPeSignature ||
+        !read_at(file, pe + 4, machine) ||
+        !read_at(file, pe + 22, characteristics)) {
+        return Validation::NotPe;
+    }
+
+    if (machine != kMachineI386 && machine != kMachineAmd64 &&
+        machine != kMachineArm64) {
+        return Validation::UnsupportedArchitecture;
+    }
+    if ((characteristics & kCharExecutableImage) == 0 ||
+        (characteristics & kCharDll) != 0) {
+        return Validation::NotExecutable;
+    }
+    return Validation::Ok;
+}
+
+}  // namespace exeray::process
@@ -0,0 +1,53 @@
+#include "arena_test_common.hpp"
+
+#include <cstring>
+
+namespace exeray {
+namespace arena_test {
+
+TEST_F(ArenaTest, Grow_IncreasesCapacity) {
+    Arena arena{kDefaultCapacity};
+    ASSERT_TRUE(arena.grow(kDefaultCapacity));
+
+    EXPECT_GE(arena.capacity(), 2 * kDefaultCapacity);
+    EXPECT_EQ(arena.blocks(), 2U);
+}
+
+TEST_F(ArenaTest, Grow_PreservesExistingAllocations) {
+    Arena arena{1024};
+    auto* first = arena.allocate<std::uint64_t>();
+    ASSERT_NE(first, nullptr);
+    *first = 0xDEADBEEF;
+
+    // Exhaust the first block
+    EXPECT_EQ(arena.allocate<char>(2048), nullptr);
+
+    ASSERT_TRUE(arena.grow(4096));
+    auto* second = arena.allocate<char>(2048);
+    ASSERT_NE(second, nullptr);
+    std::memset(second, 0xAB, 2048);
+
+    EXPECT_EQ(*first, 0xDEADBEEFU);
+    EXPECT_EQ(arena.at(arena.offset_of(first)), reinterpret_cast<std::uint8_t*>(first));
+    EXPECT_EQ(arena.at(arena.offset_of(second)), reinterpret_cast<std::uint8_t*>(second));
+    EXPECT_GE(arena.offset_of(second), 1024U);
+}
+
+TEST_F(ArenaTest, Grow_ZeroBytes_Fails) {
+    Arena arena{kDefaultCapacity};
+    EXPECT_FALSE(arena.grow(0));
+    EXPECT_EQ(arena.capacity(), kDefaultCapacity);
+}
+
+TEST_F(ArenaTest, Grow_ThenReset_ReleasesBlocks) {
+    Arena arena{kDefaultCapacity};
+    ASSERT_TRUE(arena.grow(kDefaultCapacity));
+    arena.reset();
+
+    EXPECT_EQ(arena.blocks(), 1U);
+    EXPECT_EQ(arena.capacity(), kDefaultCapacity);
+    EXPECT_EQ(arena.used(), 0U);
+}
+
+}  // namespace arena_test
+}  // namespace exeray
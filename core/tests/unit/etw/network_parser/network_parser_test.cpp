@@ -47,12 +47,12 @@ TEST_F(NetworkParserTest, ParseTcpConnect_IPv4_ExtractsPorts) {
     EXPECT_EQ(result.payload.network.remote_port, remote_port);
 }
 
-TEST_F(NetworkParserTest, ParseTcpConnect_IPv6_SkipsCorrectly) {
+TEST_F(NetworkParserTest, ParseTcpConnect_IPv6_InternsRemoteAddress) {
     uint8_t local_addr[16] = {0};
     uint8_t remote_addr[16] = {0};
     // ::1 (loopback)
     local_addr[15] = 1;
-    // 2001:4860:4860::8888 (Google DNS IPv6)
+    // 2001:4860::88
     remote_addr[0] = 0x20;
     remote_addr[1] = 0x01;
     remote_addr[2] = 0x48;
@@ -67,12 +67,26 @@ TEST_F(NetworkParserTest, ParseTcpConnect_IPv6_SkipsCorrectly) {
 
     auto result = parse_network_event(&record, strings_.get());
 
-    // IPv6 parsing: addresses remain 0 since only IPv4 is fully extracted
     EXPECT_TRUE(result.valid);
     EXPECT_EQ(result.payload.network.protocol, PROTO_TCP);
-    // IPv6 addresses are not stored in local_addr/remote_addr (they are 32-bit)
+    EXPECT_EQ(result.payload.network.family, event::AddressFamily::IPv6);
+    // The 16-byte remote address is interned as text; local_addr is IPv4 only
     EXPECT_EQ(result.payload.network.local_addr, 0u);
-    EXPECT_EQ(result.payload.network.remote_addr, 0u);
+    EXPECT_EQ(strings_->get(result.payload.network.remote_addr), "2001:4860::88");
+    EXPECT_EQ(result.payload.network.local_port, 12345);
+    EXPECT_EQ(result.payload.network.remote_port, 443);
+}
+
+TEST_F(NetworkParserTest, ParseTcpConnect_IPv4_SetsFamily) {
+    auto data = build_tcp_connect_ipv4_data(1234, 0x7F000001, 12345, 0x08080808, 80);
+
+    EVENT_RECORD record = make_record(ids::network::TCP_CONNECT);
+    record.UserData = data.data();
+    record.UserDataLength = static_cast<USHORT>(data.size());
+
+    auto result = parse_network_event(&record, strings_.get());
+
+    EXPECT_EQ(result.payload.network.family, event::AddressFamily::IPv4);
 }
 
 TEST_F(NetworkParserTest, ParseTcpConnect_SetsProtocolTcp) {
@@ -107,6 +121,35 @@ TEST_F(NetworkParserTest, ParseTcpSend_ExtractsBytes) {
     EXPECT_EQ(result.operation, static_cast<uint8_t>(event::NetworkOp::Send));
 }
 
+TEST_F(NetworkParserTest, ParseTcpSend_IPv6_InternsRemoteAddress) {
+    uint8_t local_addr[16] = {0};
+    uint8_t remote_addr[16] = {0};
+    local_addr[15] = 1;
+    // 2001:db8::7
+    remote_addr[0] = 0x20;
+    remote_addr[1] = 0x01;
+    remote_addr[2] = 0x0d;
+    remote_addr[3] = 0xb8;
+    remote_addr[15] = 0x07;
+
+    // Transfer events carry the connect address block after the size
+    auto endpoints = build_tcp_connect_ipv6_data(1234, local_addr, 50000, remote_addr, 443);
+    auto data = build_tcp_transfer_data(1234, 512);
+    data.insert(data.end(), endpoints.begin() + sizeof(uint32_t), endpoints.end());
+
+    EVENT_RECORD record = make_record(ids::network::TCP_SEND);
+    record.UserData = data.data();
+    record.UserDataLength = static_cast<USHORT>(data.size());
+
+    auto result = parse_network_event(&record, strings_.get());
+
+    EXPECT_TRUE(result.valid);
+    EXPECT_EQ(result.payload.network.bytes, 512u);
+    EXPECT_EQ(result.payload.network.family, event::AddressFamily::IPv6);
+    EXPECT_EQ(strings_->get(result.payload.network.remote_addr), "2001:db8::7");
+    EXPECT_EQ(result.payload.network.remote_port, 443);
+}
+
 TEST_F(NetworkParserTest, ParseTcpReceive_ExtractsBytes) {
     uint32_t bytes = 65535;
     auto data = build_tcp_transfer_data(1234, bytes);
@@ -310,10 +353,11 @@ TEST_F(NetworkParserTest, ParseNetworkEvent_InitializesDefaults) {
     EXPECT_EQ(result.payload.network.remote_port, 0u);
     EXPECT_EQ(result.payload.network.bytes, 0u);
 
+    EXPECT_EQ(result.payload.network.family, event::AddressFamily::Unknown);
+
     // Verify _pad is zeroed
     EXPECT_EQ(result.payload.network._pad[0], 0);
     EXPECT_EQ(result.payload.network._pad[1], 0);
-    EXPECT_EQ(result.payload.network._pad[2], 0);
 }
 
 }  // namespace
@@ -69,4 +69,16 @@ TEST_F(EventGraphTest, Get_AllCategories_PayloadsCorrect) {
     EXPECT_EQ(graph_.count(), static_cast<std::size_t>(kCategoryCount));
 }
 
+TEST_F(EventGraphTest, Push_WithFlags_StoresBits) {
+    EventPayload payload = make_process_payload();
+    EventId plain = graph_.push(Category::Process, 0, Status::Success,
+                                INVALID_EVENT, 0, payload);
+    EXPECT_EQ(graph_.get(plain).flags(), EventFlags::NONE);
+
+    EventId tagged = graph_.push(Category::Process, 0, Status::Success,
+                                 INVALID_EVENT, 0, payload, 0,
+                                 EventFlags::REMOTE | EventFlags::INJECTED);
+    EXPECT_EQ(graph_.get(tagged).flags(), EventFlags::REMOTE | EventFlags::INJECTED);
+}
+
 }  // namespace exeray::event::test
@@ -8,6 +8,12 @@ using namespace exeray::event;
 // 2. Capacity Management
 // ============================================================================
 
+TEST_F(EventGraphTest, Construct_ZeroCapacity_Throws) {
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EXPECT_THROW((EventGraph{small_arena, small_strings, 0}), std::invalid_argument);
+}
+
 TEST_F(EventGraphTest, Push_AtCapacity_ReturnsInvalidEvent) {
     constexpr std::size_t kSmallCapacity = 100;
     Arena small_arena{1024 * 1024};
@@ -52,8 +58,9 @@ TEST_F(EventGraphTest, Push_AfterCapacityReached_NoSideEffects) {
                          INVALID_EVENT, 0, payload);
     }
 
-    // Count should remain at capacity
+    // Count should remain at capacity, with every rejected push counted
     EXPECT_EQ(small_graph.count(), kSmallCapacity);
+    EXPECT_EQ(small_graph.dropped(), 10u);
 
     // Previous events should still be valid and uncorrupted
     for (std::size_t i = 0; i < ids.size(); ++i) {
@@ -0,0 +1,106 @@
+#include "event_graph_test_common.hpp"
+
+#include <thread>
+
+namespace exeray::event::test {
+
+using namespace exeray::event;
+
+// ============================================================================
+// 13. Eviction by Timestamp
+// ============================================================================
+
+TEST_F(EventGraphTest, EvictBefore_RemovesOlderPrefix) {
+    EventPayload payload = make_process_payload();
+    EventId root = graph_.push(Category::Process, 0, Status::Success,
+                               INVALID_EVENT, 0, payload);
+    graph_.push(Category::Process, 0, Status::Success, root, 0, payload);
+    std::this_thread::sleep_for(std::chrono::milliseconds(1));
+    EventId kept = graph_.push(Category::Process, 0, Status::Success,
+                               INVALID_EVENT, 0, payload);
+
+    EXPECT_EQ(graph_.evict_before(graph_.get(kept).timestamp()), 2u);
+    EXPECT_EQ(graph_.count(), 1u);
+    EXPECT_EQ(graph_.oldest_id(), kept);
+    EXPECT_FALSE(graph_.exists(root));
+    EXPECT_TRUE(graph_.exists(kept));
+
+    // Evicted children are gone from the parent index
+    int children = 0;
+    graph_.for_each_child(root, [&children](EventView) { ++children; });
+    EXPECT_EQ(children, 0);
+}
+
+TEST_F(EventGraphTest, EvictBefore_FreesCapacity) {
+    constexpr std::size_t kSmallCapacity = 4;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+
+    EventPayload payload = make_process_payload();
+    for (std::size_t i = 0; i < kSmallCapacity; ++i) {
+        small_graph.push(Category::Process, 0, Status::Success,
+                         INVALID_EVENT, 0, payload);
+    }
+    EXPECT_EQ(small_graph.push(Category::Process, 0, Status::Success,
+                               INVALID_EVENT, 0, payload), INVALID_EVENT);
+
+    EXPECT_EQ(small_graph.evict_before(UINT64_MAX), kSmallCapacity);
+
+    EventId id = small_graph.push(Category::Process, 0, Status::Success,
+                                  INVALID_EVENT, 0, payload);
+    ASSERT_NE(id, INVALID_EVENT);
+    EXPECT_EQ(id, kSmallCapacity + 1);
+    EXPECT_EQ(small_graph.get(id).id(), id);
+    EXPECT_EQ(small_graph.oldest_id(), id);
+}
+
+TEST_F(EventGraphTest, EvictOldest_RemovesAtMostN) {
+    EventPayload payload = make_process_payload();
+    for (int i = 0; i < 3; ++i) {
+        graph_.push(Category::Process, 0, Status::Success, INVALID_EVENT, 0,
+                    payload);
+    }
+
+    EXPECT_EQ(graph_.evict_oldest(2), 2u);
+    EXPECT_EQ(graph_.count(), 1u);
+    EXPECT_EQ(graph_.oldest_id(), 3u);
+    EXPECT_EQ(graph_.evict_oldest(5), 1u);
+    EXPECT_EQ(graph_.count(), 0u);
+    EXPECT_EQ(graph_.evict_oldest(1), 0u);
+}
+
+TEST_F(EventGraphTest, EvictOldest_ConcurrentWithPush_OnlySeesWrittenSlots) {
+    constexpr std::size_t kSmallCapacity = 64;
+    constexpr int kPushes = 20000;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+
+    std::atomic<bool> done{false};
+    std::thread pusher([&] {
+        EventPayload payload = make_process_payload();
+        for (int i = 0; i < kPushes; ++i) {
+            small_graph.push(Category::Process, 0, Status::Success,
+                             INVALID_EVENT, 0, payload);
+        }
+        done.store(true, std::memory_order_release);
+    });
+
+    // Every slot in the live window must already hold its own event
+    std::size_t mismatches = 0;
+    while (!done.load(std::memory_order_acquire)) {
+        small_graph.evict_oldest(1);
+        small_graph.for_each([&](EventView view) {
+            if (!small_graph.exists(view.id())) {
+                ++mismatches;
+            }
+        });
+    }
+    pusher.join();
+
+    EXPECT_EQ(mismatches, 0u);
+    EXPECT_LE(small_graph.count(), kSmallCapacity);
+}
+
+}  // namespace exeray::event::test
@@ -0,0 +1,42 @@
+#include "event_graph_test_common.hpp"
+
+#include <thread>
+
+namespace exeray::event::test {
+
+using namespace exeray::event;
+
+// ============================================================================
+// 12. Pending Resolution
+// ============================================================================
+
+TEST_F(EventGraphTest, Resolve_PendingEvent_RecordsTimestamp) {
+    EventPayload payload = make_process_payload();
+    EventId id = graph_.push(Category::Process, 0, Status::Pending,
+                             INVALID_EVENT, 0, payload);
+    EXPECT_EQ(graph_.resolved_at(id), 0u);
+
+    std::this_thread::sleep_for(std::chrono::milliseconds(1));
+    ASSERT_TRUE(graph_.resolve(id, Status::Success));
+
+    EXPECT_EQ(graph_.get(id).status(), Status::Success);
+    EXPECT_GT(graph_.resolved_at(id), graph_.get(id).timestamp());
+}
+
+TEST_F(EventGraphTest, Resolve_NonPendingOrMissing_Fails) {
+    EventPayload payload = make_process_payload();
+    EventId done = graph_.push(Category::Process, 0, Status::Success,
+                               INVALID_EVENT, 0, payload);
+    EventId pending = graph_.push(Category::Process, 0, Status::Pending,
+                                  INVALID_EVENT, 0, payload);
+
+    EXPECT_FALSE(graph_.resolve(done, Status::Denied));
+    EXPECT_FALSE(graph_.resolve(pending, Status::Pending));
+    EXPECT_FALSE(graph_.resolve(pending + 100, Status::Success));
+    EXPECT_TRUE(graph_.resolve(pending, Status::Denied));
+    // Second resolution is rejected, first one sticks
+    EXPECT_FALSE(graph_.resolve(pending, Status::Success));
+    EXPECT_EQ(graph_.get(pending).status(), Status::Denied);
+}
+
+}  // namespace exeray::event::test
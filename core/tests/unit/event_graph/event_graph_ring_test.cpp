@@ -0,0 +1,174 @@
+#include "event_graph_test_common.hpp"
+
+namespace exeray::event::test {
+
+using namespace exeray::event;
+
+// ============================================================================
+// 11. Ring Mode
+// ============================================================================
+
+TEST_F(EventGraphTest, RingMode_DisabledByDefault) {
+    EXPECT_FALSE(graph_.ring_buffer());
+    EXPECT_EQ(graph_.oldest_id(), INVALID_EVENT);
+}
+
+TEST_F(EventGraphTest, RingMode_AtCapacity_OverwritesOldest) {
+    constexpr std::size_t kSmallCapacity = 10;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+
+    EventPayload payload = make_process_payload();
+
+    EventId last = INVALID_EVENT;
+    for (std::size_t i = 0; i < kSmallCapacity + 5; ++i) {
+        last = small_graph.push(Category::Process, 0, Status::Success,
+                                INVALID_EVENT, 0, payload);
+        ASSERT_NE(last, INVALID_EVENT) << "Push " << i << " failed in ring mode";
+    }
+
+    EXPECT_EQ(last, kSmallCapacity + 5);
+    EXPECT_EQ(small_graph.count(), kSmallCapacity);
+    EXPECT_EQ(small_graph.oldest_id(), 6u);
+
+    // Evicted IDs no longer exist, live ones resolve to themselves
+    EXPECT_FALSE(small_graph.exists(5));
+    for (EventId id = 6; id <= last; ++id) {
+        ASSERT_TRUE(small_graph.exists(id));
+        EXPECT_EQ(small_graph.get(id).id(), id);
+    }
+    EXPECT_FALSE(small_graph.exists(last + 1));
+}
+
+TEST_F(EventGraphTest, RingMode_ForEach_VisitsOldestToNewest) {
+    constexpr std::size_t kSmallCapacity = 4;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+
+    EventPayload payload = make_process_payload();
+    for (int i = 0; i < 7; ++i) {
+        small_graph.push(Category::Process, 0, Status::Success,
+                         INVALID_EVENT, 0, payload);
+    }
+
+    std::vector<EventId> visited;
+    small_graph.for_each([&visited](EventView view) {
+        visited.push_back(view.id());
+    });
+
+    EXPECT_EQ(visited, (std::vector<EventId>{4, 5, 6, 7}));
+}
+
+TEST_F(EventGraphTest, RingMode_EvictedChild_RemovedFromParentIndex) {
+    constexpr std::size_t kSmallCapacity = 3;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+
+    EventPayload payload = make_process_payload();
+    EventId root = small_graph.push(Category::Process, 0, Status::Success,
+                                    INVALID_EVENT, 0, payload);
+    small_graph.push(Category::Process, 0, Status::Success, root, 0, payload);
+
+    // Overwrite both the root and its child
+    for (int i = 0; i < 3; ++i) {
+        small_graph.push(Category::Process, 0, Status::Success,
+                         INVALID_EVENT, 0, payload);
+    }
+
+    int children = 0;
+    small_graph.for_each_child(root, [&children](EventView) { ++children; });
+    EXPECT_EQ(children, 0);
+}
+
+TEST_F(EventGraphTest, RingMode_SlotReuse_RetagsTargetPidAndGeneration) {
+    constexpr std::size_t kSmallCapacity = 4;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+
+    EventPayload payload = make_process_payload();
+
+    EventId first = small_graph.push(Category::Process, 0, Status::Success,
+                                     INVALID_EVENT, 0, payload, 1234);
+    EXPECT_EQ(small_graph.target_pid(first), 1234u);
+    EXPECT_EQ(small_graph.generation(first), 1u);
+
+    EventId reused = INVALID_EVENT;
+    for (std::size_t i = 0; i < kSmallCapacity; ++i) {
+        reused = small_graph.push(Category::Process, 0, Status::Success,
+                                  INVALID_EVENT, 0, payload);
+    }
+
+    // The evicted event reports nothing and its slot takes the new tags
+    EXPECT_EQ(small_graph.target_pid(first), 0u);
+    EXPECT_EQ(small_graph.target_pid(reused), 0u);
+    EXPECT_EQ(small_graph.generation(first), 0u);
+    EXPECT_EQ(small_graph.generation(reused), kSmallCapacity + 1);
+}
+
+TEST_F(EventGraphTest, RingMode_CapsStringPool) {
+    constexpr std::size_t kSmallCapacity = 4;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+    EXPECT_EQ(small_strings.max_bytes(),
+              kSmallCapacity * EventGraph::kRingStringBytesPerEvent);
+
+    // Unique strings stop being stored once the budget is spent
+    const std::string path(100, 'x');
+    StringId kept = INVALID_STRING;
+    StringId rejected = INVALID_STRING;
+    for (int i = 0; i < 100 && rejected == INVALID_STRING; ++i) {
+        const auto id = small_strings.intern(path + std::to_string(i));
+        if (id == INVALID_STRING) {
+            rejected = 1;
+        } else {
+            kept = id;
+        }
+    }
+    EXPECT_NE(rejected, INVALID_STRING);
+    EXPECT_LE(small_strings.bytes_used(), small_strings.max_bytes());
+
+    // Strings already pooled still resolve and deduplicate
+    EXPECT_FALSE(small_graph.resolve_string(kept).empty());
+    EXPECT_EQ(small_strings.intern(small_graph.resolve_string(kept)), kept);
+
+    small_graph.set_ring_buffer(false);
+    EXPECT_EQ(small_strings.max_bytes(), 0u);
+    EXPECT_NE(small_strings.intern("after"), INVALID_STRING);
+}
+
+TEST_F(EventGraphTest, RingMode_CopyRange_MatchesPositionsToIds) {
+    constexpr std::size_t kSmallCapacity = 4;
+    Arena small_arena{1024 * 1024};
+    StringPool small_strings{small_arena};
+    EventGraph small_graph{small_arena, small_strings, kSmallCapacity};
+    small_graph.set_ring_buffer(true);
+
+    EventPayload payload = make_process_payload();
+    for (std::size_t i = 0; i < kSmallCapacity + 2; ++i) {
+        small_graph.push(Category::Process, 0, Status::Success, INVALID_EVENT, 0, payload,
+                         static_cast<std::uint32_t>(100 + i));
+    }
+
+    EventRecord records[kSmallCapacity + 1]{};
+    const auto copied = small_graph.copy_range(1, records, kSmallCapacity + 1);
+    EXPECT_EQ(copied, kSmallCapacity - 1);
+    for (std::size_t i = 0; i < copied; ++i) {
+        const auto id = small_graph.oldest_id() + 1 + i;
+        EXPECT_EQ(records[i].node.id, id);
+        EXPECT_EQ(records[i].target_pid, small_graph.target_pid(id));
+        EXPECT_EQ(records[i].generation, small_graph.generation(id));
+    }
+    EXPECT_EQ(small_graph.copy_range(kSmallCapacity, records, 1), 0u);
+}
+
+}  // namespace exeray::event::test
@@ -68,5 +68,22 @@ TEST_F(SmallArenaStringPoolTest, Intern_AfterExhaustion_ConsistentState) {
     EXPECT_GE(pool_.count(), interned.size());
 }
 
+TEST_F(SmallArenaStringPoolTest, Intern_AfterArenaGrow_ResolvesAcrossBlocks) {
+    StringId first = pool_.intern("first");
+    ASSERT_NE(first, INVALID_STRING);
+
+    // Exhaust the initial block
+    for (int i = 0; pool_.intern(std::string(100, 'y') + std::to_string(i)) != INVALID_STRING;
+         ++i) {
+    }
+
+    ASSERT_TRUE(arena_.grow(kSmallArenaSize));
+    StringId after = pool_.intern("after grow");
+    ASSERT_NE(after, INVALID_STRING);
+
+    EXPECT_EQ(pool_.get(first), "first");
+    EXPECT_EQ(pool_.get(after), "after grow");
+}
+
 }  // namespace
 }  // namespace exeray::event
@@ -17,10 +17,12 @@ fn main() {
     println!("cargo:rustc-link-lib=static=spdlog");
 
     cxx_build::bridge("src/lib.rs")
+        .file("src/bridge.cc")
         .include("../../core/include")
         .std("c++20")
         .compile("exeray_ffi");
 
     println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/bridge.cc");
     println!("cargo:rerun-if-changed=../../core/include");
 }
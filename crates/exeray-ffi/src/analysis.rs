@@ -0,0 +1,191 @@
+//! Pure analysis helpers over captured events.
+//!
+//! These operate on plain data so they can be tested without a running
+//! core; the matching [`Engine`](crate::Engine) methods collect the inputs
+//! and delegate here.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use crate::event::Event;
+use crate::ffi::Category;
+
+/// Average the latency samples of each category.
+///
+/// Categories without samples are absent from the result.
+pub fn average_latency_by_category(
+    samples: impl IntoIterator<Item = (Category, Duration)>,
+) -> HashMap<Category, Duration> {
+    let mut totals: HashMap<Category, (Duration, u32)> = HashMap::new();
+    for (category, latency) in samples {
+        let entry = totals.entry(category).or_default();
+        entry.0 += latency;
+        entry.1 += 1;
+    }
+    totals
+        .into_iter()
+        .map(|(category, (total, n))| (category, total / n))
+        .collect()
+}
+
+/// Bucket width for the event rate estimate in [`idle_progress`].
+const RATE_BUCKET_NS: u64 = 1_000_000_000;
+
+/// Estimate progress toward idle from event timestamps.
+///
+/// Events are grouped into one-second buckets. The busiest bucket is taken
+/// as the peak rate and the last second before `now_ns` as the current
+/// rate; progress is `1 - current / peak`. A run that has gone quiet after
+/// a burst therefore approaches 1.0, while one still at its peak rate stays
+/// near 0.0. Returns 0.0 when there are no events.
+pub fn idle_progress(timestamps: &[u64], now_ns: u64) -> f32 {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort_unstable();
+    let mut tracker = IdleTracker::default();
+    for ts in sorted {
+        tracker.record(ts);
+    }
+    tracker.progress(now_ns)
+}
+
+/// Incremental [`idle_progress`] for a live capture, fed each event's
+/// timestamp once as it arrives.
+///
+/// Keeps one counter per second of capture and the timestamps of the last
+/// second, so a progress query does not revisit older events.
+#[derive(Debug, Clone, Default)]
+pub struct IdleTracker {
+    first: Option<u64>,
+    buckets: HashMap<u64, u32>,
+    peak: u32,
+    newest: u64,
+    /// Timestamps within a bucket width of the newest one.
+    recent: VecDeque<u64>,
+}
+
+impl IdleTracker {
+    /// Count an event timestamped `ts`.
+    pub fn record(&mut self, ts: u64) {
+        let first = *self.first.get_or_insert(ts);
+        let count = self
+            .buckets
+            .entry(ts.saturating_sub(first) / RATE_BUCKET_NS)
+            .or_default();
+        *count += 1;
+        self.peak = self.peak.max(*count);
+
+        self.recent.push_back(ts);
+        self.newest = self.newest.max(ts);
+        let keep_from = self.newest.saturating_sub(RATE_BUCKET_NS);
+        while self.recent.front().is_some_and(|&old| old < keep_from) {
+            self.recent.pop_front();
+        }
+    }
+
+    /// Progress toward idle at `now_ns`, see [`idle_progress`].
+    pub fn progress(&self, now_ns: u64) -> f32 {
+        if self.peak == 0 {
+            return 0.0;
+        }
+        let recent_start = now_ns.saturating_sub(RATE_BUCKET_NS);
+        let current = self
+            .recent
+            .iter()
+            .filter(|&&ts| ts >= recent_start && ts <= now_ns)
+            .count();
+        (1.0 - current as f32 / self.peak as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// Select the events whose timestamp lies in `[start_ns, end_ns]`.
+///
+/// Timestamps are not assumed to be sorted, so every event is checked.
+pub fn events_in_window(
+    events: &[Event],
+    start_ns: u64,
+    end_ns: u64,
+) -> impl Iterator<Item = &Event> {
+    events
+        .iter()
+        .filter(move |event| (start_ns..=end_ns).contains(&event.timestamp))
+}
+
+/// Count events per category.
+///
+/// Every category is present, in [`Category::ALL`] order, including those
+/// with no events.
+pub fn category_histogram<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+) -> Vec<(Category, usize)> {
+    let mut counts = [0usize; Category::ALL.len()];
+    for event in events {
+        if let Some(count) = counts.get_mut(usize::from(event.category.repr)) {
+            *count += 1;
+        }
+    }
+    Category::ALL.iter().copied().zip(counts).collect()
+}
+
+/// Count the operation codes among events of one category.
+///
+/// Empty when no event has category `category`.
+pub fn operation_histogram<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    category: Category,
+) -> BTreeMap<u8, usize> {
+    let mut counts = BTreeMap::new();
+    for event in events {
+        if event.category == category {
+            *counts.entry(event.operation).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// Keep the first event of each [`Event::content_key`], in input order.
+pub fn dedup_by_content<'a>(events: impl IntoIterator<Item = &'a Event>) -> Vec<Event> {
+    let mut seen = HashSet::new();
+    events
+        .into_iter()
+        .filter(|event| seen.insert(event.content_key()))
+        .copied()
+        .collect()
+}
+
+/// Most buckets [`events_per_bucket`] will allocate.
+pub const MAX_BUCKETS: usize = 1 << 16;
+
+/// Count events per time bucket across the capture span.
+///
+/// Buckets are `bucket_ns` wide and aligned to the earliest timestamp;
+/// every bucket from the first to the last event is present, including
+/// empty ones, so the result can feed a bar chart directly. Returns
+/// `(bucket_start, count)` pairs, or an empty vector when there are no
+/// events.
+///
+/// Returns `None` if `bucket_ns` is 0 or the span would need more than
+/// [`MAX_BUCKETS`] buckets.
+pub fn events_per_bucket(timestamps: &[u64], bucket_ns: u64) -> Option<Vec<(u64, usize)>> {
+    if bucket_ns == 0 {
+        return None;
+    }
+    let (Some(&first), Some(&last)) = (timestamps.iter().min(), timestamps.iter().max()) else {
+        return Some(Vec::new());
+    };
+
+    let last_bucket = (last - first) / bucket_ns;
+    if last_bucket >= MAX_BUCKETS as u64 {
+        return None;
+    }
+    let mut counts = vec![0usize; last_bucket as usize + 1];
+    for &ts in timestamps {
+        counts[((ts - first) / bucket_ns) as usize] += 1;
+    }
+    Some(
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (first + i as u64 * bucket_ns, count))
+            .collect(),
+    )
+}
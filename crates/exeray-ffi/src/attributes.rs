@@ -0,0 +1,33 @@
+//! Attribute bits of events, beyond category and status.
+
+bitflags::bitflags! {
+    /// Typed view of the core's per-event `EventFlags` bits.
+    ///
+    /// Bits are only set when the source knows them, so an empty set makes
+    /// no claim either way. The core currently sets
+    /// [`EventAttributes::INJECTED`] on synthetic events; the others are
+    /// reserved for providers that can tell.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct EventAttributes: u16 {
+        /// The operation involves a remote host.
+        const REMOTE = 1 << 0;
+        /// The event was emitted by an elevated process.
+        const ELEVATED = 1 << 1;
+        /// The event is synthetic, pushed with one of the `inject_*` methods.
+        const INJECTED = 1 << 2;
+    }
+}
+
+impl EventAttributes {
+    pub fn is_remote(self) -> bool {
+        self.contains(Self::REMOTE)
+    }
+
+    pub fn is_elevated(self) -> bool {
+        self.contains(Self::ELEVATED)
+    }
+
+    pub fn is_injected(self) -> bool {
+        self.contains(Self::INJECTED)
+    }
+}
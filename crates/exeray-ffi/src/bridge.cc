@@ -0,0 +1,80 @@
+/// @file bridge.cc
+/// @brief Bridge functions using shared structs.
+///
+/// The generated bridge header defines the shared structs after including
+/// exeray/ffi.hpp, so functions using them are declared there and defined
+/// here, where the header is complete.
+
+#include "exeray-ffi/src/lib.rs.h"
+
+namespace exeray {
+
+namespace {
+
+RawEvent to_raw(const event::EventRecord& record) {
+    const event::EventView ev(&record.node);
+    RawEvent raw{};
+    raw.id = ev.id();
+    raw.parent_id = ev.parent_id();
+    raw.timestamp = ev.timestamp();
+    raw.category = static_cast<std::uint8_t>(ev.category());
+    raw.status = static_cast<std::uint8_t>(ev.status());
+    raw.operation = ev.operation();
+    raw.target_pid = record.target_pid;
+    raw.flags = ev.flags();
+    return raw;
+}
+
+} // namespace
+
+RawTaskState Handle::task_state() const {
+    const auto state = engine_.task_state();
+    return RawTaskState{state.generation, state.flags, state.progress};
+}
+
+rust::Vec<RawThread> Handle::target_threads() const {
+    rust::Vec<RawThread> threads;
+    for (const auto& thread : engine_.target_threads()) {
+        threads.push_back(RawThread{thread.tid, thread.suspended, thread.start_address});
+    }
+    return threads;
+}
+
+RawCpuTimes target_cpu_times(const Handle& h) {
+    RawCpuTimes raw{};
+    if (const auto times = h.target_cpu_times()) {
+        raw.available = true;
+        raw.kernel_ns = static_cast<std::uint64_t>(times->kernel.count());
+        raw.user_ns = static_cast<std::uint64_t>(times->user.count());
+    }
+    return raw;
+}
+
+RawStats get_stats(const Handle& h) {
+    const EngineStats stats = h.stats();
+    RawStats raw{};
+    raw.event_count = static_cast<std::size_t>(stats.event_count);
+    raw.events_dropped = stats.events_dropped;
+    raw.buffers_lost = stats.buffers_lost;
+    raw.busy_threads = static_cast<std::size_t>(stats.busy_threads);
+    raw.queue_depth = static_cast<std::size_t>(stats.queue_depth);
+    raw.arena_used_bytes = static_cast<std::size_t>(stats.arena_used_bytes);
+    return raw;
+}
+
+RawEvent event_at(const Handle& h, std::size_t index) {
+    event::EventRecord record{};
+    return h.graph().copy_range(index, &record, 1) == 1 ? to_raw(record) : RawEvent{};
+}
+
+std::size_t get_events(const Handle& h, std::size_t start, rust::Slice<RawEvent> out) {
+    // One locked copy, so the batch is consistent with a single oldest id
+    std::vector<event::EventRecord> records(out.size());
+    const std::size_t written = h.graph().copy_range(start, records.data(), records.size());
+    for (std::size_t i = 0; i < written; ++i) {
+        out[i] = to_raw(records[i]);
+    }
+    return written;
+}
+
+} // namespace exeray
@@ -0,0 +1,145 @@
+//! Name, provider, and conversion helpers for the Category enum.
+
+use crate::error::ConversionError;
+use crate::ffi::Category;
+
+/// Convert a raw discriminant, rejecting unknown values.
+///
+/// The match is exhaustive over the known discriminants, so adding a
+/// category to the CXX enum requires updating it.
+impl TryFrom<u8> for Category {
+    type Error = ConversionError;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Category::FileSystem),
+            1 => Ok(Category::Registry),
+            2 => Ok(Category::Network),
+            3 => Ok(Category::Process),
+            4 => Ok(Category::Scheduler),
+            5 => Ok(Category::Input),
+            6 => Ok(Category::Image),
+            7 => Ok(Category::Thread),
+            8 => Ok(Category::Memory),
+            9 => Ok(Category::Script),
+            10 => Ok(Category::Amsi),
+            11 => Ok(Category::Dns),
+            12 => Ok(Category::Security),
+            13 => Ok(Category::Service),
+            14 => Ok(Category::Wmi),
+            15 => Ok(Category::Clr),
+            _ => Err(ConversionError::UnknownCategory(val)),
+        }
+    }
+}
+
+impl Category {
+    /// All categories in discriminant order.
+    pub const ALL: [Category; 16] = [
+        Category::FileSystem,
+        Category::Registry,
+        Category::Network,
+        Category::Process,
+        Category::Scheduler,
+        Category::Input,
+        Category::Image,
+        Category::Thread,
+        Category::Memory,
+        Category::Script,
+        Category::Amsi,
+        Category::Dns,
+        Category::Security,
+        Category::Service,
+        Category::Wmi,
+        Category::Clr,
+    ];
+
+    /// Convert a raw discriminant, mapping unknown values to `FileSystem`.
+    ///
+    /// This is the infallible path used for values read from the core,
+    /// which only emits valid categories; the fallback avoids panics should
+    /// that ever break. Use [`Category::try_from`] to detect unknown values,
+    /// e.g. when deserializing records from another source. (`From<u8>`
+    /// cannot coexist with `TryFrom<u8>` because of the standard blanket
+    /// impl, hence the named constructor.)
+    pub fn from_u8_lossy(val: u8) -> Category {
+        Category::try_from(val).unwrap_or(Category::FileSystem)
+    }
+
+    /// Get all categories in discriminant order.
+    pub fn all() -> &'static [Category] {
+        &Self::ALL
+    }
+
+    /// Get the stable display name of the category.
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::FileSystem => "FileSystem",
+            Category::Registry => "Registry",
+            Category::Network => "Network",
+            Category::Process => "Process",
+            Category::Scheduler => "Scheduler",
+            Category::Input => "Input",
+            Category::Image => "Image",
+            Category::Thread => "Thread",
+            Category::Memory => "Memory",
+            Category::Script => "Script",
+            Category::Amsi => "Amsi",
+            Category::Dns => "Dns",
+            Category::Security => "Security",
+            Category::Service => "Service",
+            Category::Wmi => "Wmi",
+            Category::Clr => "Clr",
+            _ => "Unknown",
+        }
+    }
+
+    /// Look up a category by name (case-insensitive).
+    ///
+    /// Returns `None` if the name does not match any category.
+    pub fn from_name(name: &str) -> Option<Category> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|cat| cat.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the name of the core ETW provider that emits this category.
+    ///
+    /// Returns `None` for categories without a dedicated provider.
+    pub(crate) fn provider(self) -> Option<&'static str> {
+        match self {
+            Category::FileSystem => Some("File"),
+            Category::Registry => Some("Registry"),
+            Category::Network => Some("Network"),
+            Category::Process => Some("Process"),
+            Category::Image => Some("Image"),
+            Category::Thread => Some("Thread"),
+            Category::Memory => Some("Memory"),
+            Category::Script => Some("PowerShell"),
+            Category::Amsi => Some("AMSI"),
+            Category::Dns => Some("DNS"),
+            Category::Security | Category::Service => Some("Security"),
+            Category::Wmi => Some("WMI"),
+            Category::Clr => Some("CLR"),
+            _ => None,
+        }
+    }
+}
+
+/// Names of all providers known to the core (see `EngineConfig::with_defaults`).
+pub(crate) const PROVIDERS: [&str; 13] = [
+    "Process",
+    "File",
+    "Registry",
+    "Network",
+    "Image",
+    "Thread",
+    "Memory",
+    "PowerShell",
+    "AMSI",
+    "DNS",
+    "WMI",
+    "CLR",
+    "Security",
+];
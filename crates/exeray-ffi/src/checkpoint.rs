@@ -0,0 +1,138 @@
+//! Checkpoints of a capture for crash recovery.
+//!
+//! [`Engine::checkpoint`] writes the events and session metadata to a TOML
+//! file; [`restore_view`] reads it back as a read-only [`EventSnapshot`]
+//! for post-mortem viewing.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::attributes::EventAttributes;
+use crate::engine::Engine;
+use crate::event::Event;
+use crate::ffi::{Category, Status};
+use crate::snapshot::{EventSnapshot, SnapshotMetadata};
+use crate::timestamp::TimestampEpoch;
+
+/// On-disk checkpoint layout.
+#[derive(Serialize, Deserialize)]
+struct Checkpoint {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exe_path: Option<String>,
+    /// Session start as nanoseconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    started_at_ns: Option<u64>,
+    generation: u64,
+    /// Raw `TimestampEpoch` of the event timestamps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp_epoch: Option<u8>,
+    /// Unix minus monotonic time, for converting monotonic timestamps.
+    #[serde(default)]
+    unix_offset_ns: i64,
+    #[serde(default)]
+    events: Vec<EventRecord>,
+}
+
+/// On-disk event layout, with enums stored by discriminant.
+#[derive(Serialize, Deserialize)]
+struct EventRecord {
+    id: u64,
+    parent_id: u64,
+    timestamp: u64,
+    category: u8,
+    status: u8,
+    operation: u8,
+    target_pid: u32,
+    #[serde(default)]
+    attributes: u16,
+}
+
+impl From<&Event> for EventRecord {
+    fn from(event: &Event) -> Self {
+        Self {
+            id: event.id,
+            parent_id: event.parent_id,
+            timestamp: event.timestamp,
+            category: event.category.repr,
+            status: event.status.repr,
+            operation: event.operation,
+            target_pid: event.target_pid,
+            attributes: event.attributes.bits(),
+        }
+    }
+}
+
+impl TryFrom<EventRecord> for Event {
+    type Error = io::Error;
+
+    fn try_from(record: EventRecord) -> io::Result<Self> {
+        let invalid = |err| io::Error::new(io::ErrorKind::InvalidData, err);
+        Ok(Self {
+            id: record.id,
+            parent_id: record.parent_id,
+            timestamp: record.timestamp,
+            category: Category::try_from(record.category).map_err(invalid)?,
+            status: Status::try_from(record.status).map_err(invalid)?,
+            operation: record.operation,
+            target_pid: record.target_pid,
+            attributes: EventAttributes::from_bits_truncate(record.attributes),
+        })
+    }
+}
+
+impl Engine {
+    /// Write the events and session metadata to `path`.
+    ///
+    /// The metadata holds the primary target path, the session start time,
+    /// the engine generation and the timestamp clock, so absolute times
+    /// can be recovered without this engine. Reload the file with [`restore_view`].
+    pub fn checkpoint(&self, path: &Path) -> io::Result<()> {
+        let checkpoint = Checkpoint {
+            exe_path: self.session.exe_path.clone(),
+            started_at_ns: self.session.started_at.and_then(unix_nanos),
+            generation: self.handle.generation(),
+            timestamp_epoch: Some(self.timestamp_epoch().to_raw()),
+            unix_offset_ns: self.handle.unix_offset_ns(),
+            events: self.iter_events().map(|event| (&event).into()).collect(),
+        };
+        let text = toml::to_string(&checkpoint)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, text)
+    }
+}
+
+/// Load a checkpoint written by [`Engine::checkpoint`].
+///
+/// The result is a read-only view of the capture, not a live engine.
+/// Fails with [`io::ErrorKind::InvalidData`] if the file is malformed.
+pub fn restore_view(path: &Path) -> io::Result<EventSnapshot> {
+    let text = fs::read_to_string(path)?;
+    let checkpoint: Checkpoint =
+        toml::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    let events = checkpoint
+        .events
+        .into_iter()
+        .map(Event::try_from)
+        .collect::<io::Result<Vec<_>>>()?;
+    let metadata = SnapshotMetadata {
+        exe_path: checkpoint.exe_path,
+        started_at: checkpoint
+            .started_at_ns
+            .map(|ns| SystemTime::UNIX_EPOCH + Duration::from_nanos(ns)),
+        generation: checkpoint.generation,
+        timestamp_epoch: checkpoint.timestamp_epoch.map(TimestampEpoch::from_raw),
+        unix_offset_ns: checkpoint.unix_offset_ns,
+    };
+    Ok(EventSnapshot::from_events(events).with_metadata(metadata))
+}
+
+/// Nanoseconds since the Unix epoch, if representable.
+fn unix_nanos(time: SystemTime) -> Option<u64> {
+    let since = time.duration_since(SystemTime::UNIX_EPOCH).ok()?;
+    u64::try_from(since.as_nanos()).ok()
+}
@@ -0,0 +1,52 @@
+//! Pluggable event classification.
+//!
+//! The core assigns each event a [`Status`]; a [`Classifier`] maps events to
+//! a [`Severity`] instead, so callers can substitute their own notion of
+//! "suspicious" for display and scoring.
+
+use crate::event::Event;
+use crate::ffi::Status;
+
+/// How alarming an event is, from least to most.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    #[default]
+    Info,
+    Low,
+    Medium,
+    High,
+}
+
+impl Severity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Info => "Info",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+}
+
+/// Assigns a [`Severity`] to events.
+pub trait Classifier {
+    fn classify(&self, event: &Event) -> Severity;
+}
+
+/// Severity derived from the core-assigned status.
+///
+/// Suspicious events are high, denied ones medium, errors low and
+/// everything else informational.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultClassifier;
+
+impl Classifier for DefaultClassifier {
+    fn classify(&self, event: &Event) -> Severity {
+        match event.status {
+            Status::Suspicious => Severity::High,
+            Status::Denied => Severity::Medium,
+            Status::Error => Severity::Low,
+            _ => Severity::Info,
+        }
+    }
+}
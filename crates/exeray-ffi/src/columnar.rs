@@ -0,0 +1,62 @@
+//! Arrow export of events for columnar analysis (Polars, DataFusion).
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, DictionaryArray, StringArray, UInt8Array, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, UInt8Type};
+use arrow::record_batch::RecordBatch;
+
+use crate::engine::Engine;
+use crate::event::Event;
+use crate::ffi::Category;
+
+/// Schema of the batches built by [`Engine::to_arrow`].
+///
+/// `category` is dictionary encoded over [`Category::name`], keyed by
+/// discriminant; `status` and `operation` hold the raw codes.
+pub fn schema() -> SchemaRef {
+    let category = DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8));
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::UInt64, false),
+        Field::new("parent_id", DataType::UInt64, false),
+        Field::new("timestamp", DataType::UInt64, false),
+        Field::new("category", category, false),
+        Field::new("status", DataType::UInt8, false),
+        Field::new("operation", DataType::UInt8, false),
+    ]))
+}
+
+/// Build a record batch with one row per event, in order.
+pub fn to_record_batch(events: &[Event]) -> RecordBatch {
+    let names = StringArray::from_iter_values(Category::all().iter().map(|c| c.name()));
+    let keys = UInt8Array::from_iter_values(events.iter().map(|e| e.category.repr));
+    let category = DictionaryArray::<UInt8Type>::try_new(keys, Arc::new(names))
+        .expect("category discriminants index Category::all()");
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from_iter_values(events.iter().map(|e| e.id))),
+        Arc::new(UInt64Array::from_iter_values(
+            events.iter().map(|e| e.parent_id),
+        )),
+        Arc::new(UInt64Array::from_iter_values(
+            events.iter().map(|e| e.timestamp),
+        )),
+        Arc::new(category),
+        Arc::new(UInt8Array::from_iter_values(
+            events.iter().map(|e| e.status.repr),
+        )),
+        Arc::new(UInt8Array::from_iter_values(
+            events.iter().map(|e| e.operation),
+        )),
+    ];
+    RecordBatch::try_new(schema(), columns).expect("columns match the schema")
+}
+
+impl Engine {
+    /// Get all current events as an Arrow record batch.
+    ///
+    /// See [`schema`] for the columns.
+    pub fn to_arrow(&self) -> RecordBatch {
+        self.with_events(to_record_batch)
+    }
+}
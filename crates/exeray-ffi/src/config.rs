@@ -0,0 +1,123 @@
+//! Persistent monitoring configuration.
+//!
+//! A [`MonitorConfig`] captures everything needed to repeat a capture:
+//! engine sizing, the category selection, and the target path. It is
+//! stored as TOML.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::{Engine, EngineBuilder};
+use crate::error::EngineError;
+use crate::ffi::Category;
+
+/// Error loading, saving, or validating a [`MonitorConfig`].
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to access config file {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to parse config: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("failed to serialize config: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error("arena size must be nonzero")]
+    ZeroArena,
+    #[error("unknown category: {0}")]
+    UnknownCategory(String),
+    #[error(transparent)]
+    Engine(#[from] EngineError),
+}
+
+/// Saved monitoring configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorConfig {
+    /// Arena size in MB.
+    pub arena_mb: usize,
+    /// Number of worker threads.
+    #[serde(default)]
+    pub threads: usize,
+    /// Category names to capture (empty = core defaults).
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Path to the executable to monitor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+impl Default for MonitorConfig {
+    fn default() -> Self {
+        Self {
+            arena_mb: 64,
+            threads: 0,
+            categories: Vec::new(),
+            target: None,
+        }
+    }
+}
+
+impl MonitorConfig {
+    /// Load and validate a configuration from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let config: Self = toml::from_str(&text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Save the configuration to a TOML file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let text = toml::to_string_pretty(self)?;
+        fs::write(path, text).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Check that the arena is nonzero and all category names are known.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.arena_mb == 0 {
+            return Err(ConfigError::ZeroArena);
+        }
+        self.resolved_categories().map(|_| ())
+    }
+
+    /// Resolve the category names into categories.
+    pub fn resolved_categories(&self) -> Result<Vec<Category>, ConfigError> {
+        self.categories
+            .iter()
+            .map(|name| {
+                Category::from_name(name).ok_or_else(|| ConfigError::UnknownCategory(name.clone()))
+            })
+            .collect()
+    }
+}
+
+impl Engine {
+    /// Create an engine from a saved configuration.
+    ///
+    /// The target path is not launched; pass `config.target` to
+    /// [`Engine::start_monitoring`] when ready.
+    pub fn from_config(config: &MonitorConfig) -> Result<Engine, ConfigError> {
+        config.validate()?;
+
+        let mut builder = EngineBuilder::new()
+            .arena_mb(config.arena_mb)
+            .threads(config.threads);
+        if !config.categories.is_empty() {
+            builder = builder.categories(&config.resolved_categories()?);
+        }
+        Ok(builder.try_build()?)
+    }
+}
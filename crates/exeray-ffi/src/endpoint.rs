@@ -0,0 +1,12 @@
+//! Remote network endpoints contacted by a target.
+
+/// A remote endpoint and how often it was connected to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    /// Remote IPv4 address in dotted-decimal form.
+    pub addr: String,
+    /// Remote port.
+    pub port: u16,
+    /// Number of connect events to this endpoint.
+    pub count: usize,
+}
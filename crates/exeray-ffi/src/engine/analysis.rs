@@ -0,0 +1,163 @@
+//! Aggregate analysis methods for the Engine.
+
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use super::Engine;
+use crate::analysis;
+use crate::endpoint::Endpoint;
+use crate::event::Event;
+use crate::ffi::Category;
+use crate::operation::{NetOp, ProcessOp, TypedOp};
+use crate::process_tree::ProcessTree;
+use crate::risk::{self, RiskWeights};
+
+impl Engine {
+    /// Average Pending → resolved latency of each category.
+    ///
+    /// Only events that have been resolved contribute; categories without
+    /// resolved events are absent.
+    pub fn average_latency_by_category(&self) -> HashMap<Category, Duration> {
+        analysis::average_latency_by_category(self.iter_events().filter_map(|event| {
+            self.event_latency(event.id)
+                .map(|latency| (event.category, latency))
+        }))
+    }
+
+    /// Reconstruct which process spawned which from Process create events.
+    ///
+    /// Unlike the event tree this follows PIDs, so it also links processes
+    /// whose events were not correlated. See [`ProcessTree::from_creates`].
+    pub fn process_tree(&self) -> ProcessTree {
+        ProcessTree::from_creates((0..self.event_count()).filter_map(|index| {
+            let event = self.get_event(index)?;
+            if event.typed_operation() != TypedOp::Process(ProcessOp::Create) {
+                return None;
+            }
+            let (pid, parent_pid) = self.event_process_ids(index)?;
+            let image = self.event_image_path(index).unwrap_or_default();
+            Some((pid, parent_pid, image))
+        }))
+    }
+
+    /// Count the captured events of each category, see
+    /// [`analysis::category_histogram`].
+    pub fn category_histogram(&self) -> Vec<(Category, usize)> {
+        analysis::category_histogram(&self.iter_events().collect::<Vec<_>>())
+    }
+
+    /// Count the operations among events of `category`, see
+    /// [`analysis::operation_histogram`].
+    pub fn operation_histogram(&self, category: Category) -> BTreeMap<u8, usize> {
+        self.with_events(|events| analysis::operation_histogram(events, category))
+    }
+
+    /// Partition the captured events by raw category value in one pass.
+    ///
+    /// Each list keeps capture order; categories without events are
+    /// absent. Every event is copied, so the map costs about
+    /// `event_count() * size_of::<Event>()` bytes on top of the core's own
+    /// storage; prefer [`Engine::iter_events`] with a filter when only one
+    /// category is needed.
+    pub fn events_by_category_map(&self) -> BTreeMap<u8, Vec<Event>> {
+        let mut map: BTreeMap<u8, Vec<Event>> = BTreeMap::new();
+        for event in self.iter_events() {
+            map.entry(event.category.repr).or_default().push(event);
+        }
+        map
+    }
+
+    /// Estimate how far a bounded "analyze until exit" run has progressed.
+    ///
+    /// The estimate tracks event rate decay: the current one-second event
+    /// rate compared with the peak rate seen so far (see
+    /// [`analysis::idle_progress`]). A target that has settled down after
+    /// its startup burst approaches 1.0. Unlike [`ViewState::progress`]
+    /// (the legacy task progress) this is only meaningful while a target
+    /// is monitored.
+    ///
+    /// Returns 1.0 once the target has exited, since the run is then
+    /// complete, and `None` when no target is running or has exited.
+    ///
+    /// [`ViewState::progress`]: crate::ViewState::progress
+    pub fn analysis_progress(&self) -> Option<f32> {
+        if self.target_exited() {
+            return Some(1.0);
+        }
+        if !self.target_running() {
+            return None;
+        }
+        let timestamps: Vec<u64> = self.iter_events().map(|event| event.timestamp).collect();
+        Some(analysis::idle_progress(
+            &timestamps,
+            self.handle.timestamp_ns(),
+        ))
+    }
+
+    /// Get the events timestamped within `[start_ns, end_ns]`, oldest first.
+    pub fn events_in_window(&self, start_ns: u64, end_ns: u64) -> Vec<Event> {
+        let events: Vec<Event> = self.iter_events().collect();
+        analysis::events_in_window(&events, start_ns, end_ns)
+            .copied()
+            .collect()
+    }
+
+    /// Count events per `bucket_ns`-wide time bucket across the capture.
+    ///
+    /// Returns `(bucket_start, count)` pairs from the first to the last
+    /// event, empty buckets included; see [`analysis::events_per_bucket`].
+    /// The result has one entry per bucket in the span, so pick `bucket_ns`
+    /// relative to the capture length (e.g. span / chart width). `None` for
+    /// a zero bucket size or more than [`analysis::MAX_BUCKETS`] buckets.
+    pub fn events_per_bucket(&self, bucket_ns: u64) -> Option<Vec<(u64, usize)>> {
+        let timestamps: Vec<u64> = self.iter_events().map(|event| event.timestamp).collect();
+        analysis::events_per_bucket(&timestamps, bucket_ns)
+    }
+
+    /// Suspicion score of the capture using the default weights.
+    ///
+    /// See [`RiskWeights::default`] for the table.
+    pub fn risk_score(&self) -> u32 {
+        self.risk_score_with(&RiskWeights::default())
+    }
+
+    /// Suspicion score of the capture using custom weights.
+    pub fn risk_score_with(&self, weights: &RiskWeights) -> u32 {
+        let events: Vec<Event> = self.iter_events().collect();
+        risk::risk_score(&events, weights)
+    }
+
+    /// Remote endpoints of all network connects, most contacted first.
+    ///
+    /// Connects are aggregated by address and port, IPv4 and IPv6 alike;
+    /// ties are ordered by address (IPv4 first), then port. Connects
+    /// without an endpoint are skipped.
+    pub fn network_connections(&self) -> Vec<Endpoint> {
+        let mut counts: HashMap<SocketAddr, usize> = HashMap::new();
+        for (index, event) in self.iter_events().enumerate() {
+            if event.typed_operation() != TypedOp::Network(NetOp::Connect) {
+                continue;
+            }
+            if let Some(endpoint) = self.event_socket(index) {
+                *counts.entry(endpoint).or_insert(0) += 1;
+            }
+        }
+
+        let mut endpoints: Vec<(SocketAddr, usize)> = counts.into_iter().collect();
+        endpoints.sort_unstable_by(|(a, a_count), (b, b_count)| {
+            b_count
+                .cmp(a_count)
+                .then(a.ip().cmp(&b.ip()))
+                .then(a.port().cmp(&b.port()))
+        });
+        endpoints
+            .into_iter()
+            .map(|(endpoint, count)| Endpoint {
+                addr: endpoint.ip().to_string(),
+                port: endpoint.port(),
+                count,
+            })
+            .collect()
+    }
+}
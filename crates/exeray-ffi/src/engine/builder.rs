@@ -0,0 +1,322 @@
+//! Builder for configuring an Engine before creation.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::{Engine, MatchCallback, SharedMatchCallback};
+use crate::category::PROVIDERS;
+use crate::error::EngineError;
+use crate::ffi::{self, Category, Status};
+use crate::filter::EventFilter;
+
+/// Default arena size in MB.
+const DEFAULT_ARENA_MB: usize = 64;
+
+/// Builder for an [`Engine`].
+///
+/// # Example
+/// ```no_run
+/// use exeray_ffi::{Category, EngineBuilder};
+///
+/// let engine = EngineBuilder::new()
+///     .arena_mb(128)
+///     .categories(&[Category::Process, Category::Network])
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct EngineBuilder {
+    arena_mb: usize,
+    threads: usize,
+    categories: Option<Vec<Category>>,
+    ring_buffer: bool,
+    freeze_on_start: bool,
+    freeze_on_suspicious: bool,
+    capture_output: bool,
+    max_events: Option<usize>,
+    expected_events: usize,
+    max_duration: Option<Duration>,
+    min_status: Status,
+    on_match: Vec<(EventFilter, SharedMatchCallback)>,
+}
+
+impl fmt::Debug for EngineBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EngineBuilder")
+            .field("arena_mb", &self.arena_mb)
+            .field("threads", &self.threads)
+            .field("categories", &self.categories)
+            .field("ring_buffer", &self.ring_buffer)
+            .field("freeze_on_start", &self.freeze_on_start)
+            .field("freeze_on_suspicious", &self.freeze_on_suspicious)
+            .field("capture_output", &self.capture_output)
+            .field("max_events", &self.max_events)
+            .field("expected_events", &self.expected_events)
+            .field("max_duration", &self.max_duration)
+            .field("min_status", &self.min_status)
+            .field("on_match", &self.on_match.len())
+            .finish()
+    }
+}
+
+impl Default for EngineBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineBuilder {
+    /// Create a builder with default settings (64 MB arena, auto thread count,
+    /// core default providers).
+    pub fn new() -> Self {
+        Self {
+            arena_mb: DEFAULT_ARENA_MB,
+            threads: 0,
+            categories: None,
+            ring_buffer: false,
+            freeze_on_start: false,
+            freeze_on_suspicious: false,
+            capture_output: false,
+            max_events: None,
+            expected_events: 0,
+            max_duration: None,
+            min_status: Status::Success,
+            on_match: Vec::new(),
+        }
+    }
+
+    /// Set the arena size in MB.
+    pub fn arena_mb(mut self, arena_mb: usize) -> Self {
+        self.arena_mb = arena_mb;
+        self
+    }
+
+    /// Set the number of worker threads; 0 (the default) uses one per
+    /// available CPU.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Restrict capture to the given categories.
+    ///
+    /// Enables only the providers emitting these categories. Without this
+    /// call the core's default provider set is used.
+    pub fn categories(mut self, categories: &[Category]) -> Self {
+        self.categories = Some(categories.to_vec());
+        self
+    }
+
+    /// Overwrite the oldest events instead of dropping new ones when full.
+    ///
+    /// Intended for indefinite monitoring. In ring mode event indices shift
+    /// as old events are evicted; see [`Engine::oldest_event_id`].
+    pub fn ring_buffer(mut self, enabled: bool) -> Self {
+        self.ring_buffer = enabled;
+        self
+    }
+
+    /// Keep the target suspended after launch.
+    ///
+    /// The target stays frozen at its entry point after
+    /// [`Engine::start_monitoring`] so the very first events are captured;
+    /// call [`Engine::unfreeze_target`] to let it run.
+    pub fn freeze_on_start(mut self, enabled: bool) -> Self {
+        self.freeze_on_start = enabled;
+        self
+    }
+
+    /// Freeze the target as soon as a `Suspicious` event is captured.
+    ///
+    /// Meant for unattended runs. The event is reported by
+    /// [`Engine::suspicious_hit`], separately from a category breakpoint;
+    /// inspect the capture, then call [`Engine::clear_suspicious_hit`] to
+    /// re-arm the guard and [`Engine::unfreeze_target`] to continue.
+    ///
+    /// The freeze is not instantaneous: events the target produced before
+    /// the suspend landed, possibly further `Suspicious` ones, still arrive
+    /// afterwards. Only the first is recorded as the hit.
+    pub fn freeze_on_suspicious(mut self, enabled: bool) -> Self {
+        self.freeze_on_suspicious = enabled;
+        self
+    }
+
+    /// Redirect the target's stdout/stderr to a pipe.
+    ///
+    /// Output lines are read with [`Engine::target_output`]. Requires the
+    /// target to be launched by [`Engine::start_monitoring`]; an attached
+    /// process keeps its own console.
+    pub fn capture_output(mut self, enabled: bool) -> Self {
+        self.capture_output = enabled;
+        self
+    }
+
+    /// Cap the number of captured events.
+    ///
+    /// A safety limit for unattended captures of hostile targets: when the
+    /// cap is reached the engine sets [`ViewState::ERROR`], freezes the
+    /// target and stops ingesting (see [`Engine::max_events_reached`]).
+    /// Mutually exclusive with [`EngineBuilder::ring_buffer`], which keeps
+    /// ingesting by evicting old events; enabling both makes
+    /// [`EngineBuilder::try_build`] fail.
+    ///
+    /// [`ViewState::ERROR`]: crate::ViewState::ERROR
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = Some(max_events);
+        self
+    }
+
+    /// Discard events less severe than `status` at capture time.
+    ///
+    /// Severity follows [`Status::severity`]; `Status::Error` keeps errors,
+    /// denials and suspicious events. Unlike display filtering this is
+    /// lossy: discarded events never reach the arena and cannot be
+    /// recovered, which saves memory and CPU on noisy targets. A discarded
+    /// event never gets an id, so its children are captured with no parent
+    /// and show up as roots.
+    ///
+    /// Pending is ranked like any other status: a threshold above
+    /// `Status::Pending` discards in-flight events when they are pushed, so
+    /// their later completion is lost even if it fails. The default,
+    /// `Status::Success`, keeps everything.
+    pub fn min_status(mut self, status: Status) -> Self {
+        self.min_status = status;
+        self
+    }
+
+    /// Stop capturing after `max_duration` of monitoring.
+    ///
+    /// A watchdog started with each session freezes the target and stops
+    /// the session once the time is up, then sets [`Engine::timed_out`].
+    /// Bounds unattended CI captures; call [`Engine::stop_monitoring`] to
+    /// release the frozen target.
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Pre-size the engine for about `expected_events` events.
+    ///
+    /// Raises the number of event slots to at least this count and sizes
+    /// the arena for twice their footprint, leaving room for strings. The
+    /// arena ends up at whichever is larger of that size and
+    /// [`EngineBuilder::arena_mb`], so a small hint never shrinks it.
+    pub fn expected_events(mut self, expected_events: usize) -> Self {
+        self.expected_events = expected_events;
+        self
+    }
+
+    /// Run `cb` for every captured event matching `filter`.
+    ///
+    /// Matching happens when the engine is polled ([`Engine::poll`]), so
+    /// callbacks run on the polling thread, not the ETW consumer thread,
+    /// in capture order for the events added since the previous poll; the
+    /// `Send` bound lets them hand work to other threads. Events evicted
+    /// in ring mode before a poll are never offered. Several callbacks can
+    /// be registered; an event matching more than one runs each, in
+    /// registration order. Clones of the builder share the callback, as do
+    /// the engines built from them.
+    pub fn on_match(mut self, filter: EventFilter, cb: MatchCallback) -> Self {
+        self.on_match.push((filter, Arc::new(Mutex::new(cb))));
+        self
+    }
+
+    /// Create the engine.
+    ///
+    /// # Panics
+    /// Panics if the arena cannot be allocated; use
+    /// [`EngineBuilder::try_build`] to handle that case.
+    pub fn build(self) -> Engine {
+        self.try_build()
+            .unwrap_or_else(|err| panic!("EngineBuilder::build: {err}"))
+    }
+
+    /// Create the engine, returning an error if the arena cannot be allocated
+    /// or the options conflict.
+    pub fn try_build(self) -> Result<Engine, EngineError> {
+        if self.ring_buffer && self.max_events.is_some() {
+            return Err(EngineError::MaxEventsWithRingBuffer);
+        }
+        let mut engine = Engine::from_handle(
+            ffi::create_sized(
+                self.arena_mb,
+                super::resolve_threads(self.threads),
+                self.expected_events,
+            ),
+            self.arena_mb,
+        )?;
+        engine.handle.pin_mut().set_ring_buffer(self.ring_buffer);
+        engine
+            .handle
+            .pin_mut()
+            .set_freeze_on_start(self.freeze_on_start);
+        engine
+            .handle
+            .pin_mut()
+            .set_freeze_on_suspicious(self.freeze_on_suspicious);
+        engine
+            .handle
+            .pin_mut()
+            .set_capture_output(self.capture_output);
+        if let Some(max_events) = self.max_events {
+            engine.handle.pin_mut().set_max_events(max_events);
+        }
+        engine.handle.pin_mut().set_min_status(self.min_status.repr);
+        if let Some(max_duration) = self.max_duration {
+            let ns = u64::try_from(max_duration.as_nanos()).unwrap_or(u64::MAX);
+            engine.handle.pin_mut().set_max_duration_ns(ns);
+        }
+
+        *engine.on_match.borrow_mut() = self.on_match;
+
+        if let Some(categories) = &self.categories {
+            let wanted: Vec<&str> = categories.iter().filter_map(|c| c.provider()).collect();
+            for name in PROVIDERS {
+                engine
+                    .handle
+                    .pin_mut()
+                    .set_provider_enabled(name, wanted.contains(&name));
+            }
+        }
+
+        Ok(engine)
+    }
+}
+
+impl Engine {
+    /// Create a builder for configuring a new engine.
+    pub fn builder() -> EngineBuilder {
+        EngineBuilder::new()
+    }
+
+    /// Get the categories the linked core can emit.
+    ///
+    /// Decoded from the core's category mask, where bit N is set if the
+    /// category with discriminant N is supported. Offer filters only for
+    /// these; the rest never appear in a capture.
+    pub fn supported_categories() -> Vec<Category> {
+        let mask = ffi::supported_category_mask();
+        Category::all()
+            .iter()
+            .copied()
+            .filter(|cat| mask & (1 << cat.repr) != 0)
+            .collect()
+    }
+
+    /// Check if a provider is enabled for the next monitoring session.
+    ///
+    /// # Arguments
+    /// * `name` - Provider name (e.g., "Process", "File", "DNS").
+    pub fn provider_enabled(&self, name: &str) -> bool {
+        self.handle.provider_enabled(name)
+    }
+
+    /// Providers the running session actually turned on, sorted by name.
+    ///
+    /// Unlike [`Engine::provider_enabled`], this reflects the live session:
+    /// providers that are configured but failed to enable are left out.
+    /// Returns an empty list when not monitoring.
+    pub fn enabled_providers(&self) -> Vec<String> {
+        self.handle.enabled_providers()
+    }
+}
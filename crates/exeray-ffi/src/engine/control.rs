@@ -1,34 +1,331 @@
 //! Target process control methods for the Engine.
 
-use super::Engine;
+use std::time::Duration;
+
+use super::{Engine, ExitCallback};
+use crate::event::Event;
+use crate::exit_info::ExitInfo;
+use crate::ffi::{self, Category, Status};
+use crate::integrity::IntegrityLevel;
+use crate::signature::SignatureInfo;
+use crate::thread_info::ThreadInfo;
 
 impl Engine {
     /// Freeze (suspend) the target process.
     pub fn freeze_target(&mut self) {
-        self.0.pin_mut().freeze_target();
+        self.handle.pin_mut().freeze_target();
     }
 
     /// Unfreeze (resume) the target process.
     pub fn unfreeze_target(&mut self) {
-        self.0.pin_mut().unfreeze_target();
+        self.handle.pin_mut().unfreeze_target();
     }
 
     /// Terminate the target process.
+    ///
+    /// The exit callback then reports the exit with
+    /// [`ExitInfo::killed`] set.
     pub fn kill_target(&mut self) {
-        self.0.pin_mut().kill_target();
+        self.handle.pin_mut().kill_target();
     }
 
     /// Get the target process ID.
     ///
     /// Returns 0 if not currently monitoring a process.
     pub fn target_pid(&self) -> u32 {
-        self.0.target_pid()
+        self.handle.target_pid()
     }
 
     /// Check if the target process is still running.
     ///
     /// Returns `true` if currently monitoring and the target is running.
     pub fn target_running(&self) -> bool {
-        self.0.target_running()
+        self.handle.target_running()
+    }
+
+    /// Check if the target is currently frozen.
+    ///
+    /// Set after a freeze-on-start launch, [`Engine::freeze_target`], or a
+    /// breakpoint hit; cleared by [`Engine::unfreeze_target`].
+    pub fn target_frozen(&self) -> bool {
+        self.handle.target_frozen()
+    }
+
+    /// List the target's threads.
+    ///
+    /// Useful for spotting injected threads; freeze the target first for a
+    /// stable snapshot. Returns an empty list when not monitoring.
+    pub fn target_threads(&self) -> Vec<ThreadInfo> {
+        self.handle
+            .target_threads()
+            .into_iter()
+            .map(|thread| ThreadInfo {
+                tid: thread.tid,
+                suspended: thread.suspended,
+                start_address: thread.start_address,
+            })
+            .collect()
+    }
+
+    /// Command line the OS recorded for the target, read from its PEB.
+    ///
+    /// Useful to verify argument quoting. Returns `None` when not
+    /// monitoring or when the target's memory cannot be read.
+    pub fn target_command_line(&self) -> Option<String> {
+        if !self.target_running() {
+            return None;
+        }
+        Some(self.handle.target_command_line()).filter(|line| !line.is_empty())
+    }
+
+    /// Working directory of the target, read from its PEB.
+    ///
+    /// Together with the command line and environment this is what is
+    /// needed to reproduce a run. Returns `None` when not monitoring or
+    /// when the target's memory cannot be read.
+    pub fn target_cwd(&self) -> Option<String> {
+        if !self.target_running() {
+            return None;
+        }
+        Some(self.handle.target_working_directory()).filter(|dir| !dir.is_empty())
+    }
+
+    /// Environment variables of the target as `(name, value)` pairs, read
+    /// from its PEB.
+    ///
+    /// Useful to reproduce the conditions of a capture. Entries keep the
+    /// block's order, including the hidden per-drive `=C:` variables.
+    /// Returns an empty list when not monitoring or when the target's
+    /// memory cannot be read.
+    pub fn target_environment(&self) -> Vec<(String, String)> {
+        self.handle
+            .target_environment()
+            .into_iter()
+            .map(|entry| {
+                // Skip the first character so `=C:=C:\dir` splits after `=C:`
+                match entry.char_indices().skip(1).find(|&(_, c)| c == '=') {
+                    Some((at, _)) => (entry[..at].to_string(), entry[at + 1..].to_string()),
+                    None => (entry, String::new()),
+                }
+            })
+            .collect()
+    }
+
+    /// Get the CPU time the target has spent as `(kernel, user)`.
+    ///
+    /// Kernel time is CPU time spent executing in kernel mode on the
+    /// target's behalf (system calls, page faults); time spent blocked
+    /// waiting on I/O counts as neither. Returns `None` when not monitoring
+    /// or if the OS query fails.
+    pub fn target_cpu_times(&self) -> Option<(Duration, Duration)> {
+        let raw = ffi::target_cpu_times(&self.handle);
+        raw.available.then(|| {
+            (
+                Duration::from_nanos(raw.kernel_ns),
+                Duration::from_nanos(raw.user_ns),
+            )
+        })
+    }
+
+    /// Get the target's code-signing status.
+    ///
+    /// Looked up once when the target is launched. Returns `None` when not
+    /// monitoring.
+    pub fn target_signature(&self) -> Option<SignatureInfo> {
+        if !self.handle.has_target_signature() {
+            return None;
+        }
+        Some(SignatureInfo {
+            signed: self.handle.target_signed(),
+            subject: self.handle.target_signature_subject(),
+            verified: self.handle.target_signature_verified(),
+        })
+    }
+
+    /// Get the integrity level the target runs at.
+    ///
+    /// Malware often behaves differently when elevated. Returns `None` when
+    /// not monitoring or if the target's token cannot be read.
+    pub fn target_integrity(&self) -> Option<IntegrityLevel> {
+        IntegrityLevel::from_raw(self.handle.target_integrity_level())
+    }
+
+    /// Arm a one-shot breakpoint on an event category.
+    ///
+    /// The first event of `cat` suspends the target and is reported by
+    /// [`Engine::breakpoint_hit`]. Inspect the capture, then call
+    /// [`Engine::unfreeze_target`] to continue. Arming replaces any previous
+    /// breakpoint.
+    pub fn break_on(&mut self, cat: Category) {
+        self.handle.pin_mut().break_on(cat.repr);
+    }
+
+    /// Disarm the breakpoint and forget any recorded hit.
+    pub fn clear_breakpoint(&mut self) {
+        self.handle.pin_mut().clear_breakpoint();
+    }
+
+    /// Check if the target is frozen on the first `Suspicious` event.
+    ///
+    /// See [`EngineBuilder::freeze_on_suspicious`](super::EngineBuilder::freeze_on_suspicious).
+    pub fn freeze_on_suspicious(&self) -> bool {
+        self.handle.freeze_on_suspicious()
+    }
+
+    /// Get the event that triggered the breakpoint.
+    ///
+    /// Returns `None` if the breakpoint has not been hit (or the event has
+    /// since been evicted in ring mode).
+    pub fn breakpoint_hit(&self) -> Option<Event> {
+        match self.handle.breakpoint_hit() {
+            0 => None,
+            id => self.event_by_id(id),
+        }
+    }
+
+    /// Get the `Suspicious` event that froze the target under
+    /// [`EngineBuilder::freeze_on_suspicious`](super::EngineBuilder::freeze_on_suspicious).
+    ///
+    /// Returns `None` if the guard has not fired (or the event has since
+    /// been evicted in ring mode).
+    pub fn suspicious_hit(&self) -> Option<Event> {
+        match self.handle.suspicious_hit() {
+            0 => None,
+            id => self.event_by_id(id),
+        }
+    }
+
+    /// Forget the suspicious guard's hit so the next `Suspicious` event
+    /// freezes the target again. The category breakpoint is unaffected.
+    pub fn clear_suspicious_hit(&mut self) {
+        self.handle.pin_mut().clear_suspicious_hit();
+    }
+
+    /// Number of freezes triggered by the engine itself: breakpoint hits,
+    /// suspicious guard hits and the event cap.
+    ///
+    /// Counted whether or not a target was running to suspend.
+    pub fn auto_freeze_count(&self) -> u64 {
+        self.handle.auto_freeze_count()
+    }
+}
+
+impl Engine {
+    /// Get the event cap set with
+    /// [`EngineBuilder::max_events`](super::EngineBuilder::max_events).
+    ///
+    /// Returns `None` if the engine is uncapped.
+    pub fn max_events(&self) -> Option<usize> {
+        match self.handle.max_events() {
+            0 => None,
+            max => Some(max),
+        }
+    }
+
+    /// Check if the event cap was reached.
+    ///
+    /// Once reached the target is frozen, [`ViewState::ERROR`] is set and no
+    /// further events are captured. Freeing space with
+    /// [`Engine::clear_events_before`] clears it again; the error flag stays.
+    ///
+    /// [`ViewState::ERROR`]: crate::ViewState::ERROR
+    pub fn max_events_reached(&self) -> bool {
+        self.handle.max_events_reached()
+    }
+
+    /// Least severe status captured, see
+    /// [`EngineBuilder::min_status`](super::EngineBuilder::min_status).
+    pub fn min_status(&self) -> Status {
+        Status::from_u8_lossy(self.handle.min_status())
+    }
+
+    /// Number of events discarded for being below [`Engine::min_status`].
+    pub fn events_filtered(&self) -> u64 {
+        self.handle.events_filtered()
+    }
+
+    /// Get the capture duration set with
+    /// [`EngineBuilder::max_duration`](super::EngineBuilder::max_duration).
+    ///
+    /// Returns `None` if captures are unlimited.
+    pub fn max_duration(&self) -> Option<Duration> {
+        match self.handle.max_duration_ns() {
+            0 => None,
+            ns => Some(Duration::from_nanos(ns)),
+        }
+    }
+
+    /// Check if the current session ran past its max duration.
+    ///
+    /// Once timed out the target is frozen, the session is stopped and
+    /// [`Engine::is_monitoring`] is false; [`Engine::stop_monitoring`]
+    /// releases the frozen target.
+    pub fn timed_out(&self) -> bool {
+        self.handle.timed_out()
+    }
+
+    /// Start a session without a target or ETW.
+    ///
+    /// Mock path counterpart of [`Engine::start_monitoring`] for exercising
+    /// capture limits in tests and offline tooling. Arms the
+    /// [`EngineBuilder::max_duration`](super::EngineBuilder::max_duration)
+    /// watchdog; [`Engine::stop_monitoring`] ends the session.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn simulate_session(&mut self) {
+        self.handle.pin_mut().simulate_session();
+        if self.handle.is_monitoring() {
+            self.rearm_exit();
+        }
+    }
+}
+
+impl Engine {
+    /// Run `cb` when the target exits.
+    ///
+    /// Exit is detected when the engine is polled ([`Engine::poll`]), so
+    /// the callback runs on the polling thread, not the ETW consumer
+    /// thread; the `Send` bound lets it hand work to other threads. It
+    /// stays installed and fires at most once per monitoring session,
+    /// re-armed by each start. Setting a new callback replaces the old one.
+    pub fn set_on_exit(&mut self, cb: ExitCallback) {
+        *self.on_exit.borrow_mut() = Some(cb);
+    }
+
+    /// Check if the target exited during this monitoring session.
+    pub fn target_exited(&self) -> bool {
+        self.handle.target_exited()
+    }
+
+    /// Report a target exit without a real process.
+    ///
+    /// Mock path counterpart of [`Engine::inject_event`] for exercising
+    /// exit handling in tests and offline tooling.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn simulate_target_exit(&mut self, pid: u32, exit_code: u32) {
+        self.handle.pin_mut().simulate_target_exit(pid, exit_code);
+    }
+
+    /// Run the exit callback if the target has exited and this session's
+    /// exit was not reported yet.
+    pub(crate) fn check_target_exit(&self) {
+        if self.exit_reported.get() || !self.handle.target_exited() {
+            return;
+        }
+        // Release the borrow before running user code
+        let Some(mut cb) = self.on_exit.borrow_mut().take() else {
+            return;
+        };
+        self.exit_reported.set(true);
+        cb(ExitInfo {
+            pid: self.handle.exit_pid(),
+            exit_code: self.handle.exit_code(),
+            killed: self.handle.target_killed(),
+        });
+        self.on_exit.borrow_mut().get_or_insert(cb);
+    }
+
+    /// Re-arm the exit callback for a new session.
+    pub(crate) fn rearm_exit(&self) {
+        self.exit_reported.set(false);
     }
 }
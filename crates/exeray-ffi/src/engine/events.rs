@@ -1,85 +1,550 @@
 //! Event access methods for the Engine.
 
+use std::collections::{HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::PoisonError;
+use std::thread;
+use std::time::{Duration, Instant};
+
 use super::Engine;
 use crate::event::Event;
-use crate::event_iter::EventIter;
+use crate::event_iter::{Coalesced, DrainIter, EventIter};
 use crate::ffi::{self, Category, Status};
+use crate::operation::TypedOp;
+#[cfg(any(test, feature = "mock"))]
+use crate::operation::{FileOp, NetOp, ProcessOp};
 
-/// Convert a raw u8 to Category using exhaustive match.
-///
-/// This ensures compile-time safety: if the CXX enum definition changes,
-/// this function will fail to compile until updated.
-fn category_from_u8(val: u8) -> Category {
-    match val {
-        0 => Category::FileSystem,
-        1 => Category::Registry,
-        2 => Category::Network,
-        3 => Category::Process,
-        4 => Category::Scheduler,
-        5 => Category::Input,
-        6 => Category::Image,
-        7 => Category::Thread,
-        8 => Category::Memory,
-        9 => Category::Script,
-        10 => Category::Amsi,
-        11 => Category::Dns,
-        12 => Category::Security,
-        13 => Category::Service,
-        14 => Category::Wmi,
-        15 => Category::Clr,
-        // Unknown values default to FileSystem to avoid panics.
-        // C++ side guarantees valid values; this is a safety fallback.
-        _ => Category::FileSystem,
-    }
-}
+/// Sleep between event count checks in [`Engine::wait_for_events`].
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(5);
 
-/// Convert a raw u8 to Status using exhaustive match.
-///
-/// This ensures compile-time safety: if the CXX enum definition changes,
-/// this function will fail to compile until updated.
-fn status_from_u8(val: u8) -> Status {
-    match val {
-        0 => Status::Success,
-        1 => Status::Denied,
-        2 => Status::Pending,
-        3 => Status::Error,
-        4 => Status::Suspicious,
-        // Unknown values default to Error to avoid panics.
-        // C++ side guarantees valid values; this is a safety fallback.
-        _ => Status::Error,
-    }
-}
+/// Core `AddressFamily` of a Network event whose remote address is IPv6.
+const ADDRESS_FAMILY_IPV6: u8 = 6;
 
 impl Engine {
     /// Get the current event count.
     pub fn event_count(&self) -> usize {
-        ffi::event_count(&self.0)
+        ffi::event_count(&self.handle)
+    }
+
+    /// Block until at least `min` events are captured or `timeout` elapses.
+    ///
+    /// Polls [`Engine::event_count`] with a short sleep. Returns whether the
+    /// threshold was reached.
+    pub fn wait_for_events(&self, min: usize, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.event_count() >= min {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            thread::sleep(WAIT_POLL_INTERVAL.min(deadline - now));
+        }
     }
 
     /// Get an event by index.
     ///
+    /// Indices run from the oldest live event. In ring mode they shift as
+    /// old events are evicted, so hold on to event ids rather than indices.
+    ///
     /// Returns `None` if the index is out of bounds.
     pub fn get_event(&self, index: usize) -> Option<Event> {
         if index >= self.event_count() {
             return None;
         }
 
-        Some(Event {
-            id: ffi::event_get_id(&self.0, index),
-            parent_id: ffi::event_get_parent(&self.0, index),
-            timestamp: ffi::event_get_timestamp(&self.0, index),
-            category: category_from_u8(ffi::event_get_category(&self.0, index)),
-            status: status_from_u8(ffi::event_get_status(&self.0, index)),
-            operation: ffi::event_get_operation(&self.0, index),
+        Some(Event::from_raw_event(ffi::event_at(&self.handle, index)))
+    }
+
+    /// Explain why a `Denied` event was denied.
+    ///
+    /// AMSI denials read "detected as malware" or "blocked by policy",
+    /// failed logons "logon failed", and other denials "access denied".
+    /// Returns `None` for events that are not `Denied` and for out-of-bounds
+    /// indices.
+    pub fn event_denial_reason(&self, index: usize) -> Option<String> {
+        Some(ffi::event_get_denial_reason(&self.handle, index)).filter(|reason| !reason.is_empty())
+    }
+
+    /// Get an event together with its parent event.
+    ///
+    /// The parent is resolved through the cached [`Engine::index`], so
+    /// calling this per row stays cheap. The parent is `None` for root
+    /// events and for parents that are unknown or already evicted.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn event_with_parent(&self, index: usize) -> Option<(Event, Option<Event>)> {
+        let event = self.get_event(index)?;
+        let parent = match event.parent_id {
+            0 => None,
+            id => self
+                .index()
+                .by_id(id)
+                .and_then(|parent| self.get_event(parent)),
+        };
+        Some((event, parent))
+    }
+
+    /// Get every transitive descendant of an event, oldest first.
+    ///
+    /// Walks the cached [`Engine::index`] breadth-first from `ancestor_id`.
+    /// Each event is visited once, so malformed parent links that form a
+    /// cycle cannot loop. Returns an empty list for an unknown id or a
+    /// leaf event.
+    pub fn descendants_of(&self, ancestor_id: u64) -> Vec<Event> {
+        let index = self.index();
+        if index.by_id(ancestor_id).is_none() {
+            return Vec::new();
+        }
+
+        let mut seen = HashSet::from([ancestor_id]);
+        let mut queue = VecDeque::from([ancestor_id]);
+        let mut found = Vec::new();
+        while let Some(id) = queue.pop_front() {
+            for &child in index.children(id) {
+                let Some(event) = self.get_event(child) else {
+                    continue;
+                };
+                if seen.insert(event.id) {
+                    queue.push_back(event.id);
+                    found.push((child, event));
+                }
+            }
+        }
+        found.sort_unstable_by_key(|&(i, _)| i);
+        found.into_iter().map(|(_, event)| event).collect()
+    }
+
+    /// Get the `n` parent ids with the most direct children.
+    ///
+    /// Counts come from the cached [`Engine::index`] and are sorted
+    /// descending, ties by ascending id. Parents that were evicted still
+    /// count if children of theirs are live. Returns fewer than `n` entries
+    /// when there are fewer parents.
+    pub fn top_parents(&self, n: usize) -> Vec<(u64, usize)> {
+        let mut counts: Vec<_> = self
+            .index()
+            .parents()
+            .map(|(id, children)| (id, children.len()))
+            .collect();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Get the current index of the event with this id.
+    ///
+    /// Indices shift when old events are evicted, ids do not; use this to
+    /// turn a remembered id back into an index. Looks the id up in the
+    /// cached [`Engine::index`]. Returns `None` if no live event has it.
+    pub fn index_of(&self, id: u64) -> Option<usize> {
+        self.index().by_id(id)
+    }
+
+    /// Get an event by id.
+    ///
+    /// Returns `None` if no live event has this id.
+    pub(crate) fn event_by_id(&self, id: u64) -> Option<Event> {
+        let oldest = self.oldest_event_id();
+        if oldest == 0 || id < oldest {
+            return None;
+        }
+        let index = usize::try_from(id - oldest).ok()?;
+        self.get_event(index).filter(|event| event.id == id)
+    }
+
+    /// Remove events timestamped before `timestamp` to reclaim capacity.
+    ///
+    /// Eviction runs from the oldest event and stops at the first one at or
+    /// after the cutoff, so with out-of-order timestamps a few older events
+    /// may survive. Freed slots take new events; interned strings are kept.
+    /// Indices are invalidated (they run from the oldest live event), while
+    /// event ids stay valid for surviving events and remain the stable way
+    /// to refer to them.
+    ///
+    /// Returns the number of events removed.
+    pub fn clear_events_before(&mut self, timestamp: u64) -> usize {
+        self.handle.pin_mut().clear_events_before(timestamp)
+    }
+
+    /// Yield events oldest first, removing each from the graph as it is
+    /// consumed.
+    ///
+    /// Freed slots take new events, so a pipeline that processes and
+    /// discards events runs in constant memory however long the capture.
+    /// Takes `&mut self` because removal shifts indices under any other
+    /// reader. The engine is empty once the iterator is exhausted.
+    pub fn drain_events(&mut self) -> DrainIter<'_> {
+        DrainIter::new(self)
+    }
+
+    /// Events added after the engine generation `generation` (see
+    /// [`ViewState::generation`]), oldest first.
+    ///
+    /// Every committed event advances the generation and is tagged with the
+    /// new value, so an incremental consumer can pass the generation it last
+    /// rendered and get exactly the events committed since. Empty if
+    /// `generation` is at or ahead of the current one.
+    ///
+    /// [`ViewState::generation`]: crate::ViewState::generation
+    pub fn events_added_since(&self, generation: u64) -> Vec<Event> {
+        if generation >= self.handle.generation() {
+            return Vec::new();
+        }
+        // Tags grow with the index, so binary-search the first newer event
+        // and read from there in one batch
+        let count = self.event_count();
+        let (mut start, mut end) = (0, count);
+        while start < end {
+            let mid = start + (end - start) / 2;
+            if ffi::event_get_generation(&self.handle, mid) > generation {
+                end = mid;
+            } else {
+                start = mid + 1;
+            }
+        }
+        let mut events = Vec::new();
+        self.read_events(start, count, &mut events);
+        events
+    }
+
+    /// Push a synthetic event into the graph.
+    ///
+    /// The event bypasses ETW but runs through the same per-event hooks as
+    /// captured events (breakpoints, etc.), which makes it the mock path for
+    /// tests and offline tooling. This and the other mock hooks
+    /// (`inject_*`, `simulate_*`) need the `mock` feature outside tests.
+    ///
+    /// Returns the new event id, or `None` if the graph is full.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn inject_event(
+        &mut self,
+        category: Category,
+        operation: u8,
+        status: Status,
+        parent_id: u64,
+    ) -> Option<u64> {
+        match self
+            .handle
+            .pin_mut()
+            .inject_event(category.repr, operation, status.repr, parent_id)
+        {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Push a synthetic event tagged with a target PID.
+    ///
+    /// Like [`Engine::inject_event`], for exercising multi-target
+    /// consumers without ETW. Returns the new event id, or `None` if the
+    /// graph is full.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn inject_target_event(
+        &mut self,
+        target_pid: u32,
+        category: Category,
+        operation: u8,
+        status: Status,
+        parent_id: u64,
+    ) -> Option<u64> {
+        match self.handle.pin_mut().inject_target_event(
+            target_pid,
+            category.repr,
+            operation,
+            status.repr,
+            parent_id,
+        ) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Push a synthetic FileSystem event carrying a file path.
+    ///
+    /// Like [`Engine::inject_event`], for exercising path-based consumers
+    /// such as [`Engine::file_writes`]. Returns the new event id, or `None`
+    /// if the graph is full.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn inject_file_event(
+        &mut self,
+        operation: FileOp,
+        status: Status,
+        parent_id: u64,
+        path: &str,
+    ) -> Option<u64> {
+        match self
+            .handle
+            .pin_mut()
+            .inject_file_event(operation as u8, status.repr, parent_id, path)
+        {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Push a synthetic Network event with a remote endpoint.
+    ///
+    /// Like [`Engine::inject_event`], for exercising endpoint consumers such
+    /// as [`Engine::network_connections`]. Accepts IPv4 and IPv6 endpoints.
+    /// Returns the new event id, or `None` if the graph is full.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn inject_network_event(
+        &mut self,
+        operation: NetOp,
+        status: Status,
+        parent_id: u64,
+        remote: impl Into<SocketAddr>,
+    ) -> Option<u64> {
+        let remote = remote.into();
+        let id = match remote.ip() {
+            IpAddr::V4(ip) => self.handle.pin_mut().inject_network_event(
+                operation as u8,
+                status.repr,
+                parent_id,
+                u32::from(ip),
+                remote.port(),
+            ),
+            IpAddr::V6(ip) => self.handle.pin_mut().inject_network6_event(
+                operation as u8,
+                status.repr,
+                parent_id,
+                &ip.to_string(),
+                remote.port(),
+            ),
+        };
+        (id != 0).then_some(id)
+    }
+
+    /// Push a synthetic Process event for a process creation or exit.
+    ///
+    /// Like [`Engine::inject_event`], for exercising ancestry consumers
+    /// such as [`Engine::process_tree`]. Returns the new event id, or
+    /// `None` if the graph is full.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn inject_process_event(
+        &mut self,
+        operation: ProcessOp,
+        status: Status,
+        parent_id: u64,
+        pid: u32,
+        parent_pid: u32,
+        image: &str,
+    ) -> Option<u64> {
+        match self.handle.pin_mut().inject_process_event(
+            operation as u8,
+            status.repr,
+            parent_id,
+            pid,
+            parent_pid,
+            image,
+        ) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Get the `(pid, parent_pid)` of a Process event by index.
+    ///
+    /// Returns `None` for other categories.
+    pub fn event_process_ids(&self, index: usize) -> Option<(u32, u32)> {
+        let event = self.get_event(index)?;
+        (event.category == Category::Process).then(|| {
+            (
+                ffi::event_get_process_pid(&self.handle, index),
+                ffi::event_get_process_parent_pid(&self.handle, index),
+            )
+        })
+    }
+
+    /// Get the executable path of a Process event by index.
+    ///
+    /// Returns `None` for other categories and for events without an image.
+    pub fn event_image_path(&self, index: usize) -> Option<String> {
+        Some(ffi::event_get_image_path(&self.handle, index)).filter(|path| !path.is_empty())
+    }
+
+    /// Get the remote IPv4 endpoint of a Network event by index.
+    ///
+    /// Returns `None` for other categories, for events without an endpoint
+    /// and for IPv6 endpoints; see [`Engine::event_socket`] for both.
+    pub fn event_remote_endpoint(&self, index: usize) -> Option<SocketAddrV4> {
+        if ffi::event_get_address_family(&self.handle, index) == ADDRESS_FAMILY_IPV6 {
+            return None;
+        }
+        let addr = ffi::event_get_remote_addr(&self.handle, index);
+        let port = ffi::event_get_remote_port(&self.handle, index);
+        (addr != 0 || port != 0).then(|| SocketAddrV4::new(Ipv4Addr::from(addr), port))
+    }
+
+    /// Get the remote endpoint of a Network event by index, IPv4 or IPv6.
+    ///
+    /// Returns `None` for other categories, for events without an endpoint
+    /// and for addresses the core recorded in a form that does not parse.
+    pub fn event_socket(&self, index: usize) -> Option<SocketAddr> {
+        if ffi::event_get_address_family(&self.handle, index) != ADDRESS_FAMILY_IPV6 {
+            return self.event_remote_endpoint(index).map(SocketAddr::V4);
+        }
+        let ip = ffi::event_get_remote_addr6(&self.handle, index)
+            .parse()
+            .ok()?;
+        let port = ffi::event_get_remote_port(&self.handle, index);
+        Some(SocketAddr::new(IpAddr::V6(ip), port))
+    }
+
+    /// Get the file path of a FileSystem event by index.
+    ///
+    /// Returns `None` for other categories and for events without a path.
+    pub fn event_file_path(&self, index: usize) -> Option<String> {
+        Some(ffi::event_get_file_path(&self.handle, index)).filter(|path| !path.is_empty())
+    }
+
+    /// Iterate over file writes paired with their paths, oldest first.
+    ///
+    /// See [`FileOp::is_write`](crate::operation::FileOp::is_write) for the
+    /// operations that count. Writes without a resolved path are skipped.
+    pub fn file_writes(&self) -> impl Iterator<Item = (Event, String)> + '_ {
+        (0..self.event_count()).filter_map(move |index| {
+            let event = self.get_event(index)?;
+            let is_write = matches!(event.typed_operation(), TypedOp::File(op) if op.is_write());
+            if !is_write {
+                return None;
+            }
+            Some((event, self.event_file_path(index)?))
+        })
+    }
+
+    /// Resolve a `Pending` event to its final status.
+    ///
+    /// Records the resolution time used by [`Engine::event_latency`].
+    /// Returns `false` if the event does not exist, is not `Pending`, or
+    /// `status` is `Pending`.
+    pub fn resolve_event(&mut self, id: u64, status: Status) -> bool {
+        self.handle.pin_mut().resolve_event(id, status.repr)
+    }
+
+    /// Get the time a formerly `Pending` event took to resolve.
+    ///
+    /// Returns `None` if the event is unknown or was never resolved.
+    pub fn event_latency(&self, id: u64) -> Option<Duration> {
+        let resolved = match self.handle.event_resolved_at(id) {
+            0 => return None,
+            ts => ts,
+        };
+        let event = self.event_by_id(id)?;
+        Some(Duration::from_nanos(
+            resolved.saturating_sub(event.timestamp),
+        ))
+    }
+
+    /// Check if the engine was built in ring mode.
+    pub fn ring_buffer(&self) -> bool {
+        self.handle.ring_buffer()
+    }
+
+    /// Get the id of the oldest live event.
+    ///
+    /// Together with the newest event's id this bounds the window of events
+    /// still held by the engine. Returns 0 if there are no events.
+    pub fn oldest_event_id(&self) -> u64 {
+        self.handle.oldest_event_id()
+    }
+
+    /// Get the newest `n` events, oldest first.
+    ///
+    /// Reads `[count - n, count)` with a single batch call rather than
+    /// iterating from the start. Returns every event when fewer than `n`
+    /// are captured.
+    pub fn tail(&self, n: usize) -> Vec<Event> {
+        let count = self.event_count();
+        let mut events = Vec::new();
+        self.read_events(count - n.min(count), count, &mut events);
+        events
+    }
+
+    /// Get the earliest and latest event timestamps as `(min, max)`.
+    ///
+    /// Scans once rather than trusting id order, since events pushed
+    /// concurrently can carry slightly out-of-order timestamps. Returns
+    /// `None` when there are no events.
+    pub fn time_span(&self) -> Option<(u64, u64)> {
+        self.with_events(|events| {
+            let first = events.first()?.timestamp;
+            Some(events.iter().fold((first, first), |(min, max), event| {
+                (min.min(event.timestamp), max.max(event.timestamp))
+            }))
         })
     }
 
+    /// Run `f` over a slice of all current events.
+    ///
+    /// The events are read in one batch call into a buffer the engine
+    /// keeps between calls, so repeated inspections reuse its allocation.
+    /// Nested calls from inside `f` work but use a fresh buffer.
+    pub fn with_events<R>(&self, f: impl FnOnce(&[Event]) -> R) -> R {
+        let mut events = self.events_buffer.take();
+        self.read_events(0, self.event_count(), &mut events);
+        let result = f(&events);
+        *self.events_buffer.borrow_mut() = events;
+        result
+    }
+
+    /// Offer events added since the last call to the match callbacks.
+    pub(crate) fn dispatch_matches(&self) {
+        if self.on_match.borrow().is_empty() {
+            return;
+        }
+        let count = self.event_count();
+        if count == 0 {
+            return;
+        }
+        // Indices count from the oldest live event; skip what was offered
+        let oldest = self.oldest_event_id();
+        let start = usize::try_from(self.matched_through.get().saturating_sub(oldest - 1))
+            .unwrap_or(usize::MAX)
+            .min(count);
+        if start == count {
+            return;
+        }
+
+        let mut events = Vec::new();
+        self.read_events(start, count, &mut events);
+        let Some(last) = events.last() else {
+            return;
+        };
+        self.matched_through.set(last.id);
+
+        let callbacks = self.on_match.borrow();
+        for event in events {
+            for (filter, cb) in callbacks.iter() {
+                if filter.matches(&event) {
+                    let mut cb = cb.lock().unwrap_or_else(PoisonError::into_inner);
+                    cb(event);
+                }
+            }
+        }
+    }
+
+    /// Replace `out` with the events in `[start, end)`.
+    fn read_events(&self, start: usize, end: usize, out: &mut Vec<Event>) {
+        let mut raw = vec![ffi::RawEvent::default(); end - start];
+        let written = ffi::get_events(&self.handle, start, &mut raw);
+        out.clear();
+        out.extend(raw[..written].iter().copied().map(Event::from_raw_event));
+    }
+
     /// Iterate over all events.
     pub fn iter_events(&self) -> EventIter<'_> {
-        EventIter {
-            engine: self,
-            index: 0,
-            count: self.event_count(),
-        }
+        EventIter::new(self, self.event_count())
+    }
+
+    /// Iterate over events with adjacent repeats collapsed.
+    ///
+    /// Runs of events identical in category, operation, and parent are
+    /// yielded as the first event of the run and the run length.
+    pub fn iter_events_coalesced(&self) -> Coalesced<EventIter<'_>> {
+        Coalesced::new(self.iter_events())
     }
 }
@@ -1,45 +1,263 @@
 //! Safe wrapper around the ExeRay C++ engine.
 
+mod analysis;
+mod builder;
 mod control;
 mod events;
 mod monitoring;
 
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::SystemTime;
+
+use crate::error::EngineError;
+use crate::event::Event;
+use crate::exit_info::ExitInfo;
 use crate::ffi;
+use crate::filter::EventFilter;
+use crate::index::EventGraphIndex;
+use crate::stats::EngineStats;
+use crate::timestamp::TimestampEpoch;
 use crate::view_state::ViewState;
 
-// Note: These modules extend Engine with impl blocks, no items to re-export.
+pub use builder::EngineBuilder;
+
+// Note: The remaining modules extend Engine with impl blocks, no items to re-export.
+
+/// Source of process-unique engine ids.
+static NEXT_ENGINE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Callback run once per monitoring session when the target exits.
+pub type ExitCallback = Box<dyn FnMut(ExitInfo) + Send>;
+
+/// Callback run for each event matching an [`EventFilter`], see
+/// [`EngineBuilder::on_match`].
+pub type MatchCallback = Box<dyn FnMut(Event) + Send>;
+
+/// A [`MatchCallback`] shared by the clones of an [`EngineBuilder`] and the
+/// engines they build.
+type SharedMatchCallback = Arc<Mutex<MatchCallback>>;
 
 /// Safe wrapper around the ExeRay C++ engine.
-pub struct Engine(pub(crate) cxx::UniquePtr<ffi::Handle>);
+pub struct Engine {
+    pub(crate) handle: cxx::UniquePtr<ffi::Handle>,
+    id: u64,
+    index: RefCell<Option<CachedIndex>>,
+    on_exit: RefCell<Option<ExitCallback>>,
+    /// Whether this session's exit already ran the exit callback.
+    exit_reported: Cell<bool>,
+    on_match: RefCell<Vec<(EventFilter, SharedMatchCallback)>>,
+    /// Id of the newest event already offered to the match callbacks.
+    matched_through: Cell<u64>,
+    /// Scratch space reused by [`Engine::with_events`].
+    events_buffer: RefCell<Vec<Event>>,
+    pub(crate) session: SessionInfo,
+}
+
+/// Details of the current monitoring session, kept for checkpoints.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SessionInfo {
+    pub(crate) exe_path: Option<String>,
+    pub(crate) started_at: Option<SystemTime>,
+}
+
+/// An [`EventGraphIndex`] with the graph state it was built against.
+struct CachedIndex {
+    key: IndexKey,
+    index: Rc<EventGraphIndex>,
+}
+
+/// Graph state that invalidates a cached index when it changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IndexKey {
+    generation: u64,
+    oldest_id: u64,
+    count: usize,
+}
+
+/// Resolve a requested worker count, mapping 0 to the available
+/// parallelism (1 if it cannot be determined).
+pub(crate) fn resolve_threads(threads: usize) -> usize {
+    match threads {
+        0 => thread::available_parallelism().map_or(1, usize::from),
+        n => n,
+    }
+}
 
 impl Engine {
     /// Create a new engine with the specified arena size (in MB) and thread count.
+    ///
+    /// A `threads` of 0 means one worker per available CPU, as reported by
+    /// [`std::thread::available_parallelism`]; [`Engine::threads`] returns
+    /// the resolved count.
+    ///
+    /// # Panics
+    /// Panics if the arena cannot be allocated; use [`Engine::try_new`] to
+    /// handle that case.
     pub fn new(arena_mb: usize, threads: usize) -> Self {
-        Self(ffi::create(arena_mb, threads))
+        Self::try_new(arena_mb, threads).unwrap_or_else(|err| panic!("Engine::new: {err}"))
+    }
+
+    /// Create a new engine, returning an error if the arena cannot be allocated.
+    pub fn try_new(arena_mb: usize, threads: usize) -> Result<Self, EngineError> {
+        Self::from_handle(ffi::create(arena_mb, resolve_threads(threads)), arena_mb)
+    }
+
+    /// Wrap a freshly created handle, mapping a null one to an error.
+    pub(super) fn from_handle(
+        handle: cxx::UniquePtr<ffi::Handle>,
+        arena_mb: usize,
+    ) -> Result<Self, EngineError> {
+        if handle.is_null() {
+            return Err(EngineError::AllocationFailed { arena_mb });
+        }
+        Ok(Self {
+            handle,
+            id: NEXT_ENGINE_ID.fetch_add(1, Ordering::Relaxed),
+            index: RefCell::new(None),
+            on_exit: RefCell::new(None),
+            exit_reported: Cell::new(false),
+            on_match: RefCell::new(Vec::new()),
+            matched_through: Cell::new(0),
+            events_buffer: RefCell::new(Vec::new()),
+            session: SessionInfo::default(),
+        })
+    }
+
+    /// Grow the arena by `additional_mb` megabytes, keeping captured events.
+    ///
+    /// The core chains a new block rather than reallocating, so nothing
+    /// moves: event ids and indices stay stable. The added space serves
+    /// string storage; the number of event slots is fixed at creation.
+    pub fn grow_arena(&mut self, additional_mb: usize) -> Result<(), EngineError> {
+        if self.handle.pin_mut().grow_arena(additional_mb) {
+            Ok(())
+        } else {
+            Err(EngineError::GrowFailed { additional_mb })
+        }
+    }
+
+    /// What event timestamps count from in the linked core.
+    pub fn timestamp_epoch(&self) -> TimestampEpoch {
+        TimestampEpoch::from_raw(self.handle.timestamp_epoch())
+    }
+
+    /// Convert a raw event timestamp to nanoseconds since the Unix epoch.
+    ///
+    /// Monotonic timestamps are shifted by the clock offset sampled when
+    /// the engine was created, so repeated conversions agree with each
+    /// other, though not with later wall-clock adjustments.
+    pub fn to_unix_nanos(&self, raw: u64) -> u64 {
+        self.timestamp_epoch()
+            .to_unix_nanos(raw, self.handle.unix_offset_ns())
+    }
+
+    /// Number of event slots in the graph.
+    ///
+    /// At least [`EngineBuilder::expected_events`] when that hint was given.
+    pub fn event_capacity(&self) -> usize {
+        self.handle.event_capacity()
+    }
+
+    /// Capacity hint the engine was built with, see
+    /// [`EngineBuilder::expected_events`].
+    pub fn expected_events(&self) -> Option<usize> {
+        Some(self.handle.expected_events()).filter(|&n| n != 0)
+    }
+
+    /// Get the total arena capacity in bytes.
+    pub fn arena_capacity(&self) -> usize {
+        self.handle.arena_capacity()
+    }
+
+    /// Get the number of arena bytes in use.
+    pub fn arena_used(&self) -> usize {
+        self.handle.arena_used()
+    }
+
+    /// Get all engine counters in one call.
+    pub fn stats(&self) -> EngineStats {
+        EngineStats::from_raw(ffi::get_stats(&self.handle))
+    }
+
+    /// Get the process-unique id of this engine.
+    ///
+    /// Assigned at creation and never reused, so log lines from several
+    /// engines in one host can be told apart.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Get the id/children index over the current events.
+    ///
+    /// Built lazily and cached; rebuilt only once the generation, event
+    /// count, or oldest event changes. The returned index is shared with the
+    /// cache, so holding it across a rebuild is fine: it keeps describing
+    /// the events it was built from.
+    pub fn index(&self) -> Rc<EventGraphIndex> {
+        let key = IndexKey {
+            generation: self.handle.generation(),
+            oldest_id: self.oldest_event_id(),
+            count: self.event_count(),
+        };
+
+        if let Some(cached) = &*self.index.borrow()
+            && cached.key == key
+        {
+            return Rc::clone(&cached.index);
+        }
+
+        let events: Vec<_> = self.iter_events().collect();
+        let index = Rc::new(EventGraphIndex::build(&events));
+        *self.index.borrow_mut() = Some(CachedIndex {
+            key,
+            index: Rc::clone(&index),
+        });
+        index
     }
 
     /// Submit work to the engine.
     pub fn submit(&mut self) {
-        self.0.pin_mut().submit();
+        self.handle.pin_mut().submit();
     }
 
     /// Poll the current engine state.
+    ///
+    /// Also runs the callbacks registered with [`EngineBuilder::on_match`]
+    /// for events added since the last poll, and the exit callback set with
+    /// [`Engine::set_on_exit`] if the target has exited since it was set.
     pub fn poll(&self) -> ViewState {
+        self.dispatch_matches();
+        self.check_target_exit();
         ViewState {
-            generation: self.0.generation(),
-            timestamp_ns: self.0.timestamp_ns(),
-            flags: self.0.flags(),
-            progress: self.0.progress(),
+            generation: self.handle.generation(),
+            timestamp_ns: self.handle.timestamp_ns(),
+            flags: self.handle.flags(),
+            progress: self.handle.progress(),
         }
     }
 
     /// Check if the engine is idle.
     pub fn idle(&self) -> bool {
-        self.0.idle()
+        self.handle.idle()
     }
 
-    /// Get the number of worker threads.
+    /// Get the number of worker threads, never 0.
     pub fn threads(&self) -> usize {
-        self.0.threads()
+        self.handle.threads()
+    }
+}
+
+impl fmt::Debug for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Engine")
+            .field("id", &self.id)
+            .field("target_pid", &self.target_pid())
+            .field("event_count", &self.event_count())
+            .finish()
     }
 }
@@ -1,20 +1,100 @@
 //! Monitoring control methods for the Engine.
 
-use super::Engine;
+use std::time::SystemTime;
+
+use super::{Engine, SessionInfo};
+use crate::error::MonitorError;
+use crate::ffi::{self, Category};
+use crate::snapshot::EventSnapshot;
+
+/// ETW tracing exists only on Windows; elsewhere the core's monitoring
+/// entry points are stubs.
+#[cfg(windows)]
+fn ensure_supported() -> Result<(), MonitorError> {
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn ensure_supported() -> Result<(), MonitorError> {
+    Err(MonitorError::NotSupported)
+}
 
 impl Engine {
     /// Start monitoring a target process.
     ///
     /// Launches the executable in suspended mode, creates an ETW session,
-    /// enables kernel providers, and starts event capture.
+    /// enables kernel providers, and starts event capture. The target is
+    /// then resumed unless freeze-on-start is enabled.
     ///
     /// # Arguments
     /// * `exe_path` - Path to the executable to launch and monitor (UTF-8).
     ///
-    /// # Returns
-    /// `true` if monitoring started successfully, `false` on failure.
-    pub fn start_monitoring(&mut self, exe_path: &str) -> bool {
-        self.0.pin_mut().start_monitoring(exe_path)
+    /// # Errors
+    /// [`MonitorError::NotSupported`] off Windows, without calling into the
+    /// core; [`MonitorError::StartFailed`] if the target could not be
+    /// launched or the ETW session not started.
+    pub fn start_monitoring(&mut self, exe_path: &str) -> Result<(), MonitorError> {
+        ensure_supported()?;
+        if !self.handle.pin_mut().start_monitoring(exe_path) {
+            return Err(MonitorError::StartFailed);
+        }
+        self.record_session(exe_path);
+        Ok(())
+    }
+
+    /// Start monitoring several targets under one ETW session.
+    ///
+    /// Each path is validated first (see [`Engine::validate_target`]), then
+    /// all targets are launched suspended and traced together. Events carry
+    /// the PID of the process that emitted them in [`Event::target_pid`].
+    /// The first target is the primary one reported by
+    /// [`Engine::target_pid`]; freeze, unfreeze and kill apply to all.
+    ///
+    /// Returns the target PIDs in the order given, or
+    /// [`MonitorError::NotSupported`] off Windows once the paths validate.
+    ///
+    /// [`Event::target_pid`]: crate::Event::target_pid
+    pub fn start_monitoring_multi(&mut self, exe_paths: &[&str]) -> Result<Vec<u32>, MonitorError> {
+        for path in exe_paths {
+            self.validate_target(path)?;
+        }
+        if exe_paths.is_empty() {
+            return Err(MonitorError::StartFailed);
+        }
+        ensure_supported()?;
+        let paths: Vec<String> = exe_paths.iter().map(|path| path.to_string()).collect();
+        let pids = self.handle.pin_mut().start_monitoring_multi(&paths);
+        if pids.is_empty() {
+            return Err(MonitorError::StartFailed);
+        }
+        self.record_session(exe_paths[0]);
+        Ok(pids.into_iter().collect())
+    }
+
+    /// Remember the primary target and start time for checkpoints.
+    fn record_session(&mut self, exe_path: &str) {
+        self.rearm_exit();
+        self.session = SessionInfo {
+            exe_path: Some(exe_path.to_string()),
+            started_at: Some(SystemTime::now()),
+        };
+    }
+
+    /// Check that a target can be launched, without launching it.
+    ///
+    /// Verifies the file exists, is readable, has valid PE headers for a
+    /// supported architecture (x86, x64, ARM64), and is an executable image
+    /// rather than a DLL. ETW is not started.
+    pub fn validate_target(&self, exe_path: &str) -> Result<(), MonitorError> {
+        let path = exe_path.to_string();
+        match ffi::validate_target(exe_path) {
+            0 => Ok(()),
+            1 => Err(MonitorError::ExeNotFound { path }),
+            2 => Err(MonitorError::Unreadable { path }),
+            4 => Err(MonitorError::UnsupportedArchitecture { path }),
+            5 => Err(MonitorError::NotExecutable { path }),
+            _ => Err(MonitorError::NotPe { path }),
+        }
     }
 
     /// Stop monitoring and terminate the target process.
@@ -22,6 +102,110 @@ impl Engine {
     /// Stops the ETW session, joins the consumer thread, and terminates
     /// the target process if still running.
     pub fn stop_monitoring(&mut self) {
-        self.0.pin_mut().stop_monitoring();
+        self.handle.pin_mut().stop_monitoring();
+    }
+
+    /// Check if a capture is active, i.e. monitoring has started and not
+    /// been stopped, either explicitly or by the capture deadline.
+    pub fn is_monitoring(&self) -> bool {
+        self.handle.is_monitoring()
+    }
+
+    /// Force events still sitting in ETW buffers into the graph.
+    ///
+    /// Waits briefly for the consumer to drain. Call before exporting or
+    /// snapshotting so the capture is complete. Returns the number of
+    /// events committed meanwhile (0 when not monitoring).
+    pub fn flush(&mut self) -> usize {
+        self.handle.pin_mut().flush()
+    }
+
+    /// Flush, snapshot, then kill the target and stop monitoring.
+    ///
+    /// One-shot "run, collect, kill" for batch harnesses. Safe to call when
+    /// not monitoring: nothing is flushed or killed and the snapshot is
+    /// empty, so a repeated call does not collect the same events twice.
+    pub fn kill_and_collect(&mut self) -> EventSnapshot {
+        if !self.is_monitoring() {
+            return EventSnapshot::default();
+        }
+        self.flush();
+        let snapshot = self.snapshot();
+        self.kill_target();
+        self.stop_monitoring();
+        snapshot
+    }
+
+    /// Check if the target is kept suspended after launch.
+    ///
+    /// See [`EngineBuilder::freeze_on_start`](super::EngineBuilder::freeze_on_start).
+    pub fn freeze_on_start(&self) -> bool {
+        self.handle.freeze_on_start()
+    }
+
+    /// Check if the targets are still held at their entry point by
+    /// freeze-on-start.
+    ///
+    /// Unlike [`Engine::target_frozen`], a later [`Engine::freeze_target`]
+    /// or breakpoint does not count; the first [`Engine::unfreeze_target`]
+    /// of the session clears it.
+    pub fn frozen_at_entry(&self) -> bool {
+        self.handle.frozen_at_entry()
+    }
+
+    /// Check if the target's stdout/stderr are redirected on launch.
+    ///
+    /// See [`EngineBuilder::capture_output`](super::EngineBuilder::capture_output).
+    pub fn capture_output(&self) -> bool {
+        self.handle.capture_output()
+    }
+
+    /// Take the target's output lines printed since the last call.
+    ///
+    /// Lines come from stdout and stderr interleaved, without terminators,
+    /// so they can be lined up with captured events by arrival. Returns
+    /// `None` unless a target launched with output capture is running;
+    /// capture requires launch and is unavailable when attaching.
+    pub fn target_output(&self) -> Option<Vec<String>> {
+        if !self.handle.capturing_target_output() {
+            return None;
+        }
+        Some(self.handle.take_target_output())
+    }
+
+    /// Enable or disable capture of a category, including mid-capture.
+    ///
+    /// Turns the category's ETW provider on or off; while monitoring the
+    /// change is applied to the live session, otherwise it takes effect on
+    /// the next start. Events already captured remain in the graph. Security
+    /// and Service share a provider, so toggling one toggles both.
+    ///
+    /// # Errors
+    /// [`MonitorError::NoProvider`] for categories no provider emits;
+    /// [`MonitorError::ProviderUpdateFailed`] if the live session rejected
+    /// enabling the provider.
+    pub fn set_category_enabled(
+        &mut self,
+        category: Category,
+        enabled: bool,
+    ) -> Result<(), MonitorError> {
+        let provider = category
+            .provider()
+            .ok_or(MonitorError::NoProvider { category })?;
+        if !self.handle.pin_mut().update_provider(provider, enabled) {
+            return Err(MonitorError::ProviderUpdateFailed {
+                provider: provider.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check if a category's provider is enabled.
+    ///
+    /// Always `false` for categories no provider emits.
+    pub fn category_enabled(&self, category: Category) -> bool {
+        category
+            .provider()
+            .is_some_and(|provider| self.handle.provider_enabled(provider))
     }
 }
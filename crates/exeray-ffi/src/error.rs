@@ -0,0 +1,84 @@
+//! Error types for engine creation, control, target validation, export, and
+//! enum conversion.
+
+use crate::ffi::Category;
+
+/// Error creating or resizing an [`Engine`](crate::Engine).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EngineError {
+    /// The core could not allocate an arena of the requested size, or the
+    /// arena is too small to hold the event graph.
+    #[error("failed to allocate a {arena_mb} MB arena for the event graph")]
+    AllocationFailed { arena_mb: usize },
+    /// The core could not add an arena block of the requested size.
+    #[error("failed to grow the arena by {additional_mb} MB")]
+    GrowFailed { additional_mb: usize },
+    /// An event cap was requested together with ring mode.
+    #[error("max_events cannot be combined with ring buffer mode")]
+    MaxEventsWithRingBuffer,
+}
+
+/// Problem with a monitoring target.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MonitorError {
+    /// The path does not exist or is not a regular file.
+    #[error("executable not found: {path}")]
+    ExeNotFound { path: String },
+    /// The file exists but could not be read.
+    #[error("executable is not readable: {path}")]
+    Unreadable { path: String },
+    /// The file lacks valid MZ/PE headers.
+    #[error("not a PE executable: {path}")]
+    NotPe { path: String },
+    /// The PE machine type is not x86, x64, or ARM64.
+    #[error("unsupported executable architecture: {path}")]
+    UnsupportedArchitecture { path: String },
+    /// The image is a DLL or not marked executable.
+    #[error("image is not launchable: {path}")]
+    NotExecutable { path: String },
+    /// The targets could not be launched or the ETW session not started.
+    #[error("failed to start monitoring")]
+    StartFailed,
+    /// ETW monitoring is only available on Windows.
+    #[error("monitoring is not supported on this platform")]
+    NotSupported,
+    /// No provider emits events of the category.
+    #[error("no provider emits {category:?} events")]
+    NoProvider { category: Category },
+    /// The live session rejected enabling the provider.
+    #[error("failed to update provider {provider}")]
+    ProviderUpdateFailed { provider: String },
+}
+
+/// Failure of a cancellable export.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// The cancel flag was set before the export finished.
+    #[error("export cancelled")]
+    Cancelled,
+    /// Writing the output failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl From<ExportError> for std::io::Error {
+    /// Cancellation surfaces as [`std::io::ErrorKind::Interrupted`].
+    fn from(err: ExportError) -> Self {
+        match err {
+            ExportError::Cancelled => Self::new(std::io::ErrorKind::Interrupted, err),
+            ExportError::Io(err) => err,
+        }
+    }
+}
+
+/// Raw value that does not name a [`Category`] or
+/// [`Status`](crate::Status).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ConversionError {
+    /// No category has this discriminant.
+    #[error("unknown category value: {0}")]
+    UnknownCategory(u8),
+    /// No status has this discriminant.
+    #[error("unknown status value: {0}")]
+    UnknownStatus(u8),
+}
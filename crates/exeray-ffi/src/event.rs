@@ -1,6 +1,10 @@
 //! Event struct representing a single event from the EventGraph.
 
-use crate::ffi::{Category, Status};
+use std::fmt::Write;
+
+use crate::attributes::EventAttributes;
+use crate::ffi::{Category, RawEvent, Status};
+use crate::operation::TypedOp;
 
 /// A single event from the EventGraph.
 #[derive(Debug, Clone, Copy)]
@@ -10,5 +14,68 @@ pub struct Event {
     pub timestamp: u64,
     pub category: Category,
     pub status: Status,
+    /// Raw category-specific operation code.
+    ///
+    /// Kept alongside [`Event::typed_operation`] so codes added by newer
+    /// cores are never lost.
     pub operation: u8,
+    /// PID of the target process that emitted the event (0 if untagged).
+    ///
+    /// Separates the targets of [`Engine::start_monitoring_multi`](crate::Engine::start_monitoring_multi).
+    pub target_pid: u32,
+    /// Attribute bits the core tagged the event with (empty if none).
+    pub attributes: EventAttributes,
+}
+
+impl Event {
+    /// Build from the fixed fields returned by `event_at` and `get_events`.
+    pub(crate) fn from_raw_event(raw: RawEvent) -> Self {
+        Self {
+            id: raw.id,
+            parent_id: raw.parent_id,
+            timestamp: raw.timestamp,
+            category: Category::from_u8_lossy(raw.category),
+            status: Status::from_u8_lossy(raw.status),
+            operation: raw.operation,
+            target_pid: raw.target_pid,
+            attributes: EventAttributes::from_bits_truncate(raw.flags),
+        }
+    }
+
+    /// Content identity of the event: `(category, status, operation)` as raw
+    /// values.
+    ///
+    /// Ids, parents and timestamps differ between runs, so this is what
+    /// "the same kind of event" means when matching across captures.
+    pub fn content_key(&self) -> (u8, u8, u8) {
+        (self.category.repr, self.status.repr, self.operation)
+    }
+
+    /// Decode the operation according to the event category.
+    pub fn typed_operation(&self) -> TypedOp {
+        TypedOp::decode(self.category, self.operation)
+    }
+
+    /// Render the event as a compact one-line string.
+    ///
+    /// Format: `#42 ◂#7 Network/Connect [Suspicious] @123456789`, where the
+    /// `◂#parent` part is omitted for root events and unknown operation
+    /// codes render as `Unknown(code)`.
+    pub fn summary(&self) -> String {
+        let mut out = String::with_capacity(48);
+        // Writing to a String cannot fail
+        let _ = write!(out, "#{}", self.id);
+        if self.parent_id != 0 {
+            let _ = write!(out, " ◂#{}", self.parent_id);
+        }
+        let _ = write!(out, " {}/", self.category.name());
+        match self.typed_operation() {
+            TypedOp::Unknown(_, code) => {
+                let _ = write!(out, "Unknown({code})");
+            }
+            op => out.push_str(op.name()),
+        }
+        let _ = write!(out, " [{}] @{}", self.status.name(), self.timestamp);
+        out
+    }
 }
@@ -1,13 +1,77 @@
-//! Iterator over events in the EventGraph.
+//! Iterators over events in the EventGraph.
+
+use std::iter::Peekable;
 
 use crate::engine::Engine;
 use crate::event::Event;
+use crate::ffi;
+
+/// Events fetched per FFI call by [`EventIter`] unless overridden.
+pub const DEFAULT_PREFETCH: usize = 256;
 
 /// Iterator over events in the EventGraph.
+///
+/// Iterates oldest to newest; use [`Iterator::rev`] for newest first.
+/// Forward and backward iteration share bounds and never overlap.
+///
+/// Events are fetched in blocks of [`EventIter::prefetch`] per FFI call and
+/// served from a local buffer.
 pub struct EventIter<'a> {
-    pub(crate) engine: &'a Engine,
-    pub(crate) index: usize,
-    pub(crate) count: usize,
+    engine: &'a Engine,
+    /// Next index from the front.
+    index: usize,
+    /// One past the next index from the back.
+    count: usize,
+    /// Events per refill.
+    block: usize,
+    /// Index of `buffer[0]`.
+    buffer_start: usize,
+    buffer: Vec<Event>,
+    raw: Vec<ffi::RawEvent>,
+}
+
+impl<'a> EventIter<'a> {
+    pub(crate) fn new(engine: &'a Engine, count: usize) -> Self {
+        Self {
+            engine,
+            index: 0,
+            count,
+            block: DEFAULT_PREFETCH,
+            buffer_start: 0,
+            buffer: Vec::new(),
+            raw: Vec::new(),
+        }
+    }
+
+    /// Set how many events are fetched per FFI call (at least 1).
+    pub fn prefetch(mut self, block: usize) -> Self {
+        self.block = block.max(1);
+        self
+    }
+
+    /// Get the event at `index`, refilling the buffer with a block of
+    /// `[start, end)` if it does not hold it.
+    fn fetch(&mut self, index: usize, start: usize, end: usize) -> Option<Event> {
+        let buffered = index
+            .checked_sub(self.buffer_start)
+            .filter(|&offset| offset < self.buffer.len());
+        if let Some(offset) = buffered {
+            return Some(self.buffer[offset]);
+        }
+
+        let len = self.block.min(end - start);
+        self.raw.resize(len, ffi::RawEvent::default());
+        let written = ffi::get_events(&self.engine.handle, start, &mut self.raw);
+        self.buffer_start = start;
+        self.buffer.clear();
+        self.buffer.extend(
+            self.raw[..written]
+                .iter()
+                .copied()
+                .map(Event::from_raw_event),
+        );
+        self.buffer.get(index - start).copied()
+    }
 }
 
 impl<'a> Iterator for EventIter<'a> {
@@ -17,9 +81,9 @@ impl<'a> Iterator for EventIter<'a> {
         if self.index >= self.count {
             return None;
         }
-        let event = self.engine.get_event(self.index);
+        let index = self.index;
         self.index += 1;
-        event
+        self.fetch(index, index, self.count)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -28,4 +92,88 @@ impl<'a> Iterator for EventIter<'a> {
     }
 }
 
+impl DoubleEndedIterator for EventIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        self.count -= 1;
+        let index = self.count;
+        // Fill the block ending at `index`, without crossing the front
+        let start = (index + 1).saturating_sub(self.block).max(self.index);
+        self.fetch(index, start, index + 1)
+    }
+}
+
 impl ExactSizeIterator for EventIter<'_> {}
+
+/// Consuming iterator that removes each event from the graph as it is
+/// yielded, see [`Engine::drain_events`].
+///
+/// Runs until the graph is empty, so events captured while draining are
+/// yielded too. Dropping it early leaves the unvisited events in place.
+pub struct DrainIter<'a> {
+    engine: &'a mut Engine,
+}
+
+impl<'a> DrainIter<'a> {
+    pub(crate) fn new(engine: &'a mut Engine) -> Self {
+        Self { engine }
+    }
+}
+
+impl Iterator for DrainIter<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = self.engine.get_event(0)?;
+        // A ring-mode push may already have evicted it
+        if self.engine.oldest_event_id() == event.id {
+            self.engine.handle.pin_mut().evict_oldest_events(1);
+        }
+        Some(event)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.engine.event_count(), None)
+    }
+}
+
+/// Iterator collapsing runs of repeated events.
+///
+/// Adjacent events with the same category, operation, and parent are
+/// yielded once as the first event of the run plus the run length. Only
+/// adjacent events are merged, so the adapter streams without buffering.
+pub struct Coalesced<I: Iterator<Item = Event>> {
+    inner: Peekable<I>,
+}
+
+impl<I: Iterator<Item = Event>> Coalesced<I> {
+    /// Wrap an event iterator.
+    pub fn new(events: I) -> Self {
+        Self {
+            inner: events.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = Event>> Iterator for Coalesced<I> {
+    type Item = (Event, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let mut count = 1;
+        while self
+            .inner
+            .next_if(|next| {
+                next.category == first.category
+                    && next.operation == first.operation
+                    && next.parent_id == first.parent_id
+            })
+            .is_some()
+        {
+            count += 1;
+        }
+        Some((first, count))
+    }
+}
@@ -0,0 +1,21 @@
+//! Exit details of a monitored target.
+
+/// How a target process ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitInfo {
+    /// Process ID of the exited target.
+    pub pid: u32,
+    /// Process exit code.
+    pub exit_code: u32,
+    /// Whether [`Engine::kill_target`](crate::Engine::kill_target) ended
+    /// the target rather than it exiting on its own.
+    pub killed: bool,
+}
+
+impl ExitInfo {
+    /// Whether the target failed on its own: a nonzero exit code that was
+    /// not caused by a kill.
+    pub fn is_error(&self) -> bool {
+        !self.killed && self.exit_code != 0
+    }
+}
@@ -0,0 +1,194 @@
+//! Export of captured events to external formats.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::engine::Engine;
+use crate::error::ExportError;
+use crate::event::Event;
+use crate::ffi::Status;
+
+/// Graphviz fill color for an event status.
+fn dot_color(status: Status) -> &'static str {
+    match status {
+        Status::Success => "palegreen",
+        Status::Denied => "orange",
+        Status::Pending => "lightblue",
+        Status::Error => "salmon",
+        Status::Suspicious => "red",
+        _ => "white",
+    }
+}
+
+/// Write events as a Graphviz DOT digraph.
+///
+/// Emits one node per event, labeled with its id and operation and filled
+/// by status, and one edge per known parent → child relationship. Edges
+/// whose parent is not among `events` are skipped.
+pub fn write_dot<W: Write>(events: &[Event], w: &mut W) -> io::Result<()> {
+    let ids: HashSet<u64> = events.iter().map(|e| e.id).collect();
+
+    writeln!(w, "digraph events {{")?;
+    writeln!(w, "    node [shape=box, style=filled];")?;
+    for event in events {
+        writeln!(
+            w,
+            "    e{} [label=\"#{} {:?}\", fillcolor=\"{}\"];",
+            event.id,
+            event.id,
+            event.typed_operation(),
+            dot_color(event.status)
+        )?;
+    }
+    for event in events {
+        if event.parent_id != 0 && ids.contains(&event.parent_id) {
+            writeln!(w, "    e{} -> e{};", event.parent_id, event.id)?;
+        }
+    }
+    writeln!(w, "}}")
+}
+
+/// Write events as JSON Lines, one object per event.
+///
+/// Each line holds the id, parent id, timestamp, category and status
+/// names, and the raw operation code.
+pub fn write_jsonl<W: Write>(events: &[Event], w: &mut W) -> io::Result<()> {
+    Ok(write_jsonl_cancellable(events, w, &AtomicBool::new(false))?)
+}
+
+/// Like [`write_jsonl`], stopping with [`ExportError::Cancelled`] once
+/// `cancel` is set.
+///
+/// The flag is checked before each line, so a UI thread can abort a long
+/// export; lines already written are left in `w`.
+pub fn write_jsonl_cancellable<W: Write>(
+    events: &[Event],
+    w: &mut W,
+    cancel: &AtomicBool,
+) -> Result<(), ExportError> {
+    for event in events {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(ExportError::Cancelled);
+        }
+        writeln!(
+            w,
+            "{{\"id\":{},\"parent_id\":{},\"timestamp\":{},\"category\":\"{}\",\"status\":\"{}\",\"operation\":{}}}",
+            event.id,
+            event.parent_id,
+            event.timestamp,
+            event.category.name(),
+            event.status.name(),
+            event.operation
+        )?;
+    }
+    Ok(())
+}
+
+/// Nanosecond timestamp as Chrome trace microseconds.
+fn trace_micros(ns: u64) -> String {
+    format!("{}.{:03}", ns / 1_000, ns % 1_000)
+}
+
+/// Write events in the Chrome Trace Event JSON format.
+///
+/// An event with descendants becomes a complete (`"X"`) event spanning up
+/// to its latest descendant, so Perfetto nests children under parents; a
+/// leaf becomes an instant (`"i"`) event. Events are grouped by target pid.
+pub fn write_chrome_trace<W: Write>(events: &[Event], w: &mut W) -> io::Result<()> {
+    // Children always carry higher ids than their parents, so a single
+    // pass from the newest event propagates span ends up the tree.
+    let mut ends: HashMap<u64, u64> = HashMap::new();
+    let mut order: Vec<&Event> = events.iter().collect();
+    order.sort_unstable_by_key(|e| std::cmp::Reverse(e.id));
+    for event in order {
+        let end = ends.get(&event.id).copied().unwrap_or(event.timestamp);
+        if event.parent_id != 0 {
+            let parent_end = ends.entry(event.parent_id).or_insert(0);
+            *parent_end = (*parent_end).max(end);
+        }
+    }
+
+    write!(w, "{{\"displayTimeUnit\":\"ns\",\"traceEvents\":[")?;
+    for (i, event) in events.iter().enumerate() {
+        if i > 0 {
+            write!(w, ",")?;
+        }
+        write!(
+            w,
+            "{{\"name\":\"{}\",\"cat\":\"{}\",\"pid\":{},\"tid\":0,\"ts\":{},",
+            event.typed_operation(),
+            event.category.name(),
+            event.target_pid,
+            trace_micros(event.timestamp)
+        )?;
+        match ends.get(&event.id) {
+            Some(&end) => write!(
+                w,
+                "\"ph\":\"X\",\"dur\":{},",
+                trace_micros(end.saturating_sub(event.timestamp))
+            )?,
+            None => write!(w, "\"ph\":\"i\",\"s\":\"t\",")?,
+        }
+        write!(
+            w,
+            "\"args\":{{\"id\":{},\"parent_id\":{},\"status\":\"{}\"}}}}",
+            event.id,
+            event.parent_id,
+            event.status.name()
+        )?;
+    }
+    writeln!(w, "]}}")
+}
+
+impl Engine {
+    /// Write the event causality tree as a Graphviz DOT digraph.
+    ///
+    /// See [`write_dot`] for the output layout.
+    pub fn export_dot<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let events: Vec<Event> = self.iter_events().collect();
+        write_dot(&events, w)
+    }
+
+    /// Write all events as JSON Lines.
+    ///
+    /// See [`write_jsonl`] for the line layout.
+    pub fn export_jsonl<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let events: Vec<Event> = self.iter_events().collect();
+        write_jsonl(&events, w)
+    }
+
+    /// Write all events as JSON Lines until `cancel` is set.
+    ///
+    /// See [`write_jsonl_cancellable`].
+    pub fn export_jsonl_cancellable<W: Write>(
+        &self,
+        w: &mut W,
+        cancel: &AtomicBool,
+    ) -> Result<(), ExportError> {
+        let events: Vec<Event> = self.iter_events().collect();
+        write_jsonl_cancellable(&events, w, cancel)
+    }
+
+    /// Write the event `root_id` and all its descendants as JSON Lines.
+    ///
+    /// The root comes first, followed by its descendants in capture order
+    /// (see [`Engine::descendants_of`]). An unknown root writes nothing.
+    pub fn export_subtree_jsonl<W: Write>(&self, root_id: u64, w: &mut W) -> io::Result<()> {
+        let Some(root) = self.event_by_id(root_id) else {
+            return Ok(());
+        };
+        let mut events = vec![root];
+        events.extend(self.descendants_of(root_id));
+        write_jsonl(&events, w)
+    }
+
+    /// Write all events in the Chrome Trace Event JSON format.
+    ///
+    /// The output loads in Perfetto or `chrome://tracing`; see
+    /// [`write_chrome_trace`] for the event mapping.
+    pub fn export_chrome_trace<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let events: Vec<Event> = self.iter_events().collect();
+        write_chrome_trace(&events, w)
+    }
+}
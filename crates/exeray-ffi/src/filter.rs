@@ -0,0 +1,64 @@
+//! Event predicates for reactive consumers.
+
+use crate::event::Event;
+use crate::ffi::{Category, Status};
+
+/// Conditions an event must meet; unset conditions match anything.
+///
+/// # Example
+/// ```
+/// use exeray_ffi::{Category, EventFilter, operation::NetOp};
+///
+/// // Every network connect, whatever its status
+/// let connects = EventFilter::new()
+///     .category(Category::Network)
+///     .operation(NetOp::Connect as u8);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    category: Option<Category>,
+    operation: Option<u8>,
+    status: Option<Status>,
+    target_pid: Option<u32>,
+}
+
+impl EventFilter {
+    /// A filter matching every event.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events of `category`.
+    pub fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Only match events with this raw operation code.
+    ///
+    /// Codes are per category, so combine with [`EventFilter::category`].
+    pub fn operation(mut self, operation: u8) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Only match events with `status`.
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only match events tagged with this target PID.
+    pub fn target_pid(mut self, pid: u32) -> Self {
+        self.target_pid = Some(pid);
+        self
+    }
+
+    /// Check whether `event` meets every set condition.
+    pub fn matches(&self, event: &Event) -> bool {
+        self.category.is_none_or(|c| event.category == c)
+            && self.operation.is_none_or(|op| event.operation == op)
+            && self.status.is_none_or(|s| event.status == s)
+            && self.target_pid.is_none_or(|pid| event.target_pid == pid)
+    }
+}
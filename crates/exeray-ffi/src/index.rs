@@ -0,0 +1,85 @@
+//! Id and parent/child lookup tables over captured events.
+
+use std::collections::HashMap;
+
+use crate::event::Event;
+
+/// Lookup tables mapping event ids to indices and parents to children.
+///
+/// Indices refer to positions in the event slice the index was built from,
+/// which for [`Engine::index`](crate::Engine::index) are the indices accepted
+/// by [`Engine::get_event`](crate::Engine::get_event).
+#[derive(Debug, Clone, Default)]
+pub struct EventGraphIndex {
+    by_id: HashMap<u64, usize>,
+    children: HashMap<u64, Vec<usize>>,
+}
+
+impl EventGraphIndex {
+    /// Build an index over a slice of events.
+    pub fn build(events: &[Event]) -> Self {
+        let mut index = Self::default();
+        index.extend(events, 0);
+        index
+    }
+
+    /// Index events appended after the indexed ones, the first of them at
+    /// index `start`.
+    pub fn extend(&mut self, events: &[Event], start: usize) {
+        for (i, event) in (start..).zip(events) {
+            self.by_id.insert(event.id, i);
+            if event.parent_id != 0 {
+                self.children.entry(event.parent_id).or_default().push(i);
+            }
+        }
+    }
+
+    /// Drop the oldest indexed events, which must be `events` in index order.
+    ///
+    /// The remaining events keep their indices.
+    pub fn remove_oldest(&mut self, events: &[Event]) {
+        for event in events {
+            self.by_id.remove(&event.id);
+            if event.parent_id == 0 {
+                continue;
+            }
+            if let Some(children) = self.children.get_mut(&event.parent_id) {
+                // Children are kept oldest first, so this one leads
+                children.remove(0);
+                if children.is_empty() {
+                    self.children.remove(&event.parent_id);
+                }
+            }
+        }
+    }
+
+    /// Get the index of the event with the given id.
+    pub fn by_id(&self, id: u64) -> Option<usize> {
+        self.by_id.get(&id).copied()
+    }
+
+    /// Get the indices of the direct children of an event, oldest first.
+    pub fn children(&self, id: u64) -> &[usize] {
+        self.children.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterate over every parent id with the indices of its direct children.
+    ///
+    /// Parents appear in no particular order and may themselves have been
+    /// evicted.
+    pub fn parents(&self) -> impl Iterator<Item = (u64, &[usize])> {
+        self.children
+            .iter()
+            .map(|(&id, children)| (id, children.as_slice()))
+    }
+
+    /// Number of indexed events.
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    /// Check if no events are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+}
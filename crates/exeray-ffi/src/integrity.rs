@@ -0,0 +1,33 @@
+//! Privilege context of the target process.
+
+/// Mandatory integrity level of the target's token, ordered from least to
+/// most privileged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntegrityLevel {
+    /// Low or untrusted, e.g. a sandboxed browser renderer.
+    Low,
+    /// Standard user.
+    Medium,
+    /// Elevated administrator.
+    High,
+    /// Local system or a protected process.
+    System,
+}
+
+impl IntegrityLevel {
+    /// Decode the core's raw level, where 0 means unknown.
+    pub(crate) fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Low),
+            2 => Some(Self::Medium),
+            3 => Some(Self::High),
+            4 => Some(Self::System),
+            _ => None,
+        }
+    }
+
+    /// Whether the target runs with administrator or system rights.
+    pub fn is_elevated(self) -> bool {
+        self >= Self::High
+    }
+}
@@ -3,17 +3,45 @@
 //! Provides safe Rust wrappers around the C++ ExeRay engine,
 //! including access to the EventGraph for event monitoring.
 
+pub mod analysis;
+pub mod attributes;
+pub mod category;
+pub mod checkpoint;
+pub mod classify;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+pub mod config;
+pub mod endpoint;
 pub mod engine;
+pub mod error;
 pub mod event;
 pub mod event_iter;
+pub mod exit_info;
+pub mod export;
+pub mod filter;
+pub mod index;
+pub mod integrity;
+pub mod operation;
+pub mod platform;
+pub mod process_tree;
+pub mod reader;
+pub mod risk;
+pub mod signature;
+pub mod snapshot;
+pub mod stats;
+pub mod status;
+#[cfg(feature = "serde")]
+pub mod summary;
 mod tests;
+pub mod thread_info;
+pub mod timestamp;
 pub mod view_state;
 
 // CXX bridge must be in lib.rs for cxxbridge tool to find it
 #[cxx::bridge(namespace = "exeray")]
 mod ffi {
     /// Event category classification.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub enum Category {
         FileSystem = 0,
         Registry = 1,
@@ -34,7 +62,7 @@ mod ffi {
     }
 
     /// Operation result status.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub enum Status {
         Success = 0,
         Denied = 1,
@@ -43,32 +71,106 @@ mod ffi {
         Suspicious = 4,
     }
 
+    /// Fixed fields of one event, read by `event_at` and `get_events`.
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    pub struct RawEvent {
+        pub id: u64,
+        pub parent_id: u64,
+        pub timestamp: u64,
+        pub category: u8,
+        pub status: u8,
+        pub operation: u8,
+        pub target_pid: u32,
+        pub flags: u16,
+    }
+
+    /// Target CPU times from `target_cpu_times`; zero unless `available`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RawCpuTimes {
+        pub available: bool,
+        pub kernel_ns: u64,
+        pub user_ns: u64,
+    }
+
+    /// Engine counters read as one unit by `get_stats`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RawStats {
+        pub event_count: usize,
+        pub events_dropped: u64,
+        pub buffers_lost: u64,
+        pub busy_threads: usize,
+        pub queue_depth: usize,
+        pub arena_used_bytes: usize,
+    }
+
+    /// Generation, flags and progress read as one unit by `task_state`.
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    pub struct RawTaskState {
+        pub generation: u64,
+        pub flags: u64,
+        pub progress: f32,
+    }
+
+    /// One thread of the target, returned in a batch by `target_threads`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RawThread {
+        pub tid: u32,
+        pub suspended: bool,
+        pub start_address: u64,
+    }
+
     unsafe extern "C++" {
         include!("exeray/ffi.hpp");
 
         pub type Handle;
 
         pub fn create(arena_mb: usize, threads: usize) -> UniquePtr<Handle>;
+        pub fn create_sized(
+            arena_mb: usize,
+            threads: usize,
+            expected_events: usize,
+        ) -> UniquePtr<Handle>;
+        pub fn supported_category_mask() -> u32;
+        pub fn has_monitoring_support() -> bool;
+        pub fn validate_target(exe_path: &str) -> u8;
         pub fn submit(self: Pin<&mut Handle>);
         pub fn generation(self: &Handle) -> u64;
         pub fn timestamp_ns(self: &Handle) -> u64;
         pub fn flags(self: &Handle) -> u64;
         pub fn progress(self: &Handle) -> f32;
+        pub fn task_state(self: &Handle) -> RawTaskState;
         pub fn idle(self: &Handle) -> bool;
         pub fn threads(self: &Handle) -> usize;
 
         // Event graph accessors
         pub fn event_count(handle: &Handle) -> usize;
-        pub fn event_get_id(handle: &Handle, index: usize) -> u64;
-        pub fn event_get_parent(handle: &Handle, index: usize) -> u64;
-        pub fn event_get_timestamp(handle: &Handle, index: usize) -> u64;
-        pub fn event_get_category(handle: &Handle, index: usize) -> u8;
-        pub fn event_get_status(handle: &Handle, index: usize) -> u8;
-        pub fn event_get_operation(handle: &Handle, index: usize) -> u8;
+        pub fn event_at(handle: &Handle, index: usize) -> RawEvent;
+        pub fn event_get_generation(handle: &Handle, index: usize) -> u64;
+        pub fn event_get_file_path(handle: &Handle, index: usize) -> String;
+        pub fn event_get_denial_reason(handle: &Handle, index: usize) -> String;
+        // Batch read: fills `out` with consecutive events from `start`
+        pub fn get_events(handle: &Handle, start: usize, out: &mut [RawEvent]) -> usize;
+        pub fn event_get_remote_addr(handle: &Handle, index: usize) -> u32;
+        pub fn event_get_remote_port(handle: &Handle, index: usize) -> u16;
+        pub fn event_get_address_family(handle: &Handle, index: usize) -> u8;
+        pub fn event_get_remote_addr6(handle: &Handle, index: usize) -> String;
+        pub fn event_get_process_pid(handle: &Handle, index: usize) -> u32;
+        pub fn event_get_process_parent_pid(handle: &Handle, index: usize) -> u32;
+        pub fn event_get_image_path(handle: &Handle, index: usize) -> String;
 
         // Monitoring control
         pub fn start_monitoring(self: Pin<&mut Handle>, exe_path: &str) -> bool;
+        pub fn start_monitoring_multi(self: Pin<&mut Handle>, exe_paths: &[String]) -> Vec<u32>;
         pub fn stop_monitoring(self: Pin<&mut Handle>);
+        pub fn is_monitoring(self: &Handle) -> bool;
+        pub fn flush(self: Pin<&mut Handle>) -> usize;
+        pub fn set_freeze_on_start(self: Pin<&mut Handle>, enabled: bool);
+        pub fn freeze_on_start(self: &Handle) -> bool;
+        pub fn frozen_at_entry(self: &Handle) -> bool;
+        pub fn set_capture_output(self: Pin<&mut Handle>, enabled: bool);
+        pub fn capture_output(self: &Handle) -> bool;
+        pub fn capturing_target_output(self: &Handle) -> bool;
+        pub fn take_target_output(self: &Handle) -> Vec<String>;
 
         // Target process control
         pub fn freeze_target(self: Pin<&mut Handle>);
@@ -78,13 +180,168 @@ mod ffi {
         // Target state
         pub fn target_pid(self: &Handle) -> u32;
         pub fn target_running(self: &Handle) -> bool;
+        pub fn target_frozen(self: &Handle) -> bool;
+        pub fn target_exited(self: &Handle) -> bool;
+        pub fn target_killed(self: &Handle) -> bool;
+        pub fn exit_pid(self: &Handle) -> u32;
+        pub fn exit_code(self: &Handle) -> u32;
+
+        // Target process details
+        pub fn target_command_line(self: &Handle) -> String;
+        pub fn target_working_directory(self: &Handle) -> String;
+        pub fn target_environment(self: &Handle) -> Vec<String>;
+        pub fn has_target_signature(self: &Handle) -> bool;
+        pub fn target_signed(self: &Handle) -> bool;
+        pub fn target_signature_verified(self: &Handle) -> bool;
+        pub fn target_signature_subject(self: &Handle) -> String;
+        pub fn target_integrity_level(self: &Handle) -> u8;
+        pub fn target_threads(self: &Handle) -> Vec<RawThread>;
+
+        // Breakpoints
+        pub fn break_on(self: Pin<&mut Handle>, category: u8);
+        pub fn clear_breakpoint(self: Pin<&mut Handle>);
+        pub fn breakpoint_hit(self: &Handle) -> u64;
+        pub fn set_freeze_on_suspicious(self: Pin<&mut Handle>, enabled: bool);
+        pub fn freeze_on_suspicious(self: &Handle) -> bool;
+        pub fn suspicious_hit(self: &Handle) -> u64;
+        pub fn clear_suspicious_hit(self: Pin<&mut Handle>);
+        pub fn auto_freeze_count(self: &Handle) -> u64;
+
+        // Safety cap
+        pub fn set_max_events(self: Pin<&mut Handle>, max_events: usize);
+        pub fn max_events(self: &Handle) -> usize;
+        pub fn max_events_reached(self: &Handle) -> bool;
+
+        // Status threshold
+        pub fn set_min_status(self: Pin<&mut Handle>, status: u8);
+        pub fn min_status(self: &Handle) -> u8;
+        pub fn events_filtered(self: &Handle) -> u64;
+
+        // Capture deadline
+        pub fn set_max_duration_ns(self: Pin<&mut Handle>, ns: u64);
+        pub fn max_duration_ns(self: &Handle) -> u64;
+        pub fn timed_out(self: &Handle) -> bool;
+
+        // Synthetic events and sessions (mock path). The Engine wrappers
+        // only exist in tests and with the `mock` feature.
+        #[allow(dead_code)]
+        pub fn simulate_session(self: Pin<&mut Handle>);
+        #[allow(dead_code)]
+        pub fn simulate_target_exit(self: Pin<&mut Handle>, pid: u32, exit_code: u32);
+        #[allow(dead_code)]
+        pub fn inject_event(
+            self: Pin<&mut Handle>,
+            category: u8,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+        ) -> u64;
+        #[allow(dead_code)]
+        pub fn inject_target_event(
+            self: Pin<&mut Handle>,
+            target_pid: u32,
+            category: u8,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+        ) -> u64;
+        #[allow(dead_code)]
+        pub fn inject_file_event(
+            self: Pin<&mut Handle>,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+            path: &str,
+        ) -> u64;
+        #[allow(dead_code)]
+        pub fn inject_network_event(
+            self: Pin<&mut Handle>,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+            remote_addr: u32,
+            remote_port: u16,
+        ) -> u64;
+        #[allow(dead_code)]
+        pub fn inject_network6_event(
+            self: Pin<&mut Handle>,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+            remote_addr: &str,
+            remote_port: u16,
+        ) -> u64;
+        #[allow(dead_code)]
+        pub fn inject_process_event(
+            self: Pin<&mut Handle>,
+            operation: u8,
+            status: u8,
+            parent_id: u64,
+            pid: u32,
+            parent_pid: u32,
+            image_path: &str,
+        ) -> u64;
+
+        // Provider configuration
+        pub fn set_provider_enabled(self: Pin<&mut Handle>, name: &str, enabled: bool);
+        pub fn provider_enabled(self: &Handle, name: &str) -> bool;
+        pub fn update_provider(self: Pin<&mut Handle>, name: &str, enabled: bool) -> bool;
+        pub fn enabled_providers(self: &Handle) -> Vec<String>;
+
+        // Arena
+        pub fn grow_arena(self: Pin<&mut Handle>, additional_mb: usize) -> bool;
+        pub fn arena_capacity(self: &Handle) -> usize;
+        pub fn arena_used(self: &Handle) -> usize;
+        pub fn expected_events(self: &Handle) -> usize;
+
+        // Timestamps
+        pub fn timestamp_epoch(self: &Handle) -> u8;
+        pub fn unix_offset_ns(self: &Handle) -> i64;
+        pub fn event_capacity(self: &Handle) -> usize;
+
+        // Statistics
+        pub fn get_stats(handle: &Handle) -> RawStats;
+
+        // Target CPU times
+        pub fn target_cpu_times(handle: &Handle) -> RawCpuTimes;
+
+        // Ring mode
+        pub fn set_ring_buffer(self: Pin<&mut Handle>, enabled: bool);
+        pub fn ring_buffer(self: &Handle) -> bool;
+        pub fn oldest_event_id(self: &Handle) -> u64;
+        pub fn clear_events_before(self: Pin<&mut Handle>, cutoff: u64) -> usize;
+        pub fn evict_oldest_events(self: Pin<&mut Handle>, n: usize) -> usize;
+
+        // Pending resolution
+        pub fn resolve_event(self: Pin<&mut Handle>, id: u64, status: u8) -> bool;
+        pub fn event_resolved_at(self: &Handle, id: u64) -> u64;
     }
 }
 
 // Re-export public API
-pub use engine::Engine;
+pub use attributes::EventAttributes;
+pub use checkpoint::restore_view;
+pub use classify::{Classifier, DefaultClassifier, Severity};
+pub use config::{ConfigError, MonitorConfig};
+pub use endpoint::Endpoint;
+pub use engine::{Engine, EngineBuilder, ExitCallback, MatchCallback};
+pub use error::{ConversionError, EngineError, ExportError, MonitorError};
 pub use event::Event;
-pub use event_iter::EventIter;
+pub use event_iter::{Coalesced, DrainIter, EventIter};
+pub use exit_info::ExitInfo;
 pub use ffi::Category;
 pub use ffi::Status;
-pub use view_state::ViewState;
+pub use filter::EventFilter;
+pub use index::EventGraphIndex;
+pub use integrity::IntegrityLevel;
+pub use operation::TypedOp;
+pub use platform::has_monitoring_support;
+pub use process_tree::{ProcessNode, ProcessTree};
+pub use reader::EngineReader;
+pub use risk::RiskWeights;
+pub use signature::SignatureInfo;
+pub use snapshot::{EventSnapshot, SnapshotMetadata};
+pub use stats::EngineStats;
+pub use thread_info::ThreadInfo;
+pub use timestamp::TimestampEpoch;
+pub use view_state::{StateFlags, ViewState};
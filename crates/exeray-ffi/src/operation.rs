@@ -0,0 +1,228 @@
+//! Category-scoped typed operation enums.
+//!
+//! Mirrors the operation enums in `core/include/exeray/event/types/operations/`.
+//! Each category has its own enum; [`TypedOp`] ties them together so consumers
+//! can match exhaustively on the decoded operation.
+
+use std::fmt;
+
+use crate::ffi::Category;
+
+/// Define a `#[repr(u8)]` operation enum with a fallible `from_u8`, a
+/// stable `name()`, and a `Display` impl rendering that name.
+macro_rules! operation_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $value:literal),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(u8)]
+        pub enum $name {
+            $($variant = $value),+
+        }
+
+        impl $name {
+            /// All variants in discriminant order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// Decode a raw operation code.
+            ///
+            /// Returns `None` for codes unknown to this version.
+            pub fn from_u8(val: u8) -> Option<Self> {
+                match val {
+                    $($value => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Get the stable display name of the operation.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)+
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    };
+}
+
+operation_enum! {
+    /// File system operation types.
+    FileOp { Create = 0, Delete = 1, Read = 2, Write = 3, Rename = 4, SetAttributes = 5 }
+}
+
+impl FileOp {
+    /// Check whether the operation writes a file, as counted by
+    /// [`Engine::file_writes`](crate::Engine::file_writes).
+    ///
+    /// `Create`, `Write` and `Rename` (which writes the new name) count;
+    /// `Read`, `Delete` and `SetAttributes` do not.
+    pub fn is_write(self) -> bool {
+        matches!(self, FileOp::Create | FileOp::Write | FileOp::Rename)
+    }
+}
+
+operation_enum! {
+    /// Windows registry operation types.
+    RegOp { CreateKey = 0, DeleteKey = 1, SetValue = 2, DeleteValue = 3, QueryValue = 4 }
+}
+
+operation_enum! {
+    /// Network operation types.
+    NetOp { Connect = 0, Listen = 1, Send = 2, Receive = 3, DnsQuery = 4 }
+}
+
+operation_enum! {
+    /// Process operation types.
+    ProcessOp { Create = 0, Terminate = 1, Inject = 2, LoadLibrary = 3 }
+}
+
+operation_enum! {
+    /// Task scheduler operation types.
+    SchedulerOp { CreateTask = 0, DeleteTask = 1, ModifyTask = 2, RunTask = 3 }
+}
+
+operation_enum! {
+    /// Input device operation types.
+    InputOp { BlockKeyboard = 0, BlockMouse = 1, InstallHook = 2 }
+}
+
+operation_enum! {
+    /// Image load/unload operation types.
+    ImageOp { Load = 0, Unload = 1 }
+}
+
+operation_enum! {
+    /// Thread operation types.
+    ThreadOp { Start = 0, End = 1, DCStart = 2, DCEnd = 3 }
+}
+
+operation_enum! {
+    /// Virtual memory operation types.
+    MemoryOp { Alloc = 0, Free = 1 }
+}
+
+operation_enum! {
+    /// PowerShell script operation types.
+    ScriptOp { Execute = 0, Module = 1 }
+}
+
+operation_enum! {
+    /// AMSI scan operation types.
+    AmsiOp { Scan = 0, Session = 1 }
+}
+
+operation_enum! {
+    /// DNS operation types.
+    DnsOp { Query = 0, Response = 1, Failure = 2 }
+}
+
+operation_enum! {
+    /// Security auditing operation types.
+    SecurityOp {
+        Logon = 0,
+        LogonFailed = 1,
+        PrivilegeAdjust = 2,
+        ProcessCreate = 3,
+        ProcessTerminate = 4,
+    }
+}
+
+operation_enum! {
+    /// Windows service operation types.
+    ServiceOp { Install = 0, Start = 1, Stop = 2, Delete = 3 }
+}
+
+operation_enum! {
+    /// WMI operation types.
+    WmiOp { Query = 0, ExecMethod = 1, Subscribe = 2, Connect = 3 }
+}
+
+operation_enum! {
+    /// .NET CLR runtime operation types.
+    ClrOp { AssemblyLoad = 0, AssemblyUnload = 1, MethodJit = 2 }
+}
+
+/// An operation decoded according to its event category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypedOp {
+    File(FileOp),
+    Registry(RegOp),
+    Network(NetOp),
+    Process(ProcessOp),
+    Scheduler(SchedulerOp),
+    Input(InputOp),
+    Image(ImageOp),
+    Thread(ThreadOp),
+    Memory(MemoryOp),
+    Script(ScriptOp),
+    Amsi(AmsiOp),
+    Dns(DnsOp),
+    Security(SecurityOp),
+    Service(ServiceOp),
+    Wmi(WmiOp),
+    Clr(ClrOp),
+    /// Operation code not known to this version for the given category.
+    Unknown(Category, u8),
+}
+
+impl TypedOp {
+    /// Decode a raw operation code for the given category.
+    pub fn decode(category: Category, op: u8) -> TypedOp {
+        let typed = match category {
+            Category::FileSystem => FileOp::from_u8(op).map(TypedOp::File),
+            Category::Registry => RegOp::from_u8(op).map(TypedOp::Registry),
+            Category::Network => NetOp::from_u8(op).map(TypedOp::Network),
+            Category::Process => ProcessOp::from_u8(op).map(TypedOp::Process),
+            Category::Scheduler => SchedulerOp::from_u8(op).map(TypedOp::Scheduler),
+            Category::Input => InputOp::from_u8(op).map(TypedOp::Input),
+            Category::Image => ImageOp::from_u8(op).map(TypedOp::Image),
+            Category::Thread => ThreadOp::from_u8(op).map(TypedOp::Thread),
+            Category::Memory => MemoryOp::from_u8(op).map(TypedOp::Memory),
+            Category::Script => ScriptOp::from_u8(op).map(TypedOp::Script),
+            Category::Amsi => AmsiOp::from_u8(op).map(TypedOp::Amsi),
+            Category::Dns => DnsOp::from_u8(op).map(TypedOp::Dns),
+            Category::Security => SecurityOp::from_u8(op).map(TypedOp::Security),
+            Category::Service => ServiceOp::from_u8(op).map(TypedOp::Service),
+            Category::Wmi => WmiOp::from_u8(op).map(TypedOp::Wmi),
+            Category::Clr => ClrOp::from_u8(op).map(TypedOp::Clr),
+            _ => None,
+        };
+        typed.unwrap_or(TypedOp::Unknown(category, op))
+    }
+
+    /// Get the stable display name of the operation, without its category.
+    ///
+    /// Returns `"Unknown"` for codes unknown to this version.
+    pub fn name(self) -> &'static str {
+        match self {
+            TypedOp::File(op) => op.name(),
+            TypedOp::Registry(op) => op.name(),
+            TypedOp::Network(op) => op.name(),
+            TypedOp::Process(op) => op.name(),
+            TypedOp::Scheduler(op) => op.name(),
+            TypedOp::Input(op) => op.name(),
+            TypedOp::Image(op) => op.name(),
+            TypedOp::Thread(op) => op.name(),
+            TypedOp::Memory(op) => op.name(),
+            TypedOp::Script(op) => op.name(),
+            TypedOp::Amsi(op) => op.name(),
+            TypedOp::Dns(op) => op.name(),
+            TypedOp::Security(op) => op.name(),
+            TypedOp::Service(op) => op.name(),
+            TypedOp::Wmi(op) => op.name(),
+            TypedOp::Clr(op) => op.name(),
+            TypedOp::Unknown(..) => "Unknown",
+        }
+    }
+}
+
+/// Renders [`TypedOp::name`]; unknown codes render as `Unknown`.
+impl fmt::Display for TypedOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,13 @@
+//! Platform capabilities of the linked core.
+
+use crate::ffi;
+
+/// Whether this build can monitor targets.
+///
+/// True only on Windows with the core built with its ETW session; on other
+/// platforms [`Engine::start_monitoring`](crate::Engine::start_monitoring)
+/// can never succeed, so callers can say so up front instead of checking
+/// the platform themselves.
+pub fn has_monitoring_support() -> bool {
+    cfg!(windows) && ffi::has_monitoring_support()
+}
@@ -0,0 +1,97 @@
+//! Process ancestry reconstructed from Process create events.
+
+use std::collections::BTreeMap;
+
+/// One process in a [`ProcessTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessNode {
+    /// Process ID.
+    pub pid: u32,
+    /// Process ID of its creator, as reported by the create event.
+    pub parent_pid: u32,
+    /// Full executable path, empty if unknown.
+    pub image: String,
+    /// PIDs of the processes it spawned, in creation order.
+    pub children: Vec<u32>,
+}
+
+impl ProcessNode {
+    /// Executable file name without its directory.
+    pub fn name(&self) -> &str {
+        self.image.rsplit(['\\', '/']).next().unwrap_or(&self.image)
+    }
+}
+
+/// Which PID spawned which.
+///
+/// Processes whose creator was not captured (orphans) are roots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessTree {
+    nodes: BTreeMap<u32, ProcessNode>,
+    roots: Vec<u32>,
+}
+
+impl ProcessTree {
+    /// Build the tree from `(pid, parent_pid, image)` create records,
+    /// oldest first.
+    ///
+    /// A PID created more than once (PID reuse) keeps its latest record.
+    pub fn from_creates(creates: impl IntoIterator<Item = (u32, u32, String)>) -> Self {
+        let mut nodes = BTreeMap::new();
+        let mut order = Vec::new();
+        for (pid, parent_pid, image) in creates {
+            let node = ProcessNode {
+                pid,
+                parent_pid,
+                image,
+                children: Vec::new(),
+            };
+            if nodes.insert(pid, node).is_none() {
+                order.push(pid);
+            }
+        }
+
+        let mut roots = Vec::new();
+        for pid in order {
+            let parent = nodes[&pid].parent_pid;
+            if parent != pid && nodes.contains_key(&parent) {
+                if let Some(node) = nodes.get_mut(&parent) {
+                    node.children.push(pid);
+                }
+            } else {
+                roots.push(pid);
+            }
+        }
+        Self { nodes, roots }
+    }
+
+    /// PIDs without a captured creator, in creation order.
+    pub fn roots(&self) -> &[u32] {
+        &self.roots
+    }
+
+    /// Look up a process by PID.
+    pub fn get(&self, pid: u32) -> Option<&ProcessNode> {
+        self.nodes.get(&pid)
+    }
+
+    /// PIDs spawned by `pid`; empty for unknown PIDs.
+    pub fn children(&self, pid: u32) -> &[u32] {
+        self.nodes.get(&pid).map_or(&[], |node| &node.children)
+    }
+
+    /// Iterate over all processes in PID order.
+    pub fn iter(&self) -> impl Iterator<Item = &ProcessNode> {
+        self.nodes.values()
+    }
+
+    /// Number of processes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether no process creation was captured.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
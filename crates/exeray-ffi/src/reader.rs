@@ -0,0 +1,82 @@
+//! Thread-safe read-only view of an [`Engine`].
+
+use std::fmt;
+
+use crate::engine::Engine;
+use crate::ffi;
+use crate::view_state::ViewState;
+
+/// Read-only view of an engine that can be shared across threads.
+///
+/// [`Engine`] itself is neither `Send` nor `Sync`: its caches and callbacks
+/// live in `RefCell`s. The accessors here only touch state the C++ core
+/// keeps in atomics or behind the graph's own lock, so they are safe to call
+/// concurrently with the worker and ETW threads mutating the engine, e.g.
+/// from a timer or another event loop.
+#[derive(Clone, Copy)]
+pub struct EngineReader<'a> {
+    handle: &'a ffi::Handle,
+}
+
+// SAFETY: every method only calls C++ accessors that are documented as
+// thread-safe: atomic loads of generation, flags and progress, the seqlock
+// read of all three, and the graph count.
+unsafe impl Send for EngineReader<'_> {}
+unsafe impl Sync for EngineReader<'_> {}
+
+impl<'a> EngineReader<'a> {
+    /// Get a consistent view of the engine state.
+    ///
+    /// The core writes flags and progress under a seqlock and reads all
+    /// three fields inside it, retrying while a write is in progress, so a
+    /// completed task's flags and generation are seen together or not at all.
+    pub fn poll_consistent(&self) -> ViewState {
+        let state = self.handle.task_state();
+        ViewState {
+            generation: state.generation,
+            timestamp_ns: self.handle.timestamp_ns(),
+            flags: state.flags,
+            progress: state.progress,
+        }
+    }
+
+    /// Get the current generation.
+    pub fn generation(&self) -> u64 {
+        self.handle.generation()
+    }
+
+    /// Get the current event count.
+    pub fn event_count(&self) -> usize {
+        ffi::event_count(self.handle)
+    }
+}
+
+impl fmt::Debug for EngineReader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EngineReader")
+            .field("generation", &self.generation())
+            .field("event_count", &self.event_count())
+            .finish()
+    }
+}
+
+impl Engine {
+    /// Get a [`EngineReader`] for polling from other threads.
+    pub fn reader(&self) -> EngineReader<'_> {
+        EngineReader {
+            handle: &self.handle,
+        }
+    }
+
+    /// Poll the engine state without a torn read.
+    ///
+    /// Like [`Engine::poll`], but the view is read through
+    /// [`EngineReader::poll_consistent`]: `poll` loads each field once and can
+    /// pair a new generation with flags from before it if a task completes
+    /// mid-read.
+    pub fn poll_consistent(&self) -> ViewState {
+        self.dispatch_matches();
+        self.check_target_exit();
+        self.reader().poll_consistent()
+    }
+}
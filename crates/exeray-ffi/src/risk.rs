@@ -0,0 +1,77 @@
+//! Severity-weighted suspicion scoring of a capture.
+
+use crate::classify::{Classifier, DefaultClassifier, Severity};
+use crate::event::Event;
+use crate::operation::{AmsiOp, NetOp, TypedOp};
+
+/// Per-signal weights for [`risk_score`].
+///
+/// An event adds the weight of every signal it matches, so a suspicious
+/// network connect scores both `suspicious` and `network_connect`. The
+/// status signals follow the event's [`Severity`]: high scores as
+/// suspicious, medium as denied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RiskWeights {
+    /// Event of [`Severity::High`], by default one with
+    /// [`Status::Suspicious`](crate::Status::Suspicious).
+    pub suspicious: u32,
+    /// Event of [`Severity::Medium`], by default one with
+    /// [`Status::Denied`](crate::Status::Denied).
+    pub denied: u32,
+    /// AMSI scan ([`AmsiOp::Scan`]).
+    pub amsi_scan: u32,
+    /// Outbound network connection ([`NetOp::Connect`]).
+    pub network_connect: u32,
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            suspicious: 10,
+            denied: 5,
+            amsi_scan: 8,
+            network_connect: 3,
+        }
+    }
+}
+
+impl RiskWeights {
+    /// Score contribution of a single event, classified by
+    /// [`DefaultClassifier`].
+    pub fn weigh(&self, event: &Event) -> u32 {
+        self.weigh_as(event, DefaultClassifier.classify(event))
+    }
+
+    /// Score contribution of a single event of the given severity.
+    pub fn weigh_as(&self, event: &Event, severity: Severity) -> u32 {
+        let status = match severity {
+            Severity::High => self.suspicious,
+            Severity::Medium => self.denied,
+            _ => 0,
+        };
+        let operation = match event.typed_operation() {
+            TypedOp::Amsi(AmsiOp::Scan) => self.amsi_scan,
+            TypedOp::Network(NetOp::Connect) => self.network_connect,
+            _ => 0,
+        };
+        status.saturating_add(operation)
+    }
+}
+
+/// Sum the weighted contributions of all events, saturating at `u32::MAX`.
+pub fn risk_score<'a>(events: impl IntoIterator<Item = &'a Event>, weights: &RiskWeights) -> u32 {
+    events.into_iter().fold(0u32, |score, event| {
+        score.saturating_add(weights.weigh(event))
+    })
+}
+
+/// Like [`risk_score`], with event severities assigned by `classifier`.
+pub fn risk_score_classified<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    weights: &RiskWeights,
+    classifier: &dyn Classifier,
+) -> u32 {
+    events.into_iter().fold(0u32, |score, event| {
+        score.saturating_add(weights.weigh_as(event, classifier.classify(event)))
+    })
+}
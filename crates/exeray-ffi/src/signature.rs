@@ -0,0 +1,13 @@
+//! Code-signing status of the target executable.
+
+/// Authenticode signature of the target, looked up once at launch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignatureInfo {
+    /// The image carries an embedded signature.
+    pub signed: bool,
+    /// Display name of the signing certificate; empty if unsigned or the
+    /// certificate could not be read.
+    pub subject: String,
+    /// The signature chains to a trusted root (revocation is not checked).
+    pub verified: bool,
+}
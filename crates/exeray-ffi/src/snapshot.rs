@@ -0,0 +1,179 @@
+//! Owned event snapshots for comparing a run against a baseline.
+
+use std::collections::{HashMap, HashSet};
+use std::time::SystemTime;
+
+use crate::engine::Engine;
+use crate::event::Event;
+use crate::ffi::Category;
+use crate::timestamp::TimestampEpoch;
+
+/// Structural identity of an event: what it did, ignoring when and where
+/// in the causality tree it happened.
+pub type StructuralKey = (Category, u8);
+
+/// Structural key of an event: its category and raw operation code.
+///
+/// This is [`Event::content_key`] without the status, so a baseline event
+/// still matches when a later run denies the same operation.
+pub fn structural_key(event: &Event) -> StructuralKey {
+    (event.category, event.operation)
+}
+
+/// Session details recorded alongside a snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotMetadata {
+    /// Path of the primary target, if one was monitored.
+    pub exe_path: Option<String>,
+    /// Wall-clock time monitoring started.
+    pub started_at: Option<SystemTime>,
+    /// Engine generation when the snapshot was taken.
+    pub generation: u64,
+    /// Clock the event timestamps count from, if recorded.
+    pub timestamp_epoch: Option<TimestampEpoch>,
+    /// Unix minus monotonic time of the capturing engine, see
+    /// [`Engine::to_unix_nanos`].
+    pub unix_offset_ns: i64,
+}
+
+impl SnapshotMetadata {
+    /// Convert a raw timestamp of this snapshot to Unix nanoseconds.
+    ///
+    /// Uses the capturing engine's clock, not the current one. Returns
+    /// `None` if the snapshot did not record its clock.
+    pub fn to_unix_nanos(&self, raw: u64) -> Option<u64> {
+        self.timestamp_epoch
+            .map(|epoch| epoch.to_unix_nanos(raw, self.unix_offset_ns))
+    }
+}
+
+/// Owned copy of a run's events, e.g. a known-good baseline.
+#[derive(Debug, Clone, Default)]
+pub struct EventSnapshot {
+    events: Vec<Event>,
+    keys: HashSet<StructuralKey>,
+    metadata: SnapshotMetadata,
+    /// `(source index, original id)` to merged id, see [`EventSnapshot::merge`].
+    id_map: HashMap<(usize, u64), u64>,
+}
+
+impl EventSnapshot {
+    /// Build a snapshot from events of a prior run.
+    pub fn from_events(events: Vec<Event>) -> Self {
+        let keys = events.iter().map(structural_key).collect();
+        Self {
+            events,
+            keys,
+            metadata: SnapshotMetadata::default(),
+            id_map: HashMap::new(),
+        }
+    }
+
+    /// Combine separately captured snapshots into one.
+    ///
+    /// Events are concatenated in source order and re-keyed to ids `1..=n`
+    /// in that order, since each capture numbers its events from 1. A
+    /// parent id is rewritten to the parent's new id when the parent is in
+    /// the same source; parents that were not captured (e.g. evicted) make
+    /// the event a root, so no event points into another source.
+    ///
+    /// Look up an event's new id with [`EventSnapshot::remapped_id`]. The
+    /// merged snapshot carries default metadata.
+    pub fn merge(snapshots: &[EventSnapshot]) -> EventSnapshot {
+        let mut id_map = HashMap::new();
+        let mut next_id = 1;
+        for (source, snapshot) in snapshots.iter().enumerate() {
+            for event in &snapshot.events {
+                id_map.insert((source, event.id), next_id);
+                next_id += 1;
+            }
+        }
+
+        let events = snapshots
+            .iter()
+            .enumerate()
+            .flat_map(|(source, snapshot)| {
+                let id_map = &id_map;
+                snapshot.events.iter().map(move |event| Event {
+                    id: id_map[&(source, event.id)],
+                    parent_id: id_map.get(&(source, event.parent_id)).copied().unwrap_or(0),
+                    ..*event
+                })
+            })
+            .collect();
+
+        let mut merged = Self::from_events(events);
+        merged.id_map = id_map;
+        merged
+    }
+
+    /// New id of event `id` from the `source`-th snapshot passed to
+    /// [`EventSnapshot::merge`].
+    ///
+    /// `None` if the event was not part of that source, or if this
+    /// snapshot was not built by a merge.
+    pub fn remapped_id(&self, source: usize, id: u64) -> Option<u64> {
+        self.id_map.get(&(source, id)).copied()
+    }
+
+    /// Attach session metadata.
+    pub fn with_metadata(mut self, metadata: SnapshotMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn metadata(&self) -> &SnapshotMetadata {
+        &self.metadata
+    }
+
+    /// Events in the snapshot, in capture order.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Check whether the snapshot exhibited the event's structural key.
+    pub fn contains(&self, event: &Event) -> bool {
+        self.keys.contains(&structural_key(event))
+    }
+
+    /// Events whose structural key never occurs in this snapshot.
+    ///
+    /// Every occurrence of a new key is returned, in input order.
+    pub fn deviations<'a>(&self, events: impl IntoIterator<Item = &'a Event>) -> Vec<Event> {
+        events
+            .into_iter()
+            .filter(|event| !self.contains(event))
+            .copied()
+            .collect()
+    }
+}
+
+impl Engine {
+    /// Copy the current events into a snapshot, e.g. to keep a clean run
+    /// as the baseline for [`Engine::deviation_from`].
+    pub fn snapshot(&self) -> EventSnapshot {
+        EventSnapshot::from_events(self.iter_events().collect()).with_metadata(SnapshotMetadata {
+            exe_path: self.session.exe_path.clone(),
+            started_at: self.session.started_at,
+            generation: self.handle.generation(),
+            timestamp_epoch: Some(self.timestamp_epoch()),
+            unix_offset_ns: self.handle.unix_offset_ns(),
+        })
+    }
+
+    /// Events whose category/operation the baseline never exhibited.
+    ///
+    /// See [`EventSnapshot::deviations`].
+    pub fn deviation_from(&self, baseline: &EventSnapshot) -> Vec<Event> {
+        let events: Vec<Event> = self.iter_events().collect();
+        baseline.deviations(&events)
+    }
+}
@@ -0,0 +1,34 @@
+//! Engine counter snapshot.
+
+use crate::ffi::RawStats;
+
+/// All engine counters, read in a single FFI call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EngineStats {
+    /// Live events in the graph.
+    pub event_count: usize,
+    /// Events rejected because the graph was full.
+    pub events_dropped: u64,
+    /// ETW buffers lost by the session (0 when not monitoring).
+    pub buffers_lost: u64,
+    /// Worker threads currently running a task.
+    pub busy_threads: usize,
+    /// Tasks waiting for a worker.
+    pub queue_depth: usize,
+    /// Arena bytes in use.
+    pub arena_used_bytes: usize,
+}
+
+impl EngineStats {
+    /// Build from the counters returned by `get_stats`.
+    pub(crate) fn from_raw(raw: RawStats) -> Self {
+        Self {
+            event_count: raw.event_count,
+            events_dropped: raw.events_dropped,
+            buffers_lost: raw.buffers_lost,
+            busy_threads: raw.busy_threads,
+            queue_depth: raw.queue_depth,
+            arena_used_bytes: raw.arena_used_bytes,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Name and conversion helpers for the Status enum.
+
+use crate::error::ConversionError;
+use crate::ffi::Status;
+
+/// Convert a raw discriminant, rejecting unknown values.
+impl TryFrom<u8> for Status {
+    type Error = ConversionError;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Status::Success),
+            1 => Ok(Status::Denied),
+            2 => Ok(Status::Pending),
+            3 => Ok(Status::Error),
+            4 => Ok(Status::Suspicious),
+            _ => Err(ConversionError::UnknownStatus(val)),
+        }
+    }
+}
+
+impl Status {
+    /// Convert a raw discriminant, mapping unknown values to `Error`.
+    ///
+    /// Infallible counterpart of [`Status::try_from`] for values read from
+    /// the core; the fallback is a safety net rather than expected input.
+    pub fn from_u8_lossy(val: u8) -> Status {
+        Status::try_from(val).unwrap_or(Status::Error)
+    }
+
+    /// Rank by how noteworthy the status is:
+    /// `Success < Pending < Error < Denied < Suspicious`.
+    ///
+    /// Matches the ordering the core applies for
+    /// [`EngineBuilder::min_status`](crate::EngineBuilder::min_status).
+    pub fn severity(self) -> u8 {
+        match self {
+            Status::Success => 0,
+            Status::Pending => 1,
+            Status::Error => 2,
+            Status::Denied => 3,
+            _ => 4,
+        }
+    }
+
+    /// Get the stable display name of the status.
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Success => "Success",
+            Status::Denied => "Denied",
+            Status::Pending => "Pending",
+            Status::Error => "Error",
+            Status::Suspicious => "Suspicious",
+            _ => "Unknown",
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! One-object JSON summary of a capture, for dashboards.
+
+use serde_json::{Map, Value, json};
+
+use crate::analysis;
+use crate::engine::Engine;
+use crate::ffi::Status;
+
+/// Statuses in the order they appear in the summary.
+const STATUSES: [Status; 5] = [
+    Status::Success,
+    Status::Pending,
+    Status::Error,
+    Status::Denied,
+    Status::Suspicious,
+];
+
+impl Engine {
+    /// Summarize the capture as a single JSON object.
+    ///
+    /// Keys:
+    /// - `total_events`, `events_dropped`: live and rejected event counts
+    /// - `categories`, `statuses`: counts by name, omitting zeros
+    /// - `risk_score`: [`Engine::risk_score`]
+    /// - `duration_ns`: span between the first and last event, see
+    ///   [`Engine::time_span`]
+    /// - `target_pid`: the monitored (or exited) target, `null` if none
+    /// - `exit_code`: `null` until the target exits
+    pub fn summary_json(&self) -> Value {
+        let stats = self.stats();
+        let (histogram, statuses) = self.with_events(|events| {
+            let mut statuses = [0usize; STATUSES.len()];
+            for event in events {
+                if let Some(i) = STATUSES.iter().position(|&s| s == event.status) {
+                    statuses[i] += 1;
+                }
+            }
+            (analysis::category_histogram(events), statuses)
+        });
+
+        let categories: Map<String, Value> = histogram
+            .into_iter()
+            .filter(|&(_, count)| count > 0)
+            .map(|(category, count)| (category.name().to_string(), count.into()))
+            .collect();
+        let statuses: Map<String, Value> = STATUSES
+            .iter()
+            .zip(statuses)
+            .filter(|&(_, count)| count > 0)
+            .map(|(status, count)| (status.name().to_string(), count.into()))
+            .collect();
+
+        let exited = self.target_exited();
+        let target_pid = match self.target_pid() {
+            0 if exited => Some(self.handle.exit_pid()),
+            0 => None,
+            pid => Some(pid),
+        };
+
+        json!({
+            "total_events": stats.event_count,
+            "events_dropped": stats.events_dropped,
+            "categories": categories,
+            "statuses": statuses,
+            "risk_score": self.risk_score(),
+            "duration_ns": self.time_span().map_or(0, |(first, last)| last - first),
+            "target_pid": target_pid,
+            "exit_code": exited.then(|| self.handle.exit_code()),
+        })
+    }
+}
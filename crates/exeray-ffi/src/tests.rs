@@ -2,8 +2,15 @@
 
 #![cfg(test)]
 
+use crate::attributes::EventAttributes;
+use crate::config::{ConfigError, MonitorConfig};
 use crate::engine::Engine;
+use crate::error::{ConversionError, EngineError, MonitorError};
+use crate::event::Event;
 use crate::ffi::{Category, Status};
+use crate::operation::TypedOp;
+use crate::snapshot::EventSnapshot;
+use crate::view_state::{StateFlags, ViewState};
 
 #[test]
 fn test_event_count_initially_zero() {
@@ -68,3 +75,2311 @@ fn test_target_state_api_exists() {
     assert_eq!(engine.target_pid(), 0);
     assert!(!engine.target_running());
 }
+
+#[test]
+fn test_builder_category_selection() {
+    let engine = Engine::builder()
+        .arena_mb(16)
+        .categories(&[Category::Process, Category::Network])
+        .build();
+    assert!(engine.provider_enabled("Process"));
+    assert!(engine.provider_enabled("Network"));
+    assert!(!engine.provider_enabled("File"));
+}
+
+#[test]
+fn test_monitor_config_round_trip() {
+    let config = MonitorConfig {
+        arena_mb: 32,
+        threads: 2,
+        categories: vec!["Process".into(), "Dns".into()],
+        target: Some("C:\\Windows\\notepad.exe".into()),
+    };
+    let path = std::env::temp_dir().join(format!("exeray-config-{}.toml", std::process::id()));
+
+    config.save(&path).unwrap();
+    let loaded = MonitorConfig::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded, config);
+    let engine = Engine::from_config(&loaded).unwrap();
+    assert!(engine.provider_enabled("DNS"));
+    assert!(!engine.provider_enabled("Registry"));
+}
+
+#[test]
+fn test_monitor_config_validation() {
+    let zero = MonitorConfig {
+        arena_mb: 0,
+        ..MonitorConfig::default()
+    };
+    assert!(matches!(zero.validate(), Err(ConfigError::ZeroArena)));
+
+    let unknown = MonitorConfig {
+        categories: vec!["Bogus".into()],
+        ..MonitorConfig::default()
+    };
+    assert!(matches!(
+        unknown.validate(),
+        Err(ConfigError::UnknownCategory(name)) if name == "Bogus"
+    ));
+}
+
+#[test]
+fn test_typed_operation_op_zero_per_category() {
+    use crate::operation::*;
+
+    let expected = [
+        (Category::FileSystem, TypedOp::File(FileOp::Create)),
+        (Category::Registry, TypedOp::Registry(RegOp::CreateKey)),
+        (Category::Network, TypedOp::Network(NetOp::Connect)),
+        (Category::Process, TypedOp::Process(ProcessOp::Create)),
+        (
+            Category::Scheduler,
+            TypedOp::Scheduler(SchedulerOp::CreateTask),
+        ),
+        (Category::Input, TypedOp::Input(InputOp::BlockKeyboard)),
+        (Category::Image, TypedOp::Image(ImageOp::Load)),
+        (Category::Thread, TypedOp::Thread(ThreadOp::Start)),
+        (Category::Memory, TypedOp::Memory(MemoryOp::Alloc)),
+        (Category::Script, TypedOp::Script(ScriptOp::Execute)),
+        (Category::Amsi, TypedOp::Amsi(AmsiOp::Scan)),
+        (Category::Dns, TypedOp::Dns(DnsOp::Query)),
+        (Category::Security, TypedOp::Security(SecurityOp::Logon)),
+        (Category::Service, TypedOp::Service(ServiceOp::Install)),
+        (Category::Wmi, TypedOp::Wmi(WmiOp::Query)),
+        (Category::Clr, TypedOp::Clr(ClrOp::AssemblyLoad)),
+    ];
+
+    for (category, typed) in expected {
+        let event = Event {
+            id: 1,
+            parent_id: 0,
+            timestamp: 0,
+            category,
+            status: Status::Success,
+            operation: 0,
+            target_pid: 0,
+            attributes: EventAttributes::empty(),
+        };
+        assert_eq!(event.typed_operation(), typed);
+    }
+}
+
+#[test]
+fn test_typed_operation_unknown_code() {
+    assert_eq!(
+        TypedOp::decode(Category::Memory, 200),
+        TypedOp::Unknown(Category::Memory, 200)
+    );
+}
+
+#[test]
+fn test_ring_buffer_mode_reflected_in_engine() {
+    let engine = Engine::builder().arena_mb(16).ring_buffer(true).build();
+    assert!(engine.ring_buffer());
+    assert_eq!(engine.oldest_event_id(), 0);
+
+    let engine = Engine::new(16, 1);
+    assert!(!engine.ring_buffer());
+}
+
+#[test]
+fn test_inject_event_mock_path() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let child = engine
+        .inject_event(Category::FileSystem, 3, Status::Denied, root)
+        .unwrap();
+
+    assert_eq!(engine.event_count(), 2);
+    let event = engine.get_event(1).unwrap();
+    assert_eq!(event.id, child);
+    assert_eq!(event.parent_id, root);
+    assert_eq!(event.category, Category::FileSystem);
+    assert_eq!(event.status, Status::Denied);
+    assert_eq!(event.operation, 3);
+}
+
+#[test]
+fn test_break_on_category_hit() {
+    let mut engine = Engine::new(16, 1);
+    engine.break_on(Category::Network);
+
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+    assert!(engine.breakpoint_hit().is_none());
+
+    let id = engine
+        .inject_event(Category::Network, 0, Status::Success, 0)
+        .unwrap();
+    let hit = engine.breakpoint_hit().unwrap();
+    assert_eq!(hit.id, id);
+    assert_eq!(hit.category, Category::Network);
+
+    // One-shot: a second network event does not move the hit
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    assert_eq!(engine.breakpoint_hit().unwrap().id, id);
+}
+
+#[test]
+fn test_clear_breakpoint() {
+    let mut engine = Engine::new(16, 1);
+    engine.break_on(Category::Network);
+    engine.clear_breakpoint();
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    assert!(engine.breakpoint_hit().is_none());
+
+    engine.break_on(Category::Network);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    assert!(engine.breakpoint_hit().is_some());
+    engine.clear_breakpoint();
+    assert!(engine.breakpoint_hit().is_none());
+}
+
+#[test]
+fn test_freeze_on_suspicious_records_first_hit() {
+    let engine = Engine::new(16, 1);
+    assert!(!engine.freeze_on_suspicious());
+
+    let mut engine = Engine::builder()
+        .arena_mb(16)
+        .freeze_on_suspicious(true)
+        .build();
+    assert!(engine.freeze_on_suspicious());
+
+    // Injected events run the same hooks as captured ones
+    engine.inject_event(Category::Network, 0, Status::Denied, 0);
+    assert!(engine.suspicious_hit().is_none());
+    assert_eq!(engine.auto_freeze_count(), 0);
+
+    let first = engine
+        .inject_event(Category::Network, 0, Status::Suspicious, 0)
+        .unwrap();
+    engine.inject_event(Category::Process, 0, Status::Suspicious, 0);
+    assert_eq!(engine.suspicious_hit().unwrap().id, first);
+    assert_eq!(engine.auto_freeze_count(), 1);
+
+    // The guard and the category breakpoint keep separate hits
+    engine.break_on(Category::Registry);
+    let breakpoint = engine
+        .inject_event(Category::Registry, 0, Status::Success, 0)
+        .unwrap();
+    assert_eq!(engine.breakpoint_hit().unwrap().id, breakpoint);
+    assert_eq!(engine.suspicious_hit().unwrap().id, first);
+    assert_eq!(engine.auto_freeze_count(), 2);
+    engine.clear_breakpoint();
+    engine.inject_event(Category::Registry, 0, Status::Suspicious, 0);
+    assert_eq!(engine.suspicious_hit().unwrap().id, first);
+    assert_eq!(engine.auto_freeze_count(), 2);
+
+    // Clearing the guard's hit re-arms it
+    engine.clear_suspicious_hit();
+    let second = engine
+        .inject_event(Category::Registry, 0, Status::Suspicious, 0)
+        .unwrap();
+    assert_eq!(engine.suspicious_hit().unwrap().id, second);
+    assert!(engine.breakpoint_hit().is_none());
+    assert_eq!(engine.auto_freeze_count(), 3);
+}
+
+#[test]
+fn test_try_new_zero_arena_is_error() {
+    assert!(matches!(
+        Engine::try_new(0, 1),
+        Err(EngineError::AllocationFailed { arena_mb: 0 })
+    ));
+    assert!(Engine::builder().arena_mb(0).try_build().is_err());
+}
+
+#[test]
+fn test_try_new_valid_arena() {
+    let engine = Engine::try_new(16, 1).unwrap();
+    assert_eq!(engine.event_count(), 0);
+}
+
+#[test]
+fn test_engine_ids_are_distinct() {
+    let a = Engine::new(16, 1);
+    let b = Engine::new(16, 1);
+    assert_ne!(a.id(), b.id());
+    assert!(format!("{a:?}").contains(&format!("id: {}", a.id())));
+}
+
+/// Build a synthetic event for tests that don't need the core.
+fn synthetic(id: u64, parent_id: u64, category: Category, status: Status) -> Event {
+    Event {
+        id,
+        parent_id,
+        timestamp: id * 1_000,
+        category,
+        status,
+        operation: 0,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    }
+}
+
+#[test]
+fn test_write_dot_nodes_and_edges() {
+    let events = [
+        synthetic(1, 0, Category::Process, Status::Success),
+        synthetic(2, 1, Category::FileSystem, Status::Success),
+        synthetic(3, 1, Category::Network, Status::Suspicious),
+        synthetic(4, 2, Category::Registry, Status::Denied),
+        // Parent 99 is unknown: node kept, edge skipped
+        synthetic(5, 99, Category::Thread, Status::Success),
+    ];
+
+    let mut out = Vec::new();
+    crate::export::write_dot(&events, &mut out).unwrap();
+    let dot = String::from_utf8(out).unwrap();
+
+    assert!(dot.starts_with("digraph events {"));
+    assert_eq!(dot.matches("[label=").count(), 5);
+    assert_eq!(dot.matches(" -> ").count(), 3);
+    assert!(dot.contains("e1 -> e3;"));
+    assert!(!dot.contains("e99"));
+    assert!(dot.contains("fillcolor=\"red\""));
+}
+
+#[test]
+fn test_export_dot_engine() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::FileSystem, 3, Status::Success, root);
+
+    let mut out = Vec::new();
+    engine.export_dot(&mut out).unwrap();
+    let dot = String::from_utf8(out).unwrap();
+    assert_eq!(dot.matches(" -> ").count(), 1);
+}
+
+#[test]
+fn test_export_chrome_trace() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::FileSystem, 3, Status::Success, root);
+    engine.inject_event(Category::Network, 0, Status::Suspicious, root);
+
+    let mut out = Vec::new();
+    engine.export_chrome_trace(&mut out).unwrap();
+    let trace: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let events = trace["traceEvents"].as_array().unwrap();
+    assert_eq!(events.len(), 3);
+
+    // The root spans its children, the leaves are instants
+    assert_eq!(events[0]["ph"], "X");
+    assert_eq!(events[0]["cat"], "Process");
+    assert!(events[0]["dur"].as_f64().unwrap() >= 0.0);
+    assert_eq!(events[1]["ph"], "i");
+    assert_eq!(events[2]["args"]["parent_id"], root);
+}
+
+#[test]
+fn test_freeze_on_start_flag() {
+    let engine = Engine::new(16, 1);
+    assert!(!engine.freeze_on_start());
+
+    let mut engine = Engine::builder().arena_mb(16).freeze_on_start(true).build();
+    assert!(engine.freeze_on_start());
+    assert!(!engine.target_frozen());
+    assert!(!engine.frozen_at_entry());
+
+    // A failed launch holds nothing at entry
+    assert!(engine.start_monitoring("nonexistent.exe").is_err());
+    assert!(!engine.is_monitoring());
+    assert!(!engine.frozen_at_entry());
+
+    // A manual freeze is not an entry freeze
+    engine.freeze_target();
+    assert!(!engine.frozen_at_entry());
+    engine.unfreeze_target();
+    assert!(!engine.target_frozen());
+}
+
+#[test]
+fn test_view_state_flag_helpers() {
+    let state = |flags| ViewState {
+        generation: 0,
+        timestamp_ns: 0,
+        flags,
+        progress: 0.0,
+    };
+
+    let idle = state(ViewState::IDLE);
+    assert!(!idle.is_pending() && !idle.is_complete() && !idle.is_ready() && !idle.is_error());
+
+    assert!(state(ViewState::PENDING).is_pending());
+    assert!(state(ViewState::COMPLETE).is_complete());
+    assert!(state(ViewState::READY).is_ready());
+    assert!(!state(ViewState::READY).is_error());
+    assert!(state(ViewState::ERROR).is_error());
+    assert!(!state(ViewState::ERROR).is_ready());
+}
+
+#[test]
+fn test_target_threads_empty_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert!(engine.target_threads().is_empty());
+}
+
+#[test]
+fn test_event_graph_index_lookups() {
+    let events = [
+        synthetic(1, 0, Category::Process, Status::Success),
+        synthetic(2, 1, Category::FileSystem, Status::Success),
+        synthetic(3, 1, Category::Network, Status::Success),
+        synthetic(4, 2, Category::Registry, Status::Success),
+    ];
+    let index = crate::index::EventGraphIndex::build(&events);
+
+    assert_eq!(index.len(), 4);
+    assert_eq!(index.by_id(3), Some(2));
+    assert_eq!(index.by_id(99), None);
+    assert_eq!(index.children(1), &[1, 2]);
+    assert_eq!(index.children(4), &[] as &[usize]);
+}
+
+#[test]
+fn test_event_graph_index_extend_and_remove_oldest() {
+    let events = [
+        synthetic(1, 0, Category::Process, Status::Success),
+        synthetic(2, 1, Category::FileSystem, Status::Success),
+        synthetic(3, 1, Category::Network, Status::Success),
+        synthetic(4, 2, Category::Registry, Status::Success),
+    ];
+    let mut index = crate::index::EventGraphIndex::build(&events[..2]);
+    index.extend(&events[2..], 2);
+    index.remove_oldest(&events[..2]);
+
+    // Survivors keep the indices they were given
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.by_id(1), None);
+    assert_eq!(index.by_id(4), Some(3));
+    assert_eq!(index.children(1), &[2]);
+    assert_eq!(index.children(2), &[3]);
+}
+
+#[test]
+fn test_engine_index_invalidates_on_new_events() {
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.index().is_empty());
+
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    {
+        let index = engine.index();
+        assert_eq!(index.len(), 1);
+        assert!(index.children(root).is_empty());
+    }
+
+    let child = engine
+        .inject_event(Category::FileSystem, 0, Status::Success, root)
+        .unwrap();
+    let index = engine.index();
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.children(root), &[index.by_id(child).unwrap()]);
+
+    // A held index survives the rebuild triggered by new events
+    engine.inject_event(Category::FileSystem, 0, Status::Success, root);
+    assert_eq!(engine.top_parents(1), vec![(root, 2)]);
+    assert_eq!(engine.index().len(), 3);
+    assert_eq!(index.len(), 2);
+}
+
+#[test]
+fn test_average_latency_by_category() {
+    use std::time::Duration;
+
+    let ms = Duration::from_millis;
+    let averages = crate::analysis::average_latency_by_category([
+        (Category::Network, ms(10)),
+        (Category::Network, ms(30)),
+        (Category::FileSystem, ms(4)),
+    ]);
+
+    assert_eq!(averages.len(), 2);
+    assert_eq!(averages[&Category::Network], ms(20));
+    assert_eq!(averages[&Category::FileSystem], ms(4));
+    assert!(!averages.contains_key(&Category::Process));
+}
+
+#[test]
+fn test_event_latency_after_resolution() {
+    let mut engine = Engine::new(16, 1);
+    let pending = engine
+        .inject_event(Category::Network, 0, Status::Pending, 0)
+        .unwrap();
+    let done = engine
+        .inject_event(Category::Network, 0, Status::Success, 0)
+        .unwrap();
+    assert_eq!(engine.event_latency(pending), None);
+
+    assert!(engine.resolve_event(pending, Status::Success));
+    assert!(!engine.resolve_event(done, Status::Denied));
+
+    let latency = engine.event_latency(pending).unwrap();
+    assert_eq!(engine.event_latency(done), None);
+    assert_eq!(
+        engine.average_latency_by_category()[&Category::Network],
+        latency
+    );
+    assert_eq!(engine.get_event(0).unwrap().status, Status::Success);
+}
+
+#[test]
+fn test_write_jsonl_one_line_per_event() {
+    let events = [
+        synthetic(1, 0, Category::Process, Status::Success),
+        synthetic(2, 1, Category::Dns, Status::Denied),
+    ];
+
+    let mut out = Vec::new();
+    crate::export::write_jsonl(&events, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[1],
+        r#"{"id":2,"parent_id":1,"timestamp":2000,"category":"Dns","status":"Denied","operation":0}"#
+    );
+}
+
+#[test]
+fn test_wait_for_events_times_out() {
+    use std::time::{Duration, Instant};
+
+    let mut engine = Engine::new(16, 1);
+    let start = Instant::now();
+    assert!(!engine.wait_for_events(1, Duration::from_millis(20)));
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    assert!(engine.wait_for_events(1, Duration::ZERO));
+}
+
+#[test]
+fn test_event_summary_format() {
+    let event = Event {
+        id: 42,
+        parent_id: 7,
+        timestamp: 123_456_789,
+        category: Category::Network,
+        status: Status::Suspicious,
+        operation: 0,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    };
+    assert_eq!(
+        event.summary(),
+        "#42 ◂#7 Network/Connect [Suspicious] @123456789"
+    );
+
+    let root = Event {
+        parent_id: 0,
+        operation: 200,
+        ..event
+    };
+    assert_eq!(
+        root.summary(),
+        "#42 Network/Unknown(200) [Suspicious] @123456789"
+    );
+}
+
+#[test]
+fn test_analysis_progress_none_when_not_monitoring() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.analysis_progress(), None);
+}
+
+#[test]
+fn test_analysis_progress_complete_when_target_exited() {
+    let mut engine = Engine::new(16, 1);
+    engine.simulate_session();
+    engine.simulate_target_exit(1234, 0);
+    assert_eq!(engine.analysis_progress(), Some(1.0));
+    engine.stop_monitoring();
+}
+
+#[test]
+fn test_idle_progress_on_captured_timestamps() {
+    use crate::analysis::{IdleTracker, idle_progress};
+
+    const SEC: u64 = 1_000_000_000;
+    let mut engine = Engine::new(16, 1);
+    for _ in 0..5 {
+        engine.inject_event(Category::Process, 0, Status::Success, 0);
+    }
+    let timestamps: Vec<u64> = engine.iter_events().map(|event| event.timestamp).collect();
+    let now = engine.poll().timestamp_ns;
+    let newest = timestamps.iter().copied().max().unwrap();
+    assert!(now >= newest, "engine clock must match event timestamps");
+
+    // The burst is the current second, so nothing has settled yet
+    assert_eq!(idle_progress(&timestamps, now), 0.0);
+    // A quiet second later the run looks finished
+    assert_eq!(idle_progress(&timestamps, newest + 2 * SEC), 1.0);
+
+    // The incremental tracker agrees with the batch estimate
+    let mut tracker = IdleTracker::default();
+    for &ts in &timestamps {
+        tracker.record(ts);
+    }
+    assert_eq!(tracker.progress(now), idle_progress(&timestamps, now));
+    assert_eq!(tracker.progress(newest + 2 * SEC), 1.0);
+}
+
+#[test]
+fn test_idle_progress_rate_decay() {
+    use crate::analysis::idle_progress;
+
+    const SEC: u64 = 1_000_000_000;
+    assert_eq!(idle_progress(&[], 5 * SEC), 0.0);
+
+    // Burst of 10 events in the first second, 2 in the last second
+    let mut timestamps: Vec<u64> = (0..10).map(|i| i * SEC / 10).collect();
+    timestamps.extend([4 * SEC + 100, 4 * SEC + 200]);
+    assert!((idle_progress(&timestamps, 5 * SEC) - 0.8).abs() < 1e-6);
+
+    // Quiet since the burst
+    assert_eq!(idle_progress(&timestamps, 10 * SEC), 1.0);
+
+    // Still at peak rate
+    assert_eq!(idle_progress(&timestamps[..10], SEC - 1), 0.0);
+}
+
+#[test]
+fn test_supported_categories_subset_of_all() {
+    let supported = Engine::supported_categories();
+    assert!(!supported.is_empty());
+    assert!(supported.iter().all(|cat| Category::all().contains(cat)));
+    assert!(supported.contains(&Category::Process));
+}
+
+#[test]
+fn test_has_monitoring_support_per_target() {
+    #[cfg(windows)]
+    assert!(crate::has_monitoring_support());
+    #[cfg(not(windows))]
+    assert!(!crate::has_monitoring_support());
+}
+
+#[test]
+fn test_flush_idle_engine_returns_zero() {
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.flush(), 0);
+}
+
+#[test]
+fn test_coalesced_collapses_adjacent_runs() {
+    use crate::event_iter::Coalesced;
+
+    let write = |id| Event {
+        operation: 3,
+        ..synthetic(id, 1, Category::FileSystem, Status::Success)
+    };
+    let events = [
+        synthetic(1, 0, Category::Process, Status::Success),
+        write(2),
+        write(3),
+        write(4),
+        synthetic(5, 1, Category::Network, Status::Success),
+        // Same as the earlier run but not adjacent to it
+        write(6),
+    ];
+
+    let runs: Vec<(u64, usize)> = Coalesced::new(events.into_iter())
+        .map(|(event, count)| (event.id, count))
+        .collect();
+    assert_eq!(runs, vec![(1, 1), (2, 3), (5, 1), (6, 1)]);
+}
+
+#[test]
+fn test_iter_events_coalesced_engine() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    for _ in 0..5 {
+        engine.inject_event(Category::FileSystem, 2, Status::Success, root);
+    }
+
+    let counts: Vec<usize> = engine.iter_events_coalesced().map(|(_, n)| n).collect();
+    assert_eq!(counts, vec![1, 5]);
+}
+
+#[test]
+fn test_grow_arena_increases_capacity() {
+    let mut engine = Engine::new(16, 1);
+    let id = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let before = engine.arena_capacity();
+
+    engine.grow_arena(8).unwrap();
+    assert!(engine.arena_capacity() >= before + 8 * 1024 * 1024);
+    assert_eq!(engine.get_event(0).unwrap().id, id);
+
+    assert_eq!(
+        engine.grow_arena(0),
+        Err(EngineError::GrowFailed { additional_mb: 0 })
+    );
+}
+
+#[test]
+fn test_event_with_parent() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::Network, 0, Status::Success, root);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 999);
+
+    let (event, parent) = engine.event_with_parent(0).unwrap();
+    assert_eq!(event.id, root);
+    assert!(parent.is_none());
+
+    let (_, parent) = engine.event_with_parent(1).unwrap();
+    assert_eq!(parent.unwrap().category, Category::Process);
+
+    // Unknown parent id
+    let (_, parent) = engine.event_with_parent(2).unwrap();
+    assert!(parent.is_none());
+
+    assert!(engine.event_with_parent(3).is_none());
+}
+
+#[test]
+fn test_event_iter_double_ended() {
+    let mut engine = Engine::new(16, 1);
+    for _ in 0..5 {
+        engine.inject_event(Category::Process, 0, Status::Success, 0);
+    }
+
+    let forward: Vec<u64> = engine.iter_events().map(|e| e.id).collect();
+    let mut reverse: Vec<u64> = engine.iter_events().rev().map(|e| e.id).collect();
+    assert!(reverse.windows(2).all(|w| w[0] > w[1]));
+    reverse.reverse();
+    assert_eq!(forward, reverse);
+
+    // Both ends meet without overlap
+    let mut iter = engine.iter_events();
+    assert_eq!(iter.next().unwrap().id, forward[0]);
+    assert_eq!(iter.next_back().unwrap().id, forward[4]);
+    assert_eq!(iter.len(), 3);
+    let middle: Vec<u64> = iter.by_ref().map(|e| e.id).collect();
+    assert_eq!(middle, forward[1..4]);
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn test_state_flags_names() {
+    let state = ViewState {
+        generation: 0,
+        timestamp_ns: 0,
+        flags: ViewState::PENDING | ViewState::READY | (1 << 40),
+        progress: 0.0,
+    };
+    let flags = state.state_flags();
+
+    assert!(flags.contains(StateFlags::PENDING | StateFlags::READY));
+    assert!(!flags.contains(StateFlags::ERROR));
+    let names: Vec<&str> = flags.iter_names().map(|(name, _)| name).collect();
+    assert_eq!(names, ["PENDING", "READY"]);
+    assert_eq!(format!("{flags:?}"), "StateFlags(PENDING | READY)");
+}
+
+/// Write a minimal PE header with the given machine type and characteristics.
+fn write_pe(path: &std::path::Path, machine: u16, characteristics: u16) {
+    let mut image = vec![0u8; 0x100];
+    image[..2].copy_from_slice(b"MZ");
+    image[0x3C..0x40].copy_from_slice(&0x80u32.to_le_bytes());
+    image[0x80..0x84].copy_from_slice(b"PE\0\0");
+    image[0x84..0x86].copy_from_slice(&machine.to_le_bytes());
+    image[0x96..0x98].copy_from_slice(&characteristics.to_le_bytes());
+    std::fs::write(path, image).unwrap();
+}
+
+#[test]
+fn test_validate_target_not_found() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(
+        engine.validate_target("definitely/not/here.exe"),
+        Err(MonitorError::ExeNotFound {
+            path: "definitely/not/here.exe".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_validate_target_pe_headers() {
+    let engine = Engine::new(16, 1);
+    let dir = std::env::temp_dir().join(format!("exeray-validate-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let exe = dir.join("ok.exe");
+    write_pe(&exe, 0x8664, 0x0022);
+    assert_eq!(engine.validate_target(exe.to_str().unwrap()), Ok(()));
+
+    let dll = dir.join("lib.dll");
+    write_pe(&dll, 0x8664, 0x2022);
+    assert!(matches!(
+        engine.validate_target(dll.to_str().unwrap()),
+        Err(MonitorError::NotExecutable { .. })
+    ));
+
+    let mips = dir.join("mips.exe");
+    write_pe(&mips, 0x0166, 0x0022);
+    assert!(matches!(
+        engine.validate_target(mips.to_str().unwrap()),
+        Err(MonitorError::UnsupportedArchitecture { .. })
+    ));
+
+    let text = dir.join("notes.txt");
+    std::fs::write(&text, "hello").unwrap();
+    assert!(matches!(
+        engine.validate_target(text.to_str().unwrap()),
+        Err(MonitorError::NotPe { .. })
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_stats_fresh_engine_idle() {
+    let mut engine = Engine::new(16, 1);
+    let stats = engine.stats();
+    assert_eq!(stats.event_count, 0);
+    assert_eq!(stats.events_dropped, 0);
+    assert_eq!(stats.buffers_lost, 0);
+    assert_eq!(stats.busy_threads, 0);
+    assert_eq!(stats.queue_depth, 0);
+    assert_eq!(stats.arena_used_bytes, engine.arena_used());
+
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    assert_eq!(engine.stats().event_count, 1);
+}
+
+#[test]
+fn test_target_output_none_before_monitoring() {
+    let engine = Engine::builder().arena_mb(16).capture_output(true).build();
+    assert!(engine.capture_output());
+    assert!(engine.target_output().is_none());
+}
+
+#[test]
+fn test_category_status_from_u8() {
+    for (i, &cat) in Category::ALL.iter().enumerate() {
+        assert_eq!(Category::try_from(i as u8), Ok(cat));
+        assert_eq!(Category::from_u8_lossy(i as u8), cat);
+    }
+    assert_eq!(
+        Category::try_from(200),
+        Err(ConversionError::UnknownCategory(200))
+    );
+    assert_eq!(Category::from_u8_lossy(200), Category::FileSystem);
+
+    assert_eq!(Status::try_from(4), Ok(Status::Suspicious));
+    assert_eq!(Status::from_u8_lossy(1), Status::Denied);
+    assert_eq!(Status::try_from(5), Err(ConversionError::UnknownStatus(5)));
+    assert_eq!(Status::from_u8_lossy(5), Status::Error);
+}
+
+#[test]
+fn test_events_in_window_inclusive() {
+    let events: Vec<Event> = (1..=5)
+        .map(|id| synthetic(id, 0, Category::Process, Status::Success))
+        .collect();
+    let ids: Vec<u64> = crate::analysis::events_in_window(&events, 2_000, 4_000)
+        .map(|event| event.id)
+        .collect();
+    assert_eq!(ids, vec![2, 3, 4]);
+    assert_eq!(
+        crate::analysis::events_in_window(&events, 6_000, 9_000).count(),
+        0
+    );
+}
+
+#[test]
+fn test_events_carry_target_pid() {
+    let mut engine = Engine::new(16, 1);
+    let a = engine
+        .inject_target_event(100, Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_target_event(200, Category::Network, 0, Status::Success, a);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+
+    let pids: Vec<u32> = engine.iter_events().map(|event| event.target_pid).collect();
+    assert_eq!(pids, vec![100, 200, 0]);
+}
+
+#[test]
+fn test_start_monitoring_multi_validates_paths() {
+    let mut engine = Engine::new(16, 1);
+    let missing = "definitely/not/here.exe";
+    assert_eq!(
+        engine.start_monitoring_multi(&[missing]),
+        Err(MonitorError::ExeNotFound {
+            path: missing.to_string()
+        })
+    );
+    assert_eq!(
+        engine.start_monitoring_multi(&[]),
+        Err(MonitorError::StartFailed)
+    );
+}
+
+#[test]
+fn test_clear_events_before_cutoff() {
+    let mut engine = Engine::new(16, 1);
+    let ids: Vec<u64> = (0..5)
+        .map(|_| {
+            // Keep timestamps distinct
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            engine
+                .inject_event(Category::Process, 0, Status::Success, 0)
+                .unwrap()
+        })
+        .collect();
+    let cutoff = engine.get_event(3).unwrap().timestamp;
+
+    assert_eq!(engine.clear_events_before(cutoff), 3);
+    assert_eq!(engine.event_count(), 2);
+    assert_eq!(engine.get_event(0).unwrap().id, ids[3]);
+    assert!(engine.event_by_id(ids[0]).is_none());
+
+    // Freed slots take new events; nothing is older than 0
+    assert!(
+        engine
+            .inject_event(Category::Network, 0, Status::Success, 0)
+            .is_some()
+    );
+    assert_eq!(engine.clear_events_before(0), 0);
+    assert_eq!(engine.event_count(), 3);
+}
+
+#[test]
+fn test_max_events_cap() {
+    let mut engine = Engine::builder().arena_mb(16).max_events(2).build();
+    assert_eq!(engine.max_events(), Some(2));
+    assert_eq!(Engine::new(16, 1).max_events(), None);
+
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    assert!(!engine.max_events_reached());
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    assert!(engine.max_events_reached());
+    assert!(engine.poll().is_error());
+    assert!(
+        engine
+            .inject_event(Category::Process, 0, Status::Success, 0)
+            .is_none()
+    );
+    assert_eq!(engine.event_count(), 2);
+
+    // A new task keeps the error raised by the cap
+    engine.submit();
+    assert!(engine.poll().is_error());
+
+    // Freeing space re-arms the cap
+    assert_eq!(engine.clear_events_before(u64::MAX), 2);
+    assert!(!engine.max_events_reached());
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    assert!(engine.max_events_reached());
+
+    assert_eq!(
+        Engine::builder()
+            .ring_buffer(true)
+            .max_events(10)
+            .try_build()
+            .err(),
+        Some(EngineError::MaxEventsWithRingBuffer)
+    );
+}
+
+#[test]
+fn test_events_per_bucket() {
+    use crate::analysis::{MAX_BUCKETS, events_per_bucket};
+
+    assert_eq!(events_per_bucket(&[], 10), Some(Vec::new()));
+    assert_eq!(Engine::new(16, 1).events_per_bucket(10), Some(Vec::new()));
+
+    // Three buckets of width 10 starting at 100, middle one empty
+    let timestamps = [100, 105, 109, 121, 129, 125];
+    assert_eq!(
+        events_per_bucket(&timestamps, 10),
+        Some(vec![(100, 3), (110, 0), (120, 3)])
+    );
+
+    // Zero width and spans needing too many buckets are rejected
+    assert_eq!(events_per_bucket(&[5, 5, 6], 0), None);
+    assert_eq!(events_per_bucket(&[0, u64::MAX], 1), None);
+    let widest = MAX_BUCKETS as u64 - 1;
+    assert_eq!(
+        events_per_bucket(&[0, widest], 1).map(|b| b.len()),
+        Some(MAX_BUCKETS)
+    );
+    assert_eq!(events_per_bucket(&[0, widest + 1], 1), None);
+}
+
+#[test]
+fn test_operation_display_matches_name() {
+    use crate::operation::*;
+
+    fn check<T: std::fmt::Display + Copy>(all: &[T], name: fn(T) -> &'static str) {
+        for &op in all {
+            assert_eq!(op.to_string(), name(op));
+            assert!(!name(op).is_empty());
+        }
+    }
+    check(FileOp::ALL, FileOp::name);
+    check(RegOp::ALL, RegOp::name);
+    check(NetOp::ALL, NetOp::name);
+    check(ProcessOp::ALL, ProcessOp::name);
+    check(SchedulerOp::ALL, SchedulerOp::name);
+    check(InputOp::ALL, InputOp::name);
+    check(ImageOp::ALL, ImageOp::name);
+    check(ThreadOp::ALL, ThreadOp::name);
+    check(MemoryOp::ALL, MemoryOp::name);
+    check(ScriptOp::ALL, ScriptOp::name);
+    check(AmsiOp::ALL, AmsiOp::name);
+    check(DnsOp::ALL, DnsOp::name);
+    check(SecurityOp::ALL, SecurityOp::name);
+    check(ServiceOp::ALL, ServiceOp::name);
+    check(WmiOp::ALL, WmiOp::name);
+    check(ClrOp::ALL, ClrOp::name);
+
+    for &cat in Category::ALL.iter() {
+        for code in 0..=u8::MAX {
+            let op = TypedOp::decode(cat, code);
+            assert_eq!(op.to_string(), op.name());
+        }
+    }
+}
+
+#[test]
+fn test_on_exit_fires_once() {
+    use crate::exit_info::ExitInfo;
+    use std::sync::mpsc;
+
+    let mut engine = Engine::new(16, 1);
+    let (tx, rx) = mpsc::channel();
+    engine.set_on_exit(Box::new(move |info| tx.send(info).unwrap()));
+
+    engine.poll();
+    assert!(rx.try_recv().is_err());
+
+    engine.simulate_target_exit(1234, 3);
+    assert!(engine.target_exited());
+    engine.poll();
+    let exit = rx.try_recv().unwrap();
+    assert_eq!(
+        exit,
+        ExitInfo {
+            pid: 1234,
+            exit_code: 3,
+            killed: false,
+        }
+    );
+    assert!(exit.is_error());
+
+    // At most once per session
+    engine.poll();
+    assert!(rx.try_recv().is_err());
+
+    // A new session re-arms the callback, and a kill is not an error
+    engine.simulate_session();
+    engine.poll();
+    assert!(rx.try_recv().is_err());
+    engine.kill_target();
+    engine.simulate_target_exit(1234, 1);
+    engine.poll();
+    let exit = rx.try_recv().unwrap();
+    assert!(exit.killed);
+    assert!(!exit.is_error());
+    engine.stop_monitoring();
+}
+
+#[test]
+fn test_risk_score_known_events() {
+    use crate::operation::{AmsiOp, NetOp};
+    use crate::risk::{RiskWeights, risk_score};
+
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.risk_score(), 0);
+
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    engine.inject_event(Category::FileSystem, 0, Status::Suspicious, 0); // 10
+    engine.inject_event(Category::Registry, 0, Status::Denied, 0); // 5
+    engine.inject_event(Category::Amsi, AmsiOp::Scan as u8, Status::Success, 0); // 8
+    engine.inject_event(
+        Category::Network,
+        NetOp::Connect as u8,
+        Status::Suspicious,
+        0,
+    ); // 3 + 10
+    assert_eq!(engine.risk_score(), 36);
+
+    let weights = RiskWeights {
+        suspicious: 1,
+        denied: 0,
+        amsi_scan: 0,
+        network_connect: 100,
+    };
+    assert_eq!(engine.risk_score_with(&weights), 102);
+
+    let events: Vec<Event> = engine.iter_events().collect();
+    let saturating = RiskWeights {
+        suspicious: u32::MAX,
+        ..RiskWeights::default()
+    };
+    assert_eq!(risk_score(&events, &saturating), u32::MAX);
+}
+
+#[test]
+fn test_target_command_line_none_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.target_command_line(), None);
+}
+
+#[test]
+fn test_target_cwd_none_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.target_cwd(), None);
+}
+
+#[test]
+fn test_deviation_from_baseline() {
+    use crate::operation::{FileOp, NetOp};
+
+    let mut clean = Engine::new(16, 1);
+    clean.inject_event(Category::Process, 0, Status::Success, 0);
+    clean.inject_event(Category::FileSystem, FileOp::Read as u8, Status::Success, 1);
+    let baseline = clean.snapshot();
+    assert_eq!(baseline.len(), 2);
+
+    let mut current = Engine::new(16, 1);
+    assert!(current.deviation_from(&baseline).is_empty());
+
+    current.inject_event(Category::Process, 0, Status::Success, 0);
+    current.inject_event(Category::FileSystem, FileOp::Read as u8, Status::Denied, 1);
+    current.inject_event(
+        Category::FileSystem,
+        FileOp::Write as u8,
+        Status::Success,
+        1,
+    );
+    current.inject_event(Category::Network, NetOp::Connect as u8, Status::Success, 1);
+    current.inject_event(Category::Network, NetOp::Connect as u8, Status::Success, 1);
+
+    // Status and parent do not matter, only category and operation
+    let new: Vec<(Category, u8)> = current
+        .deviation_from(&baseline)
+        .iter()
+        .map(|e| (e.category, e.operation))
+        .collect();
+    assert_eq!(
+        new,
+        vec![
+            (Category::FileSystem, FileOp::Write as u8),
+            (Category::Network, NetOp::Connect as u8),
+            (Category::Network, NetOp::Connect as u8),
+        ]
+    );
+
+    // An empty baseline flags everything
+    assert_eq!(current.deviation_from(&EventSnapshot::default()).len(), 5);
+}
+
+#[test]
+fn test_enabled_providers_empty_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert!(engine.provider_enabled("Process"));
+    assert!(engine.enabled_providers().is_empty());
+}
+
+#[test]
+fn test_file_writes_pairs_paths() {
+    use crate::operation::FileOp;
+
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_file_event(FileOp::Read, Status::Success, root, r"C:\in.txt");
+    engine.inject_file_event(FileOp::Create, Status::Success, root, r"C:\out.txt");
+    engine.inject_file_event(FileOp::Write, Status::Success, root, r"C:\out.txt");
+    engine.inject_file_event(FileOp::Delete, Status::Success, root, r"C:\tmp.txt");
+    engine.inject_file_event(FileOp::Rename, Status::Denied, root, r"C:\new.txt");
+    // A write without a path is skipped
+    engine.inject_event(
+        Category::FileSystem,
+        FileOp::Write as u8,
+        Status::Success,
+        root,
+    );
+
+    let writes: Vec<(FileOp, String)> = engine
+        .file_writes()
+        .map(|(event, path)| match event.typed_operation() {
+            TypedOp::File(op) => (op, path),
+            other => panic!("unexpected {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        writes,
+        vec![
+            (FileOp::Create, r"C:\out.txt".to_string()),
+            (FileOp::Write, r"C:\out.txt".to_string()),
+            (FileOp::Rename, r"C:\new.txt".to_string()),
+        ]
+    );
+
+    assert_eq!(engine.event_file_path(0), None);
+    assert_eq!(engine.event_file_path(1).as_deref(), Some(r"C:\in.txt"));
+}
+
+#[test]
+fn test_network_connections_aggregates_endpoints() {
+    use crate::endpoint::Endpoint;
+    use crate::operation::NetOp;
+    use std::net::{Ipv4Addr, SocketAddrV4, SocketAddrV6};
+
+    let c2 = SocketAddrV4::new(Ipv4Addr::new(203, 0, 113, 7), 443);
+    let dns = SocketAddrV4::new(Ipv4Addr::new(8, 8, 8, 8), 53);
+    let web = SocketAddrV4::new(Ipv4Addr::new(198, 51, 100, 1), 80);
+
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.network_connections().is_empty());
+
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, web);
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, c2);
+    engine.inject_network_event(NetOp::Send, Status::Success, 0, c2);
+    engine.inject_network_event(NetOp::Connect, Status::Suspicious, 0, c2);
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, dns);
+    engine.inject_network_event(NetOp::Connect, Status::Denied, 0, c2);
+    // Same address on another port is a separate endpoint
+    engine.inject_network_event(
+        NetOp::Connect,
+        Status::Success,
+        0,
+        SocketAddrV4::new(*c2.ip(), 8443),
+    );
+    let v6: SocketAddrV6 = "[2001:db8::7]:443".parse().unwrap();
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, v6);
+
+    assert_eq!(engine.event_remote_endpoint(0), Some(web));
+    let endpoint = |addr: &str, port, count| Endpoint {
+        addr: addr.to_string(),
+        port,
+        count,
+    };
+    assert_eq!(
+        engine.network_connections(),
+        vec![
+            endpoint("203.0.113.7", 443, 3),
+            endpoint("8.8.8.8", 53, 1),
+            endpoint("198.51.100.1", 80, 1),
+            endpoint("203.0.113.7", 8443, 1),
+            endpoint("2001:db8::7", 443, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_checkpoint_round_trip() {
+    use crate::checkpoint::restore_view;
+    use crate::snapshot::SnapshotMetadata;
+
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_target_event(42, Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::Network, 0, Status::Suspicious, root);
+    engine.inject_event(Category::Registry, 2, Status::Denied, root);
+
+    let path = std::env::temp_dir().join(format!("exeray_checkpoint_{}.toml", std::process::id()));
+    engine.checkpoint(&path).unwrap();
+    let restored = restore_view(&path);
+    std::fs::remove_file(&path).unwrap();
+    let restored = restored.unwrap();
+
+    let original: Vec<Event> = engine.iter_events().collect();
+    assert_eq!(restored.len(), original.len());
+    for (a, b) in original.iter().zip(restored.events()) {
+        assert_eq!(
+            (a.id, a.parent_id, a.timestamp, a.category, a.status),
+            (b.id, b.parent_id, b.timestamp, b.category, b.status)
+        );
+        assert_eq!((a.operation, a.target_pid), (b.operation, b.target_pid));
+    }
+
+    // Nothing was monitored, so only the generation is recorded
+    let metadata = restored.metadata();
+    assert_eq!(metadata.exe_path, None);
+    assert_eq!(metadata.started_at, None);
+    assert_eq!(metadata.generation, engine.snapshot().metadata().generation);
+
+    // Absolute times come from the capturing engine's clock
+    assert_eq!(metadata.timestamp_epoch, Some(engine.timestamp_epoch()));
+    let first = original[0].timestamp;
+    assert_eq!(
+        metadata.to_unix_nanos(first),
+        Some(engine.to_unix_nanos(first))
+    );
+    assert_eq!(SnapshotMetadata::default().to_unix_nanos(first), None);
+}
+
+#[test]
+fn test_restore_view_rejects_malformed_file() {
+    use crate::checkpoint::restore_view;
+
+    let path =
+        std::env::temp_dir().join(format!("exeray_bad_checkpoint_{}.toml", std::process::id()));
+    std::fs::write(&path, "generation = 0\n[[events]]\nid = 1\n").unwrap();
+    let err = restore_view(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // A missing file keeps its I/O error kind
+    assert_eq!(
+        restore_view(&path).unwrap_err().kind(),
+        std::io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+fn test_kill_and_collect() {
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.kill_and_collect().is_empty());
+
+    // Idle engines collect nothing, even with events in the graph
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::FileSystem, 3, Status::Success, root);
+    assert!(engine.kill_and_collect().is_empty());
+    assert_eq!(engine.event_count(), 2);
+    assert!(!engine.target_running());
+
+    // Repeated calls stay safe
+    assert!(engine.kill_and_collect().is_empty());
+}
+
+#[test]
+fn test_descendants_of_multi_level_tree() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let child = engine
+        .inject_event(Category::Process, 0, Status::Success, root)
+        .unwrap();
+    let sibling = engine
+        .inject_event(Category::FileSystem, 2, Status::Success, root)
+        .unwrap();
+    let grandchild = engine
+        .inject_event(Category::Network, 0, Status::Success, child)
+        .unwrap();
+    let great = engine
+        .inject_event(Category::Registry, 2, Status::Success, grandchild)
+        .unwrap();
+    let unrelated = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let late = engine
+        .inject_event(Category::FileSystem, 3, Status::Success, child)
+        .unwrap();
+
+    let ids = |ancestor| -> Vec<u64> {
+        engine
+            .descendants_of(ancestor)
+            .iter()
+            .map(|e| e.id)
+            .collect()
+    };
+    assert_eq!(ids(root), vec![child, sibling, grandchild, great, late]);
+    assert_eq!(ids(child), vec![grandchild, great, late]);
+    assert!(ids(great).is_empty());
+    assert!(ids(unrelated).is_empty());
+    assert!(ids(999).is_empty());
+}
+
+#[test]
+fn test_descendants_of_guards_cycles() {
+    let mut engine = Engine::new(16, 1);
+    // Event 1 claims event 2 as its parent and vice versa
+    let first = engine
+        .inject_event(Category::Process, 0, Status::Success, 2)
+        .unwrap();
+    let second = engine
+        .inject_event(Category::Process, 0, Status::Success, first)
+        .unwrap();
+    assert_eq!(second, 2);
+
+    let ids: Vec<u64> = engine.descendants_of(first).iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![second]);
+}
+
+#[test]
+fn test_prefetched_iteration_matches_unbuffered() {
+    let mut engine = Engine::new(16, 1);
+    for i in 0..50u64 {
+        let cat = Category::from_u8_lossy((i % 16) as u8);
+        let status = Status::from_u8_lossy((i % 5) as u8);
+        engine.inject_target_event(i as u32, cat, (i % 4) as u8, status, i / 2);
+    }
+
+    let key = |e: &Event| {
+        (
+            e.id,
+            e.parent_id,
+            e.timestamp,
+            e.category,
+            e.status,
+            e.operation,
+            e.target_pid,
+        )
+    };
+    let unbuffered: Vec<_> = (0..engine.event_count())
+        .map(|i| key(&engine.get_event(i).unwrap()))
+        .collect();
+    assert_eq!(unbuffered.len(), 50);
+
+    for block in [1, 3, 7, 50, 256] {
+        let forward: Vec<_> = engine
+            .iter_events()
+            .prefetch(block)
+            .map(|e| key(&e))
+            .collect();
+        assert_eq!(forward, unbuffered, "block {block}");
+
+        let mut backward: Vec<_> = engine
+            .iter_events()
+            .prefetch(block)
+            .rev()
+            .map(|e| key(&e))
+            .collect();
+        backward.reverse();
+        assert_eq!(backward, unbuffered, "block {block}");
+
+        // Alternating ends meet in the middle without overlap
+        let mut iter = engine.iter_events().prefetch(block);
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        while let Some(e) = iter.next() {
+            front.push(key(&e));
+            if let Some(e) = iter.next_back() {
+                back.push(key(&e));
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, unbuffered, "block {block}");
+    }
+}
+
+#[test]
+fn test_event_denial_reason() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Suspicious, 0);
+    engine.inject_event(Category::Registry, 2, Status::Denied, 0);
+    engine.inject_event(Category::Security, 0, Status::Denied, 0);
+
+    assert_eq!(engine.event_denial_reason(0), None);
+    assert_eq!(engine.event_denial_reason(1), None);
+    assert_eq!(
+        engine.event_denial_reason(2).as_deref(),
+        Some("access denied")
+    );
+    assert_eq!(
+        engine.event_denial_reason(3).as_deref(),
+        Some("logon failed")
+    );
+    assert_eq!(engine.event_denial_reason(99), None);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_monitoring_not_supported_off_windows() {
+    let mut engine = Engine::new(16, 1);
+    let exe = std::env::current_exe().unwrap();
+    let exe = exe.to_str().unwrap();
+    assert_eq!(
+        engine.start_monitoring(exe),
+        Err(MonitorError::NotSupported)
+    );
+    assert_eq!(engine.target_pid(), 0);
+}
+
+#[test]
+fn test_expected_events_hint() {
+    let plain = Engine::builder().arena_mb(16).build();
+    assert_eq!(plain.expected_events(), None);
+    let default_slots = plain.event_capacity();
+
+    // A small hint is recorded but keeps the MB-derived arena and default slots
+    let small = Engine::builder().arena_mb(16).expected_events(1000).build();
+    assert_eq!(small.expected_events(), Some(1000));
+    assert!(small.arena_capacity() >= 16 * 1024 * 1024);
+    assert_eq!(small.event_capacity(), default_slots);
+
+    // A large hint outgrows both
+    let large = Engine::builder()
+        .arena_mb(16)
+        .expected_events(500_000)
+        .build();
+    assert_eq!(large.expected_events(), Some(500_000));
+    assert!(large.event_capacity() >= 500_000);
+    assert!(large.arena_capacity() > 16 * 1024 * 1024);
+}
+
+#[test]
+fn test_content_key_ignores_id() {
+    let event = |id, status| Event {
+        id,
+        parent_id: 0,
+        timestamp: id * 1_000,
+        category: Category::Network,
+        status,
+        operation: 1,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    };
+    let first = event(1, Status::Success);
+    let second = event(7, Status::Success);
+    let denied = event(8, Status::Denied);
+
+    assert_eq!(first.content_key(), second.content_key());
+    assert_eq!(
+        first.content_key(),
+        (Category::Network.repr, Status::Success.repr, 1)
+    );
+    assert_ne!(first.content_key(), denied.content_key());
+
+    let ids: Vec<u64> = crate::analysis::dedup_by_content(&[first, second, denied])
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec![1, 8]);
+}
+
+#[test]
+fn test_tail() {
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.tail(5).is_empty());
+    for op in 0..4 {
+        engine.inject_event(Category::FileSystem, op, Status::Success, 0);
+    }
+
+    let ops: Vec<u8> = engine.tail(2).iter().map(|e| e.operation).collect();
+    assert_eq!(ops, vec![2, 3]);
+    let ids: Vec<u64> = engine.tail(100).iter().map(|e| e.id).collect();
+    let all: Vec<u64> = engine.iter_events().map(|e| e.id).collect();
+    assert_eq!(ids, all);
+    assert_eq!(ids.len(), 4);
+    assert!(engine.tail(0).is_empty());
+}
+
+#[test]
+fn test_custom_classifier() {
+    use crate::classify::{Classifier, DefaultClassifier, Severity};
+    use crate::operation::NetOp;
+    use crate::risk::{RiskWeights, risk_score, risk_score_classified};
+
+    struct NetworkIsHigh;
+    impl Classifier for NetworkIsHigh {
+        fn classify(&self, event: &Event) -> Severity {
+            if event.category == Category::Network {
+                Severity::High
+            } else {
+                Severity::Info
+            }
+        }
+    }
+
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Network, NetOp::Send as u8, Status::Success, 0);
+    engine.inject_event(Category::FileSystem, 0, Status::Suspicious, 0);
+    let events: Vec<Event> = engine.iter_events().collect();
+
+    let severities: Vec<Severity> = events.iter().map(|e| NetworkIsHigh.classify(e)).collect();
+    assert_eq!(severities, vec![Severity::High, Severity::Info]);
+    let defaults: Vec<Severity> = events
+        .iter()
+        .map(|e| DefaultClassifier.classify(e))
+        .collect();
+    assert_eq!(defaults, vec![Severity::Info, Severity::High]);
+
+    let weights = RiskWeights::default();
+    assert_eq!(
+        risk_score_classified(&events, &weights, &DefaultClassifier),
+        risk_score(&events, &weights)
+    );
+    assert_eq!(
+        risk_score_classified(&events, &weights, &NetworkIsHigh),
+        weights.suspicious
+    );
+}
+
+#[test]
+fn test_index_of() {
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.index_of(1), None);
+    let first = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let second = engine
+        .inject_event(Category::FileSystem, 0, Status::Success, first)
+        .unwrap();
+
+    assert_eq!(engine.index_of(first), Some(0));
+    assert_eq!(engine.index_of(second), Some(1));
+    assert_eq!(engine.index_of(second + 100), None);
+    assert_eq!(engine.index_of(0), None);
+}
+
+#[test]
+fn test_max_duration_recorded() {
+    use std::time::Duration;
+
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.max_duration(), None);
+    assert!(!engine.timed_out());
+
+    let engine = Engine::builder()
+        .arena_mb(16)
+        .max_duration(Duration::from_secs(30))
+        .build();
+    assert_eq!(engine.max_duration(), Some(Duration::from_secs(30)));
+    assert!(!engine.timed_out());
+}
+
+#[test]
+fn test_max_duration_stops_session() {
+    use std::time::{Duration, Instant};
+
+    let mut engine = Engine::builder()
+        .arena_mb(16)
+        .max_duration(Duration::from_millis(50))
+        .build();
+    engine.simulate_session();
+    assert!(engine.is_monitoring());
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while !engine.timed_out() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(engine.timed_out());
+    assert!(!engine.is_monitoring());
+    assert!(engine.enabled_providers().is_empty());
+
+    // Tearing down after the deadline is still safe, and a new session
+    // re-arms the watchdog
+    engine.stop_monitoring();
+    engine.simulate_session();
+    assert!(engine.is_monitoring());
+    assert!(!engine.timed_out());
+    engine.stop_monitoring();
+    assert!(!engine.is_monitoring());
+}
+
+#[test]
+fn test_target_signature_none_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.target_signature(), None);
+}
+
+#[test]
+fn test_timestamp_epoch_conversion() {
+    use crate::timestamp::TimestampEpoch;
+
+    assert_eq!(TimestampEpoch::UnixNanos.to_unix_nanos(1_234, 99), 1_234);
+    assert_eq!(
+        TimestampEpoch::QpcBootRelative.to_unix_nanos(1_000, -400),
+        600
+    );
+    // 1970-01-01 as a FILETIME
+    assert_eq!(
+        TimestampEpoch::EtwFileTime.to_unix_nanos(116_444_736_000_000_000, 0),
+        0
+    );
+
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.timestamp_epoch(), TimestampEpoch::QpcBootRelative);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let unix = engine.to_unix_nanos(engine.get_event(0).unwrap().timestamp);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    assert!(now.abs_diff(unix) < 60_000_000_000);
+}
+
+#[test]
+fn test_min_status_threshold() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.min_status(), Status::Success);
+
+    let mut engine = Engine::builder()
+        .arena_mb(16)
+        .min_status(Status::Error)
+        .build();
+    assert_eq!(engine.min_status(), Status::Error);
+
+    for status in [
+        Status::Success,
+        Status::Pending,
+        Status::Error,
+        Status::Denied,
+        Status::Suspicious,
+    ] {
+        engine.inject_event(Category::FileSystem, 0, status, 0);
+    }
+    let kept: Vec<Status> = engine.iter_events().map(|e| e.status).collect();
+    assert_eq!(
+        kept,
+        vec![Status::Error, Status::Denied, Status::Suspicious]
+    );
+    assert_eq!(engine.events_filtered(), 2);
+    assert_eq!(engine.stats().events_dropped, 0);
+}
+
+#[test]
+fn test_with_events() {
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.with_events(|events| events.len()), 0);
+
+    for _ in 0..3 {
+        engine.inject_event(Category::Registry, 0, Status::Success, 0);
+    }
+    engine.inject_event(Category::Network, 0, Status::Suspicious, 0);
+
+    assert_eq!(engine.with_events(|events| events.len()), 4);
+    let suspicious = engine.with_events(|events| {
+        events
+            .iter()
+            .filter(|e| e.status == Status::Suspicious)
+            .count()
+    });
+    assert_eq!(suspicious, 1);
+
+    // Nested calls see the same events
+    let nested = engine.with_events(|outer| engine.with_events(|inner| outer.len() == inner.len()));
+    assert!(nested);
+}
+
+#[test]
+fn test_zero_threads_autodetects() {
+    let expected = std::thread::available_parallelism().map_or(1, usize::from);
+    assert_eq!(Engine::new(16, 0).threads(), expected);
+    assert_eq!(Engine::builder().arena_mb(16).build().threads(), expected);
+    assert_eq!(Engine::new(16, 3).threads(), 3);
+}
+
+#[test]
+fn test_export_subtree_jsonl() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let other = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let child = engine
+        .inject_event(Category::FileSystem, 0, Status::Success, root)
+        .unwrap();
+    engine.inject_event(Category::Network, 0, Status::Success, other);
+    let grandchild = engine
+        .inject_event(Category::Registry, 0, Status::Denied, child)
+        .unwrap();
+
+    let ids = |root_id| {
+        let mut out = Vec::new();
+        engine.export_subtree_jsonl(root_id, &mut out).unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<serde_json::Value>(line).unwrap()["id"]
+                    .as_u64()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(root), vec![root, child, grandchild]);
+    assert_eq!(ids(grandchild), vec![grandchild]);
+    assert!(ids(999).is_empty());
+}
+
+#[test]
+fn test_target_cpu_times_none_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.target_cpu_times(), None);
+}
+
+#[test]
+fn test_process_tree_from_create_events() {
+    use crate::operation::ProcessOp;
+
+    let mut engine = Engine::new(16, 1);
+    let create = |engine: &mut Engine, pid, parent_pid, image| {
+        engine.inject_process_event(
+            ProcessOp::Create,
+            Status::Success,
+            0,
+            pid,
+            parent_pid,
+            image,
+        )
+    };
+    create(&mut engine, 100, 4, r"C:\target\app.exe");
+    create(&mut engine, 200, 100, r"C:\Windows\System32\cmd.exe");
+    create(&mut engine, 300, 200, r"C:\Windows\System32\whoami.exe");
+    create(&mut engine, 400, 100, r"C:\Windows\System32\conhost.exe");
+    create(&mut engine, 500, 999, "");
+    // Exits do not add nodes
+    engine.inject_process_event(ProcessOp::Terminate, Status::Success, 0, 600, 100, "");
+
+    let tree = engine.process_tree();
+    assert_eq!(tree.len(), 5);
+    // Parents 4 and 999 were never captured, so their children are roots
+    assert_eq!(tree.roots(), &[100, 500]);
+    assert_eq!(tree.children(100), &[200, 400]);
+    assert_eq!(tree.children(200), &[300]);
+    assert!(tree.children(300).is_empty());
+    assert_eq!(tree.get(300).unwrap().name(), "whoami.exe");
+    assert_eq!(tree.get(500).unwrap().name(), "");
+    assert!(tree.get(600).is_none());
+}
+
+#[test]
+fn test_events_added_since_current_generation_is_empty() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+
+    let current = engine.poll().generation;
+    assert!(engine.events_added_since(current).is_empty());
+    assert!(engine.events_added_since(current + 5).is_empty());
+}
+
+#[test]
+fn test_events_added_since_returns_new_events() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let before = engine.poll().generation;
+
+    let ids: Vec<u64> = [Category::Network, Category::Registry]
+        .into_iter()
+        .filter_map(|category| engine.inject_event(category, 0, Status::Success, 0))
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(engine.poll().generation > before);
+
+    let added: Vec<u64> = engine
+        .events_added_since(before)
+        .iter()
+        .map(|event| event.id)
+        .collect();
+    assert_eq!(added, ids);
+    assert_eq!(engine.events_added_since(0).len(), 3);
+}
+
+#[test]
+fn test_target_integrity_none_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert_eq!(engine.target_integrity(), None);
+}
+
+#[test]
+fn test_time_span() {
+    let mut engine = Engine::new(16, 1);
+    assert_eq!(engine.time_span(), None);
+
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let only = engine.get_event(0).unwrap().timestamp;
+    assert_eq!(engine.time_span(), Some((only, only)));
+
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    engine.inject_event(Category::Registry, 0, Status::Success, 0);
+    let timestamps: Vec<u64> = engine.iter_events().map(|event| event.timestamp).collect();
+    let min = *timestamps.iter().min().unwrap();
+    let max = *timestamps.iter().max().unwrap();
+    assert_eq!(engine.time_span(), Some((min, max)));
+    assert!(min <= max);
+}
+
+#[test]
+fn test_operation_histogram() {
+    use crate::operation::FileOp;
+    use std::collections::BTreeMap;
+
+    let mut engine = Engine::new(16, 1);
+    for op in [FileOp::Create, FileOp::Write, FileOp::Write, FileOp::Delete] {
+        engine.inject_event(Category::FileSystem, op as u8, Status::Success, 0);
+    }
+    engine.inject_event(Category::Network, FileOp::Write as u8, Status::Success, 0);
+
+    assert_eq!(
+        engine.operation_histogram(Category::FileSystem),
+        BTreeMap::from([
+            (FileOp::Create as u8, 1),
+            (FileOp::Delete as u8, 1),
+            (FileOp::Write as u8, 2),
+        ])
+    );
+    assert!(engine.operation_histogram(Category::Registry).is_empty());
+}
+
+#[test]
+fn test_write_jsonl_cancelled_mid_export() {
+    use crate::error::ExportError;
+    use std::io::{self, Write};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Sets the cancel flag once `limit` lines have been written.
+    struct CancelAfter<'a> {
+        out: Vec<u8>,
+        limit: usize,
+        cancel: &'a AtomicBool,
+    }
+
+    impl Write for CancelAfter<'_> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.out.extend_from_slice(buf);
+            if self.out.iter().filter(|&&b| b == b'\n').count() >= self.limit {
+                self.cancel.store(true, Ordering::Relaxed);
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let events: Vec<Event> = (1..=100)
+        .map(|id| synthetic(id, 0, Category::Process, Status::Success))
+        .collect();
+    let cancel = AtomicBool::new(false);
+    let mut w = CancelAfter {
+        out: Vec::new(),
+        limit: 3,
+        cancel: &cancel,
+    };
+
+    let result = crate::export::write_jsonl_cancellable(&events, &mut w, &cancel);
+    assert!(matches!(result, Err(ExportError::Cancelled)));
+    assert_eq!(String::from_utf8(w.out).unwrap().lines().count(), 3);
+
+    // The plain wrapper never cancels
+    let mut out = Vec::new();
+    crate::export::write_jsonl(&events, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().lines().count(), 100);
+}
+
+#[test]
+fn test_target_environment_empty_when_idle() {
+    let engine = Engine::new(16, 1);
+    assert!(engine.target_environment().is_empty());
+}
+
+#[test]
+fn test_on_match_fires_only_for_matching_events() {
+    use crate::engine::EngineBuilder;
+    use crate::filter::EventFilter;
+    use crate::operation::NetOp;
+    use std::net::SocketAddrV4;
+    use std::sync::mpsc;
+
+    let (connects_tx, connects) = mpsc::channel();
+    let (denied_tx, denied) = mpsc::channel();
+    let mut engine = EngineBuilder::new()
+        .arena_mb(16)
+        .threads(1)
+        .on_match(
+            EventFilter::new()
+                .category(Category::Network)
+                .operation(NetOp::Connect as u8),
+            Box::new(move |event| connects_tx.send(event.id).unwrap()),
+        )
+        .on_match(
+            EventFilter::new().status(Status::Denied),
+            Box::new(move |event| denied_tx.send(event.id).unwrap()),
+        )
+        .build();
+
+    let remote: SocketAddrV4 = "10.0.0.1:443".parse().unwrap();
+    let connect = engine
+        .inject_network_event(NetOp::Connect, Status::Success, 0, remote)
+        .unwrap();
+    engine.inject_network_event(NetOp::Send, Status::Success, 0, remote);
+    let denied_connect = engine
+        .inject_network_event(NetOp::Connect, Status::Denied, 0, remote)
+        .unwrap();
+    engine.poll();
+
+    // Later events fire on the next poll; earlier ones are not repeated
+    let denied_file = engine
+        .inject_event(Category::FileSystem, 0, Status::Denied, 0)
+        .unwrap();
+    engine.poll();
+    engine.poll();
+
+    assert_eq!(
+        connects.try_iter().collect::<Vec<_>>(),
+        vec![connect, denied_connect]
+    );
+    assert_eq!(
+        denied.try_iter().collect::<Vec<_>>(),
+        vec![denied_connect, denied_file]
+    );
+}
+
+#[test]
+fn test_cloned_builder_shares_match_callbacks() {
+    use crate::engine::EngineBuilder;
+    use crate::filter::EventFilter;
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel();
+    let builder = EngineBuilder::new().arena_mb(16).threads(1).on_match(
+        EventFilter::new().category(Category::Process),
+        Box::new(move |event| tx.send(event.id).unwrap()),
+    );
+    let mut first = builder.clone().build();
+    let mut second = builder.build();
+
+    let a = first
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    first.poll();
+    let b = second
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    second.poll();
+
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![a, b]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_summary_json_keys() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Denied, 0);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+
+    let summary = engine.summary_json();
+    let keys: Vec<&str> = summary
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    for key in [
+        "total_events",
+        "events_dropped",
+        "categories",
+        "statuses",
+        "risk_score",
+        "duration_ns",
+        "target_pid",
+        "exit_code",
+    ] {
+        assert!(keys.contains(&key), "missing {key}");
+    }
+    assert_eq!(summary["total_events"], 3);
+    assert_eq!(summary["categories"]["Network"], 2);
+    assert_eq!(summary["statuses"]["Denied"], 1);
+    assert!(summary["target_pid"].is_null());
+    assert!(summary["exit_code"].is_null());
+}
+
+#[test]
+fn test_poll_consistent_from_reader_threads() {
+    let mut engine = Engine::new(16, 2);
+    engine.submit();
+
+    let reader = engine.reader();
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(move || {
+                let mut last = 0;
+                for _ in 0..10_000 {
+                    let view = reader.poll_consistent();
+                    assert!(view.generation >= last);
+                    // Completion's flags and generation are seen together
+                    assert_eq!(view.generation > 0, view.is_complete());
+                    if view.is_complete() {
+                        assert_eq!(view.progress, 1.0);
+                    }
+                    assert_eq!(reader.event_count(), 0);
+                    last = view.generation;
+                }
+            });
+        }
+    });
+
+    assert!(engine.poll_consistent().generation <= 1);
+}
+
+#[test]
+fn test_drain_events_empties_engine() {
+    let mut engine = Engine::new(16, 1);
+    let ids: Vec<u64> = (0..3)
+        .filter_map(|_| engine.inject_event(Category::FileSystem, 0, Status::Success, 0))
+        .collect();
+
+    let drained: Vec<u64> = engine.drain_events().map(|event| event.id).collect();
+    assert_eq!(drained, ids);
+    assert_eq!(engine.event_count(), 0);
+
+    // Freed slots take new events
+    assert!(
+        engine
+            .inject_event(Category::FileSystem, 0, Status::Success, 0)
+            .is_some()
+    );
+    assert_eq!(engine.event_count(), 1);
+}
+
+#[test]
+fn test_event_socket_ipv4() {
+    use crate::operation::NetOp;
+    use std::net::{SocketAddr, SocketAddrV4};
+
+    let mut engine = Engine::new(16, 1);
+    let remote: SocketAddrV4 = "203.0.113.7:8080".parse().unwrap();
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, remote);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+
+    assert_eq!(engine.event_socket(0), Some(SocketAddr::V4(remote)));
+    assert_eq!(engine.event_socket(1), None);
+}
+
+#[test]
+fn test_event_socket_ipv6() {
+    use crate::operation::NetOp;
+    use std::net::SocketAddr;
+
+    let mut engine = Engine::new(16, 1);
+    let remote: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+    engine.inject_network_event(NetOp::Connect, Status::Success, 0, remote);
+
+    assert_eq!(engine.event_socket(0), Some(remote));
+    assert!(engine.event_socket(0).unwrap().is_ipv6());
+    // Not mistaken for an IPv4 endpoint
+    assert_eq!(engine.event_remote_endpoint(0), None);
+}
+
+#[test]
+fn test_set_category_enabled_round_trips() {
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.category_enabled(Category::Registry));
+
+    engine
+        .set_category_enabled(Category::Registry, false)
+        .unwrap();
+    assert!(!engine.category_enabled(Category::Registry));
+    assert!(!engine.provider_enabled("Registry"));
+    assert!(engine.category_enabled(Category::Process));
+
+    engine
+        .set_category_enabled(Category::Registry, true)
+        .unwrap();
+    assert!(engine.category_enabled(Category::Registry));
+
+    assert_eq!(
+        engine.set_category_enabled(Category::Input, false),
+        Err(MonitorError::NoProvider {
+            category: Category::Input
+        })
+    );
+    assert!(!engine.category_enabled(Category::Input));
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_to_arrow_rows_and_schema() {
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::UInt8Type;
+
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::Network, 1, Status::Denied, root);
+
+    let batch = engine.to_arrow();
+    assert_eq!(batch.num_rows(), engine.event_count());
+    assert_eq!(batch.schema(), crate::columnar::schema());
+
+    let schema = batch.schema();
+    let names: Vec<&str> = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "id",
+            "parent_id",
+            "timestamp",
+            "category",
+            "status",
+            "operation"
+        ]
+    );
+
+    let category = batch.column(3).as_dictionary::<UInt8Type>();
+    let values = category.values().as_string::<i32>();
+    assert_eq!(values.value(category.keys().value(1) as usize), "Network");
+    assert!(!category.is_null(0));
+}
+
+#[test]
+fn test_event_attributes_accessors() {
+    let attributes = EventAttributes::REMOTE | EventAttributes::INJECTED;
+    assert!(attributes.is_remote());
+    assert!(attributes.is_injected());
+    assert!(!attributes.is_elevated());
+    assert!(EventAttributes::default().is_empty());
+    assert_eq!(
+        EventAttributes::from_bits_truncate(1 << 15),
+        EventAttributes::empty()
+    );
+
+    // The core tags synthetic events, in batch reads and single lookups
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let event = engine.iter_events().next().unwrap();
+    assert_eq!(event.attributes, EventAttributes::INJECTED);
+    assert!(engine.get_event(0).unwrap().attributes.is_injected());
+}
+
+#[test]
+fn test_snapshot_merge_rekeys_ids() {
+    use std::collections::HashSet;
+
+    let capture = |categories: &[Category]| {
+        let mut engine = Engine::new(16, 1);
+        let root = engine
+            .inject_event(Category::Process, 0, Status::Success, 0)
+            .unwrap();
+        for &category in categories {
+            engine.inject_event(category, 0, Status::Success, root);
+        }
+        engine.snapshot()
+    };
+    let first = capture(&[Category::FileSystem]);
+    let second = capture(&[Category::Network, Category::Registry]);
+
+    let merged = EventSnapshot::merge(&[first.clone(), second.clone()]);
+    assert_eq!(merged.len(), first.len() + second.len());
+
+    let ids: HashSet<u64> = merged.events().iter().map(|event| event.id).collect();
+    assert_eq!(ids.len(), merged.len());
+
+    // Parents stay within their source
+    let second_root = merged.remapped_id(1, 1).unwrap();
+    assert_eq!(second_root, 3);
+    for event in &merged.events()[3..] {
+        assert_eq!(event.parent_id, second_root);
+    }
+    assert_eq!(
+        merged.events()[1].parent_id,
+        merged.remapped_id(0, 1).unwrap()
+    );
+    assert_eq!(merged.remapped_id(2, 1), None);
+    assert_eq!(first.remapped_id(0, 1), None);
+}
+
+#[test]
+fn test_event_at_matches_batch_read() {
+    use crate::ffi;
+
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 1, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::Network, 2, Status::Suspicious, root);
+    engine.inject_target_event(42, Category::Registry, 3, Status::Denied, root);
+
+    // One slot too many: only the live events are written
+    let mut batch = vec![ffi::RawEvent::default(); engine.event_count() + 1];
+    assert_eq!(ffi::get_events(&engine.handle, 0, &mut batch), 3);
+    for (index, raw) in batch[..3].iter().enumerate() {
+        assert_eq!(*raw, ffi::event_at(&engine.handle, index));
+    }
+    assert_eq!(batch[1].parent_id, root);
+    assert_eq!(batch[1].category, Category::Network.repr);
+    assert_eq!(batch[2].target_pid, 42);
+    assert_eq!(batch[2].operation, 3);
+    assert_eq!(
+        ffi::event_at(&engine.handle, engine.event_count()),
+        ffi::RawEvent::default()
+    );
+
+    // A batch starting mid-graph is offset accordingly
+    let mut tail = vec![ffi::RawEvent::default(); 8];
+    assert_eq!(ffi::get_events(&engine.handle, 2, &mut tail), 1);
+    assert_eq!(tail[0], batch[2]);
+}
+
+#[test]
+fn test_top_parents_by_child_count() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let busy = engine
+        .inject_event(Category::Process, 0, Status::Success, root)
+        .unwrap();
+    let quiet = engine
+        .inject_event(Category::Process, 0, Status::Success, root)
+        .unwrap();
+    // root: 3 children, busy: 4, quiet: 1
+    engine.inject_event(Category::Process, 0, Status::Success, root);
+    for _ in 0..4 {
+        engine.inject_event(Category::FileSystem, 0, Status::Success, busy);
+    }
+    engine.inject_event(Category::Network, 0, Status::Success, quiet);
+
+    assert_eq!(engine.top_parents(2), [(busy, 4), (root, 3)]);
+    assert_eq!(engine.top_parents(10), [(busy, 4), (root, 3), (quiet, 1)]);
+    assert!(engine.top_parents(0).is_empty());
+}
+
+#[test]
+fn test_events_by_category_map_covers_all_events() {
+    let mut engine = Engine::new(16, 1);
+    assert!(engine.events_by_category_map().is_empty());
+
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    for _ in 0..3 {
+        engine.inject_event(Category::FileSystem, 0, Status::Success, root);
+    }
+    engine.inject_event(Category::Network, 0, Status::Suspicious, root);
+
+    let map = engine.events_by_category_map();
+    let total: usize = map.values().map(Vec::len).sum();
+    assert_eq!(total, engine.event_count());
+    assert_eq!(map[&Category::FileSystem.repr].len(), 3);
+    assert!(
+        map.iter()
+            .all(|(&cat, events)| events.iter().all(|e| e.category.repr == cat))
+    );
+}
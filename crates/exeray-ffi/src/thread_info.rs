@@ -0,0 +1,12 @@
+//! Snapshot of a thread in the target process.
+
+/// A thread of the target process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadInfo {
+    /// Thread ID.
+    pub tid: u32,
+    /// Whether the thread's suspend count is nonzero.
+    pub suspended: bool,
+    /// Win32 start address (0 if it could not be queried).
+    pub start_address: u64,
+}
@@ -0,0 +1,52 @@
+//! Meaning of event timestamps.
+
+/// Nanoseconds between 1601-01-01 (the FILETIME epoch) and 1970-01-01.
+const FILETIME_TO_UNIX_NS: u64 = 11_644_473_600 * 1_000_000_000;
+
+/// What raw [`Event::timestamp`](crate::Event::timestamp) values count from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampEpoch {
+    /// Nanoseconds since 1970-01-01 UTC.
+    UnixNanos,
+    /// Monotonic nanoseconds, roughly since boot. Only differences are
+    /// meaningful until converted with [`Engine::to_unix_nanos`].
+    ///
+    /// [`Engine::to_unix_nanos`]: crate::Engine::to_unix_nanos
+    QpcBootRelative,
+    /// 100 ns ticks since 1601-01-01 UTC, as carried by ETW records.
+    EtwFileTime,
+}
+
+impl TimestampEpoch {
+    /// Decode the core's raw epoch value, treating unknown values as
+    /// [`TimestampEpoch::QpcBootRelative`], the core's clock.
+    pub(crate) fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::UnixNanos,
+            2 => Self::EtwFileTime,
+            _ => Self::QpcBootRelative,
+        }
+    }
+
+    /// Encode as the core's raw epoch value, the inverse of `from_raw`.
+    pub(crate) fn to_raw(self) -> u8 {
+        match self {
+            Self::UnixNanos => 0,
+            Self::QpcBootRelative => 1,
+            Self::EtwFileTime => 2,
+        }
+    }
+
+    /// Convert a raw timestamp to Unix nanoseconds.
+    ///
+    /// `unix_offset_ns` is Unix time minus monotonic time and only applies
+    /// to [`TimestampEpoch::QpcBootRelative`]. Saturates instead of
+    /// wrapping for timestamps outside the Unix range.
+    pub fn to_unix_nanos(self, raw: u64, unix_offset_ns: i64) -> u64 {
+        match self {
+            Self::UnixNanos => raw,
+            Self::QpcBootRelative => raw.saturating_add_signed(unix_offset_ns),
+            Self::EtwFileTime => raw.saturating_mul(100).saturating_sub(FILETIME_TO_UNIX_NS),
+        }
+    }
+}
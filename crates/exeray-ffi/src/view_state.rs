@@ -1,5 +1,19 @@
 //! Engine view state for UI updates.
 
+bitflags::bitflags! {
+    /// Typed view of the [`ViewState::flags`] bits.
+    ///
+    /// An empty set means idle. `Debug` prints the set flag names, e.g.
+    /// `StateFlags(PENDING | READY)`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct StateFlags: u64 {
+        const PENDING = 1 << 0;
+        const COMPLETE = 1 << 1;
+        const READY = 1 << 2;
+        const ERROR = 1 << 3;
+    }
+}
+
 /// Engine view state for UI updates.
 pub struct ViewState {
     pub generation: u64,
@@ -10,10 +24,15 @@ pub struct ViewState {
 
 impl ViewState {
     pub const IDLE: u64 = 0;
-    pub const PENDING: u64 = 1 << 0;
-    pub const COMPLETE: u64 = 1 << 1;
-    pub const READY: u64 = 1 << 2;
-    pub const ERROR: u64 = 1 << 3;
+    pub const PENDING: u64 = StateFlags::PENDING.bits();
+    pub const COMPLETE: u64 = StateFlags::COMPLETE.bits();
+    pub const READY: u64 = StateFlags::READY.bits();
+    pub const ERROR: u64 = StateFlags::ERROR.bits();
+
+    /// Get the flags as a typed set, dropping bits unknown to this version.
+    pub fn state_flags(&self) -> StateFlags {
+        StateFlags::from_bits_truncate(self.flags)
+    }
 
     pub fn is_complete(&self) -> bool {
         self.flags & Self::COMPLETE != 0
@@ -22,4 +41,12 @@ impl ViewState {
     pub fn is_pending(&self) -> bool {
         self.flags & Self::PENDING != 0
     }
+
+    pub fn is_ready(&self) -> bool {
+        self.flags & Self::READY != 0
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.flags & Self::ERROR != 0
+    }
 }
@@ -1,21 +1,528 @@
-use exeray_ffi::{Engine, ViewState};
+use crate::clipboard;
+use crate::notification::{Notification, Notifications};
+use crate::scrubber;
+use crate::search::{self, Search};
+use crate::sort::SortMode;
+use crate::timestamps::{self, TimeMode};
+use exeray_ffi::analysis::IdleTracker;
+use exeray_ffi::{
+    Category, Classifier, DefaultClassifier, Engine, EngineStats, Event, EventGraphIndex,
+    EventSnapshot, ExitInfo, IntegrityLevel, RiskWeights, Severity, SnapshotMetadata, Status,
+    ViewState,
+};
+use ratatui::style::Color;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+/// Number of frame intervals averaged for the FPS readout.
+const FPS_WINDOW: usize = 30;
+
+/// Moving-average frame rate over the last [`FPS_WINDOW`] frames.
+#[derive(Default)]
+pub struct FrameStats {
+    last: Option<Instant>,
+    intervals: VecDeque<Duration>,
+}
+
+impl FrameStats {
+    /// Record a frame rendered at `now`.
+    ///
+    /// The first frame only sets the reference point.
+    pub fn record(&mut self, now: Instant) {
+        if let Some(last) = self.last {
+            if self.intervals.len() == FPS_WINDOW {
+                self.intervals.pop_front();
+            }
+            self.intervals
+                .push_back(now.saturating_duration_since(last));
+        }
+        self.last = Some(now);
+    }
+
+    /// Average frames per second, or 0 before two frames were recorded.
+    pub fn fps(&self) -> f32 {
+        let total: Duration = self.intervals.iter().sum();
+        if total.is_zero() {
+            return 0.0;
+        }
+        self.intervals.len() as f32 / total.as_secs_f32()
+    }
+}
+
+/// Number of generation samples kept by [`GenerationTracker`].
+const GENERATION_WINDOW: usize = 32;
+
+/// Recent engine generations, for spotting view flicker.
+#[derive(Default)]
+pub struct GenerationTracker {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl GenerationTracker {
+    /// Record the generation observed at `now`, keeping the last
+    /// [`GENERATION_WINDOW`] samples.
+    pub fn record(&mut self, now: Instant, generation: u64) {
+        if self.samples.len() == GENERATION_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((now, generation));
+    }
+
+    /// Average generations per second across the window, or 0 before two
+    /// samples were recorded.
+    pub fn rate(&self) -> f32 {
+        let (Some(&(first_at, first)), Some(&(last_at, last))) =
+            (self.samples.front(), self.samples.back())
+        else {
+            return 0.0;
+        };
+        let elapsed = last_at.saturating_duration_since(first_at);
+        if elapsed.is_zero() {
+            return 0.0;
+        }
+        last.saturating_sub(first) as f32 / elapsed.as_secs_f32()
+    }
+}
+
+/// Number of event total samples kept by [`EventRateTracker`].
+const EVENT_RATE_WINDOW: usize = 32;
+
+/// Recent totals of committed events, for the events-per-second counter.
+///
+/// Fed with every event ever committed (live plus evicted), so the rate
+/// stays meaningful once a ring buffer is full and the live count stops
+/// growing.
+#[derive(Default)]
+pub struct EventRateTracker {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl EventRateTracker {
+    /// Record the committed event total observed at `now`, keeping the
+    /// last [`EVENT_RATE_WINDOW`] samples. A total below the previous one
+    /// means the events were cleared, so the window starts over.
+    pub fn record(&mut self, now: Instant, committed: u64) {
+        if self
+            .samples
+            .back()
+            .is_some_and(|&(_, last)| committed < last)
+        {
+            self.samples.clear();
+        }
+        if self.samples.len() == EVENT_RATE_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((now, committed));
+    }
+
+    /// Average events per second across the window, or 0 before two
+    /// samples were recorded.
+    pub fn rate(&self) -> f32 {
+        let (Some(&(first_at, first)), Some(&(last_at, last))) =
+            (self.samples.front(), self.samples.back())
+        else {
+            return 0.0;
+        };
+        let elapsed = last_at.saturating_duration_since(first_at);
+        if elapsed.is_zero() {
+            return 0.0;
+        }
+        (last - first) as f32 / elapsed.as_secs_f32()
+    }
+}
+
+/// What the single-line footer shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FooterMode {
+    /// Key help, the default.
+    #[default]
+    Help,
+    /// Live event counters.
+    Counters,
+}
+
+impl FooterMode {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Help => Self::Counters,
+            Self::Counters => Self::Help,
+        }
+    }
+}
+
+/// Progress of quitting, which asks first while a capture is active.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuitState {
+    #[default]
+    Running,
+    /// Waiting for `y`/`n` on stopping the capture and killing the target.
+    Confirming,
+    Quit,
+}
+
+impl QuitState {
+    /// State after a quit request; only an active capture asks first.
+    pub fn request(self, monitoring: bool) -> Self {
+        match self {
+            Self::Running if monitoring => Self::Confirming,
+            Self::Running => Self::Quit,
+            other => other,
+        }
+    }
+
+    /// State after answering the prompt.
+    pub fn answer(self, confirmed: bool) -> Self {
+        match self {
+            Self::Confirming if confirmed => Self::Quit,
+            Self::Confirming => Self::Running,
+            other => other,
+        }
+    }
+}
+
+/// Replay speed factors cycled with `x`.
+pub const REPLAY_SPEEDS: [u32; 3] = [1, 2, 10];
+
+/// Checkpoint file the `r` key replays unless `--replay` names another.
+pub const REPLAY_FILE: &str = "capture.toml";
+
+/// Progressive playback of a loaded snapshot.
+///
+/// An event is revealed once the replay clock passes its offset from the
+/// first event. The clock advances by wall time multiplied by the speed
+/// factor, so at 10x a one-second gap between events takes 100 ms.
+pub struct ReplayController {
+    /// Snapshot events in timestamp order.
+    events: Vec<Event>,
+    /// Index into [`REPLAY_SPEEDS`].
+    speed: usize,
+    playing: bool,
+    /// Capture time replayed so far, in nanoseconds from the first event.
+    elapsed_ns: u64,
+    last_advance: Option<Instant>,
+    /// Session details of the snapshot, for its timestamp clock.
+    metadata: SnapshotMetadata,
+}
+
+impl ReplayController {
+    /// Start playing `snapshot` at 1x.
+    pub fn new(snapshot: &EventSnapshot) -> Self {
+        let mut events = snapshot.events().to_vec();
+        events.sort_by_key(|event| event.timestamp);
+        Self {
+            events,
+            speed: 0,
+            playing: true,
+            elapsed_ns: 0,
+            last_advance: None,
+            metadata: snapshot.metadata().clone(),
+        }
+    }
+
+    /// Convert a replayed event's timestamp to Unix nanoseconds using the
+    /// clock of the engine that captured it.
+    pub fn to_unix_nanos(&self, raw: u64) -> Option<u64> {
+        self.metadata.to_unix_nanos(raw)
+    }
+
+    pub fn speed(&self) -> u32 {
+        REPLAY_SPEEDS[self.speed]
+    }
+
+    /// Switch to the next speed factor, wrapping back to 1x.
+    pub fn cycle_speed(&mut self) {
+        self.speed = (self.speed + 1) % REPLAY_SPEEDS.len();
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Pause, or resume where the replay was paused.
+    pub fn toggle_play(&mut self) {
+        self.playing = !self.playing;
+        self.last_advance = None;
+    }
+
+    /// Advance the replay clock to `now`.
+    ///
+    /// The first call after starting or resuming only sets the reference
+    /// point; paused replays do not advance.
+    pub fn advance(&mut self, now: Instant) {
+        if !self.playing {
+            return;
+        }
+        if let Some(last) = self.last_advance {
+            let wall =
+                u64::try_from(now.saturating_duration_since(last).as_nanos()).unwrap_or(u64::MAX);
+            self.elapsed_ns = self
+                .elapsed_ns
+                .saturating_add(wall.saturating_mul(u64::from(self.speed())));
+        }
+        self.last_advance = Some(now);
+    }
+
+    /// Timestamp of the first event, the replay origin.
+    pub fn start(&self) -> Option<u64> {
+        self.events.first().map(|event| event.timestamp)
+    }
+
+    /// Events revealed so far, in timestamp order.
+    pub fn revealed(&self) -> &[Event] {
+        let Some(start) = self.start() else {
+            return &[];
+        };
+        let end = self
+            .events
+            .partition_point(|event| event.timestamp - start <= self.elapsed_ns);
+        &self.events[..end]
+    }
+}
+
+/// Engine state resolved from the [`ViewState`] flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineState {
+    Idle,
+    Running,
+    Ready,
+    Complete,
+    Error,
+}
+
+impl EngineState {
+    /// Resolve the state, with errors taking precedence over completion.
+    pub fn from_view(state: &ViewState) -> Self {
+        if state.is_error() {
+            Self::Error
+        } else if state.is_complete() {
+            Self::Complete
+        } else if state.is_ready() {
+            Self::Ready
+        } else if state.is_pending() {
+            Self::Running
+        } else {
+            Self::Idle
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::Running => "Running",
+            Self::Ready => "Ready",
+            Self::Complete => "Complete",
+            Self::Error => "Error",
+        }
+    }
+
+    /// Color shared by the status widget and the progress gauge.
+    pub fn color(self) -> Color {
+        match self {
+            Self::Idle => Color::DarkGray,
+            Self::Running => Color::Yellow,
+            Self::Ready => Color::Blue,
+            Self::Complete => Color::Green,
+            Self::Error => Color::Red,
+        }
+    }
+}
+
+/// Color band for a risk score: green below 20, yellow below 50, red above.
+pub fn risk_color(score: u32) -> Color {
+    match score {
+        0..20 => Color::Green,
+        20..50 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+/// Scroll offset that keeps `selected` inside a `height`-row viewport over
+/// `len` rows, never scrolling past the last full page.
+pub fn clamp_scroll(offset: usize, selected: usize, len: usize, height: usize) -> usize {
+    let height = height.max(1);
+    let offset = if selected < offset {
+        selected
+    } else if selected >= offset + height {
+        selected + 1 - height
+    } else {
+        offset
+    };
+    offset.min(len.saturating_sub(height))
+}
+
+/// File the `e` key writes the visible events to.
+pub const EXPORT_FILE: &str = "capture.jsonl";
+
+/// Render an event as a single list row.
+pub fn event_row(event: &Event) -> String {
+    event.summary()
+}
+
+/// Earliest timestamp among `events`.
+fn earliest(events: &[Event]) -> Option<u64> {
+    events.iter().map(|event| event.timestamp).min()
+}
+
+/// Merge two runs sorted by `compare` into one, `a`'s items first on ties.
+fn merge_sorted<T: Copy>(
+    mut a: Vec<T>,
+    b: Vec<T>,
+    mut compare: impl FnMut(&T, &T) -> Ordering,
+) -> Vec<T> {
+    // New events usually sort last, which needs no merge at all
+    match (a.last(), b.first()) {
+        (Some(last), Some(first)) if compare(last, first) == Ordering::Greater => {}
+        _ => {
+            a.extend(b);
+            return a;
+        }
+    }
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut a, mut b) = (a.into_iter().peekable(), b.into_iter().peekable());
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        if compare(x, y) == Ordering::Greater {
+            merged.extend(b.next());
+        } else {
+            merged.extend(a.next());
+        }
+    }
+    merged.extend(a);
+    merged.extend(b);
+    merged
+}
 
 pub struct App {
     engine: Engine,
     state: ViewState,
+    frames: FrameStats,
+    generations: GenerationTracker,
+    event_rate: EventRateTracker,
+    stats: EngineStats,
+    /// Analysis progress estimate, refreshed every tick from `idle`.
+    analysis_progress: Option<f32>,
+    /// Event rate decay of the live capture, fed by `sync_events`.
+    idle: IdleTracker,
+    /// Whether the monitored target runs elevated, looked up once per session.
+    elevated: bool,
+    events: Vec<Event>,
+    /// Events dropped from the front of `events` since it was replaced.
+    /// Positions in `index` and `filtered` count them, so they survive
+    /// evictions; `events[pos - evicted]` is the event at `pos`.
+    evicted: usize,
+    index: EventGraphIndex,
+    /// Risk score of `events` before saturation, kept up to date as
+    /// events are appended and evicted.
+    risk: u64,
+    /// Cached events with [`Status::Suspicious`], kept like `risk`.
+    suspicious: usize,
+    /// Cached events per category, indexed like [`Category::ALL`] and kept
+    /// like `risk`.
+    categories: [usize; Category::ALL.len()],
+    filtered: Vec<usize>,
+    selected: usize,
+    scroll: usize,
+    viewport_rows: usize,
+    search: Search,
+    sort: SortMode,
+    footer: FooterMode,
+    show_chart: bool,
+    follow: bool,
+    scrub_cursor: Option<u64>,
+    notifications: Notifications,
+    classifier: Box<dyn Classifier>,
+    exits: Receiver<ExitInfo>,
+    dirty: bool,
+    notes: HashMap<u64, String>,
+    note_draft: Option<(u64, String)>,
+    time_mode: TimeMode,
+    quit: QuitState,
+    replay: Option<ReplayController>,
+    /// Raw timestamp of the first captured event, for relative times.
+    capture_start: Option<u64>,
 }
 
 impl App {
-    pub fn new(arena_mb: usize, threads: usize) -> Self {
-        Self {
-            engine: Engine::new(arena_mb, threads),
+    /// Build the app around an existing engine.
+    pub fn with_engine(engine: Engine) -> Self {
+        Self::with_classifier(engine, Box::new(DefaultClassifier))
+    }
+
+    /// Build the app with a classifier overriding the core-assigned status
+    /// for row emphasis and the risk score.
+    pub fn with_classifier(mut engine: Engine, classifier: Box<dyn Classifier>) -> Self {
+        let (exit_tx, exits) = mpsc::channel();
+        engine.set_on_exit(Box::new(move |info| {
+            let _ = exit_tx.send(info);
+        }));
+
+        let mut app = Self {
+            engine,
             state: ViewState {
                 generation: 0,
                 timestamp_ns: 0,
                 flags: 0,
                 progress: 0.0,
             },
+            frames: FrameStats::default(),
+            generations: GenerationTracker::default(),
+            event_rate: EventRateTracker::default(),
+            stats: EngineStats::default(),
+            analysis_progress: None,
+            idle: IdleTracker::default(),
+            elevated: false,
+            events: Vec::new(),
+            evicted: 0,
+            index: EventGraphIndex::default(),
+            risk: 0,
+            suspicious: 0,
+            categories: [0; Category::ALL.len()],
+            filtered: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            viewport_rows: 1,
+            search: Search::default(),
+            sort: SortMode::default(),
+            footer: FooterMode::default(),
+            show_chart: false,
+            follow: false,
+            scrub_cursor: None,
+            notifications: Notifications::default(),
+            classifier,
+            exits,
+            dirty: true,
+            notes: HashMap::new(),
+            note_draft: None,
+            time_mode: TimeMode::default(),
+            quit: QuitState::default(),
+            replay: None,
+            capture_start: None,
+        };
+        if !exeray_ffi::has_monitoring_support() {
+            app.notifications
+                .error("Monitoring unavailable on this platform", Instant::now());
         }
+        app
+    }
+
+    /// Launch `exe_path` and capture its events, reporting failures in
+    /// the footer.
+    pub fn monitor(&mut self, exe_path: &str) {
+        if let Err(err) = self.engine.start_monitoring(exe_path) {
+            self.notifications
+                .error(format!("Cannot monitor {exe_path}: {err}"), Instant::now());
+            return;
+        }
+        // The token does not change while the target runs
+        self.elevated = self
+            .engine
+            .target_integrity()
+            .is_some_and(IntegrityLevel::is_elevated);
     }
 
     pub fn start(&mut self) {
@@ -24,15 +531,755 @@ impl App {
         }
     }
 
+    /// Freeze the target, or resume it if already frozen.
+    pub fn toggle_freeze(&mut self) {
+        if self.engine.target_frozen() {
+            self.engine.unfreeze_target();
+        } else {
+            self.engine.freeze_target();
+        }
+    }
+
+    pub fn kill(&mut self) {
+        self.engine.kill_target();
+    }
+
+    pub fn quit_state(&self) -> QuitState {
+        self.quit
+    }
+
+    /// Quit, or ask for confirmation first while a capture is active.
+    pub fn request_quit(&mut self) {
+        self.quit = self.quit.request(self.engine.is_monitoring());
+    }
+
+    /// Answer the quit prompt; confirming stops monitoring, which kills the
+    /// target.
+    pub fn answer_quit(&mut self, confirmed: bool) {
+        self.quit = self.quit.answer(confirmed);
+        if self.quit == QuitState::Quit {
+            self.engine.stop_monitoring();
+        }
+    }
+
     pub fn tick(&mut self) {
+        let before = (
+            self.state.generation,
+            self.state.flags,
+            self.state.progress,
+            self.selected_id(),
+            self.notifications.current().cloned(),
+        );
         self.state = self.engine.poll();
+        self.generations
+            .record(Instant::now(), self.state.generation);
+        self.stats = self.engine.stats();
+        // Ids are sequential, so everything before the oldest was evicted
+        let evicted = self.engine.oldest_event_id().saturating_sub(1);
+        self.event_rate
+            .record(Instant::now(), evicted + self.stats.event_count as u64);
+        self.notifications.expire(Instant::now());
+        if let Ok(exit) = self.exits.try_recv() {
+            let now = Instant::now();
+            if exit.killed {
+                self.notifications
+                    .info(format!("Target {} killed", exit.pid), now);
+            } else if exit.is_error() {
+                self.notifications.error(
+                    format!("Target {} exited with code {}", exit.pid, exit.exit_code),
+                    now,
+                );
+            } else {
+                self.notifications
+                    .info(format!("Target {} exited", exit.pid), now);
+            }
+        }
+
+        if let Some(replay) = &mut self.replay {
+            replay.advance(Instant::now());
+            // Revealed events only grow, so append the newly revealed ones
+            let revealed = replay.revealed();
+            if revealed.len() > self.events.len() {
+                let first = self.events.len();
+                self.events.extend_from_slice(&revealed[first..]);
+                let keep = before.3;
+                self.events_appended(first, keep);
+            }
+        } else {
+            self.sync_events(before.3);
+        }
+        // Same estimate as Engine::analysis_progress, from the events
+        // already fetched instead of a walk over the whole capture
+        self.analysis_progress = if self.engine.target_exited() {
+            Some(1.0)
+        } else if self.engine.target_running() {
+            Some(self.idle.progress(self.state.timestamp_ns))
+        } else {
+            None
+        };
+
+        let after = (
+            self.state.generation,
+            self.state.flags,
+            self.state.progress,
+            self.selected_id(),
+            self.notifications.current().cloned(),
+        );
+        self.dirty |= before != after;
+    }
+
+    /// Bring the cached live events up to date with the engine.
+    ///
+    /// Ids are sequential, so only the events after the newest cached one
+    /// are read; cached events older than the engine's oldest were evicted
+    /// or cleared and are dropped from the front.
+    fn sync_events(&mut self, keep: Option<u64>) {
+        let oldest = match self.engine.oldest_event_id() {
+            0 => u64::MAX,
+            id => id,
+        };
+        let evicted = self.events.partition_point(|event| event.id < oldest);
+        let last_seen = self.events.last().map_or(0, |event| event.id);
+        let mut added: Vec<Event> = self
+            .engine
+            .iter_events()
+            .rev()
+            .take_while(|event| event.id > last_seen)
+            .collect();
+        if evicted == 0 && added.is_empty() {
+            return;
+        }
+
+        added.reverse();
+        for event in &added {
+            self.idle.record(event.timestamp);
+        }
+        let kept = if evicted == 0 {
+            self.capture_start
+        } else {
+            self.index.remove_oldest(&self.events[..evicted]);
+            self.count_events(0..evicted, false);
+            self.events.drain(..evicted);
+            self.evicted += evicted;
+            let first = self.evicted;
+            self.filtered.retain(|&pos| pos >= first);
+            earliest(&self.events)
+        };
+        self.capture_start = kept.into_iter().chain(earliest(&added)).min();
+        let first = self.evicted + self.events.len();
+        self.events.append(&mut added);
+        self.events_appended(first, keep);
+    }
+
+    /// Replace the cached events, keeping the selected event.
+    fn show_events(&mut self, events: Vec<Event>, capture_start: Option<u64>) {
+        let keep = self.selected_id();
+        self.events = events;
+        self.capture_start = capture_start;
+        self.events_changed(keep);
+    }
+
+    /// Re-index and re-filter after the cached events were replaced,
+    /// keeping event `keep` selected.
+    fn events_changed(&mut self, keep: Option<u64>) {
+        self.evicted = 0;
+        self.index = EventGraphIndex::build(&self.events);
+        self.risk = 0;
+        self.suspicious = 0;
+        self.categories = [0; Category::ALL.len()];
+        self.count_events(0..self.events.len(), true);
+        self.refilter(keep);
+        self.rows_changed();
+    }
+
+    /// Index and filter the events appended from position `first` on,
+    /// merging the matching ones into the sorted rows and keeping event
+    /// `keep` selected.
+    fn events_appended(&mut self, first: usize, keep: Option<u64>) {
+        let start = first - self.evicted;
+        self.count_events(start..self.events.len(), true);
+        let appended = &self.events[start..];
+        self.index.extend(appended, first);
+        let mut added: Vec<usize> = (first..first + appended.len())
+            .filter(|&pos| self.shows(self.event(pos)))
+            .collect();
+        added.sort_by(|&a, &b| self.sort.compare(self.event(a), self.event(b)));
+        let filtered = std::mem::take(&mut self.filtered);
+        self.filtered = merge_sorted(filtered, added, |&a, &b| {
+            self.sort.compare(self.event(a), self.event(b))
+        });
+        self.visible_changed(keep);
+        self.rows_changed();
+    }
+
+    /// Add the cached events in `range` to the running totals, or take
+    /// them out before they are evicted.
+    fn count_events(&mut self, range: Range<usize>, added: bool) {
+        let weights = RiskWeights::default();
+        for event in &self.events[range] {
+            let risk = u64::from(weights.weigh_as(event, self.classifier.classify(event)));
+            let suspicious = usize::from(event.status == Status::Suspicious);
+            let category = self.categories.get_mut(usize::from(event.category.repr));
+            if added {
+                self.risk += risk;
+                self.suspicious += suspicious;
+                if let Some(count) = category {
+                    *count += 1;
+                }
+            } else {
+                self.risk -= risk;
+                self.suspicious -= suspicious;
+                if let Some(count) = category {
+                    *count -= 1;
+                }
+            }
+        }
+    }
+
+    /// Follow the newest event if asked to and schedule a redraw.
+    fn rows_changed(&mut self) {
+        if self.follow {
+            self.select_newest();
+        }
+        self.dirty = true;
+    }
+
+    /// Cached event at absolute position `pos`.
+    fn event(&self, pos: usize) -> &Event {
+        &self.events[pos - self.evicted]
+    }
+
+    /// Snapshot replay in progress, if any.
+    pub fn replay(&self) -> Option<&ReplayController> {
+        self.replay.as_ref()
+    }
+
+    /// Replay `snapshot` in place of the live events, starting empty.
+    pub fn start_replay(&mut self, snapshot: &EventSnapshot) {
+        let replay = ReplayController::new(snapshot);
+        let start = replay.start();
+        self.replay = Some(replay);
+        self.show_events(Vec::new(), start);
+    }
+
+    /// Load the checkpoint at `path`, replay it and report the outcome.
+    pub fn load_replay(&mut self, path: &Path) {
+        let now = Instant::now();
+        match exeray_ffi::restore_view(path) {
+            Ok(snapshot) => {
+                self.notifications.info(
+                    format!(
+                        "Replaying {} events from {}",
+                        snapshot.len(),
+                        path.display()
+                    ),
+                    now,
+                );
+                self.start_replay(&snapshot);
+            }
+            Err(err) => self
+                .notifications
+                .error(format!("Replay of {} failed: {err}", path.display()), now),
+        }
+    }
+
+    /// Leave the replay and show the live events again.
+    pub fn stop_replay(&mut self) {
+        if self.replay.take().is_none() {
+            return;
+        }
+        // The cache held the replay; read the live events afresh
+        self.idle = IdleTracker::default();
+        self.show_events(Vec::new(), None);
+        self.sync_events(None);
+    }
+
+    pub fn toggle_replay_play(&mut self) {
+        if let Some(replay) = &mut self.replay {
+            replay.toggle_play();
+        }
+    }
+
+    pub fn cycle_replay_speed(&mut self) {
+        if let Some(replay) = &mut self.replay {
+            replay.cycle_speed();
+        }
+    }
+
+    /// Events currently visible in the list.
+    pub fn visible_events(&self) -> impl Iterator<Item = &Event> {
+        self.filtered.iter().map(|&pos| self.event(pos))
+    }
+
+    /// Visible events in rows `start..start + len`, clamped to the list.
+    pub fn visible_window(&self, start: usize, len: usize) -> impl Iterator<Item = &Event> {
+        let start = start.min(self.filtered.len());
+        let end = start.saturating_add(len).min(self.filtered.len());
+        self.filtered[start..end].iter().map(|&pos| self.event(pos))
+    }
+
+    /// Category of an event's parent, if the parent is still captured.
+    pub fn parent_category(&self, event: &Event) -> Option<Category> {
+        match event.parent_id {
+            0 => None,
+            id => self.index.by_id(id).map(|pos| self.event(pos).category),
+        }
+    }
+
+    /// Selected row within the visible events.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = search::clamp_selection(self.selected + 1, self.filtered.len());
+        self.follow_selection();
+    }
+
+    /// Move the selection up, leaving follow mode.
+    pub fn select_prev(&mut self) {
+        self.follow = false;
+        self.selected = self.selected.saturating_sub(1);
+        self.follow_selection();
+    }
+
+    /// Whether the selection tracks the newest event as events arrive.
+    pub fn following(&self) -> bool {
+        self.follow
+    }
+
+    /// Toggle follow mode; turning it on jumps to the newest event.
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+        if self.follow {
+            self.select_newest();
+        }
+    }
+
+    /// Select the visible event with the highest id and scroll to it.
+    fn select_newest(&mut self) {
+        let newest = (0..self.filtered.len()).max_by_key(|&row| self.event(self.filtered[row]).id);
+        if let Some(row) = newest {
+            self.selected = row;
+            self.follow_selection();
+        }
+    }
+
+    /// First visible row of the event list.
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll
+    }
+
+    /// Adapt to a new event list height after a terminal resize.
+    pub fn resize(&mut self, rows: usize) {
+        self.viewport_rows = rows.max(1);
+        self.follow_selection();
+        self.dirty = true;
+    }
+
+    /// Scroll so the selected row stays on screen.
+    fn follow_selection(&mut self) {
+        self.scroll = clamp_scroll(
+            self.scroll,
+            self.selected,
+            self.filtered.len(),
+            self.viewport_rows,
+        );
+    }
+
+    /// Earliest and latest visible event timestamps.
+    pub fn time_range(&self) -> Option<(u64, u64)> {
+        let timestamps = self.visible_events().map(|event| event.timestamp);
+        let (min, max) =
+            timestamps.fold((u64::MAX, 0), |(min, max), ts| (min.min(ts), max.max(ts)));
+        (min <= max).then_some((min, max))
+    }
+
+    /// Timeline scrubber cursor, once it has been moved.
+    pub fn scrub_cursor(&self) -> Option<u64> {
+        self.scrub_cursor
+    }
+
+    pub fn scrub_left(&mut self) {
+        self.scrub(false);
+    }
+
+    pub fn scrub_right(&mut self) {
+        self.scrub(true);
+    }
+
+    /// Move the scrubber cursor one step and select the nearest event.
+    ///
+    /// The cursor starts at the first event. Only events within one step of
+    /// the cursor are considered, so in a gap the selection stays put.
+    fn scrub(&mut self, forward: bool) {
+        let Some((min, max)) = self.time_range() else {
+            return;
+        };
+        let step = scrubber::step_ns(min, max);
+        let cursor = match self.scrub_cursor {
+            None => min,
+            Some(cursor) if forward => cursor.saturating_add(step),
+            Some(cursor) => cursor.saturating_sub(step),
+        }
+        .clamp(min, max);
+        self.scrub_cursor = Some(cursor);
+
+        let nearest = exeray_ffi::analysis::events_in_window(
+            &self.events,
+            cursor.saturating_sub(step),
+            cursor.saturating_add(step),
+        )
+        .filter_map(|event| self.index.by_id(event.id))
+        .filter_map(|pos| self.filtered.iter().position(|&row| row == pos))
+        .min_by_key(|&row| self.event(self.filtered[row]).timestamp.abs_diff(cursor));
+        if let Some(row) = nearest {
+            self.selected = row;
+            self.follow_selection();
+        }
+    }
+
+    pub fn search(&self) -> &Search {
+        &self.search
+    }
+
+    pub fn begin_search(&mut self) {
+        self.search.begin();
+        self.refilter(self.selected_id());
+    }
+
+    pub fn search_input(&mut self, c: char) {
+        self.search.push(c);
+        self.refilter(self.selected_id());
+    }
+
+    pub fn search_backspace(&mut self) {
+        self.search.pop();
+        self.refilter(self.selected_id());
+    }
+
+    pub fn commit_search(&mut self) {
+        self.search.commit();
+        self.refilter(self.selected_id());
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search.clear();
+        self.refilter(self.selected_id());
+    }
+
+    /// Note attached to an event.
+    pub fn note(&self, id: u64) -> Option<&str> {
+        self.notes.get(&id).map(String::as_str)
+    }
+
+    /// Note attached to the selected event.
+    pub fn selected_note(&self) -> Option<&str> {
+        self.note(self.selected_id()?)
+    }
+
+    /// Attach a note to an event, replacing any previous one.
+    ///
+    /// Notes key on the event id, so they survive re-sorting and filtering.
+    /// A blank note removes the annotation.
+    pub fn set_note(&mut self, id: u64, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            self.notes.remove(&id);
+        } else {
+            self.notes.insert(id, text.to_string());
+        }
+    }
+
+    /// Event id and text of the note being edited, if the overlay is open.
+    pub fn note_draft(&self) -> Option<(u64, &str)> {
+        self.note_draft
+            .as_ref()
+            .map(|(id, text)| (*id, text.as_str()))
+    }
+
+    /// Open the note overlay for the selected event, prefilled with its
+    /// current note.
+    pub fn begin_note(&mut self) {
+        if let Some(id) = self.selected_id() {
+            let text = self.note(id).unwrap_or_default().to_string();
+            self.note_draft = Some((id, text));
+        }
+    }
+
+    pub fn note_input(&mut self, c: char) {
+        if let Some((_, text)) = &mut self.note_draft {
+            text.push(c);
+        }
+    }
+
+    pub fn note_backspace(&mut self) {
+        if let Some((_, text)) = &mut self.note_draft {
+            text.pop();
+        }
+    }
+
+    /// Close the overlay, saving the draft.
+    pub fn commit_note(&mut self) {
+        if let Some((id, text)) = self.note_draft.take() {
+            self.set_note(id, &text);
+        }
+    }
+
+    /// Close the overlay, discarding the draft.
+    pub fn cancel_note(&mut self) {
+        self.note_draft = None;
+    }
+
+    /// Summary line of the selected event, as copied by `y`.
+    pub fn selected_summary(&self) -> Option<String> {
+        self.visible_events().nth(self.selected).map(Event::summary)
+    }
+
+    /// Copy the selected event's summary to the clipboard and report the
+    /// outcome.
+    pub fn copy_selected(&mut self) {
+        let Some(summary) = self.selected_summary() else {
+            return;
+        };
+        let now = Instant::now();
+        match clipboard::copy_to_clipboard(&summary) {
+            Ok(()) => self.notifications.info(format!("Copied: {summary}"), now),
+            Err(err) => self
+                .notifications
+                .error(format!("Clipboard unavailable: {err}"), now),
+        }
+    }
+
+    /// Write the visible events to [`EXPORT_FILE`] and report the outcome.
+    pub fn export_visible(&mut self) {
+        let events: Vec<Event> = self.visible_events().copied().collect();
+        let result = File::create(EXPORT_FILE).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            exeray_ffi::export::write_jsonl(&events, &mut writer)?;
+            writer.flush()
+        });
+
+        let now = Instant::now();
+        match result {
+            Ok(()) => self.notifications.info(
+                format!("Exported {} events to {EXPORT_FILE}", events.len()),
+                now,
+            ),
+            Err(err) => self
+                .notifications
+                .error(format!("Export to {EXPORT_FILE} failed: {err}"), now),
+        }
+    }
+
+    /// Footer message to show instead of the key help.
+    pub fn notification(&self) -> Option<&Notification> {
+        self.notifications.current()
+    }
+
+    pub fn sort_mode(&self) -> SortMode {
+        self.sort
+    }
+
+    /// Switch to the next sort order, keeping the selected event.
+    pub fn cycle_sort(&mut self) {
+        let keep = self.selected_id();
+        self.sort = self.sort.next();
+        self.refilter(keep);
+    }
+
+    /// Id of the selected event, if any row is selected.
+    fn selected_id(&self) -> Option<u64> {
+        self.visible_events()
+            .nth(self.selected)
+            .map(|event| event.id)
+    }
+
+    /// Recompute the visible rows in sort order.
+    ///
+    /// The selection moves to the row of event `keep` if it is still
+    /// visible, otherwise it stays at the same row. The scrubber cursor is
+    /// clamped to the new time range, or reset if nothing is visible.
+    fn refilter(&mut self, keep: Option<u64>) {
+        let mut filtered: Vec<usize> = (self.evicted..self.evicted + self.events.len())
+            .filter(|&pos| self.shows(self.event(pos)))
+            .collect();
+        filtered.sort_by(|&a, &b| self.sort.compare(self.event(a), self.event(b)));
+        self.filtered = filtered;
+        self.visible_changed(keep);
+    }
+
+    /// Check if the list shows `event` under the current search.
+    ///
+    /// Rows are only rendered for a query to match against.
+    fn shows(&self, event: &Event) -> bool {
+        self.search.is_editing()
+            || self.search.query().is_empty()
+            || self.search.matches(&event_row(event))
+    }
+
+    /// Restore the selection and scrubber cursor after the visible rows
+    /// changed; see [`Self::refilter`].
+    fn visible_changed(&mut self, keep: Option<u64>) {
+        let row = keep.and_then(|id| self.index.by_id(id)).and_then(|pos| {
+            let event = self.event(pos);
+            self.filtered
+                .binary_search_by(|&row| self.sort.compare(self.event(row), event))
+                .ok()
+        });
+        self.selected = search::clamp_selection(row.unwrap_or(self.selected), self.filtered.len());
+        self.follow_selection();
+
+        if self.scrub_cursor.is_some() {
+            let range = self.time_range();
+            self.scrub_cursor = self
+                .scrub_cursor
+                .zip(range)
+                .map(|(cursor, (min, max))| cursor.clamp(min, max));
+        }
+    }
+
+    pub fn time_mode(&self) -> TimeMode {
+        self.time_mode
+    }
+
+    /// Switch the timestamp column between relative and absolute times.
+    pub fn toggle_time_mode(&mut self) {
+        self.time_mode = self.time_mode.toggled();
+    }
+
+    /// Timestamp column of an event's row under the current mode.
+    ///
+    /// Relative times count from the first captured event; before any
+    /// event is cached the event itself is the origin. Absolute times of a
+    /// replay use the clock of the engine that captured it, and fall back
+    /// to relative times for checkpoints that did not record it.
+    pub fn event_time(&self, event: &Event) -> String {
+        let relative = || {
+            timestamps::format_relative(
+                event.timestamp,
+                self.capture_start.unwrap_or(event.timestamp),
+            )
+        };
+        match (self.time_mode, &self.replay) {
+            (TimeMode::Relative, _) => relative(),
+            (TimeMode::Absolute, Some(replay)) => replay
+                .to_unix_nanos(event.timestamp)
+                .map_or_else(relative, timestamps::format_absolute),
+            (TimeMode::Absolute, None) => {
+                timestamps::format_absolute(self.engine.to_unix_nanos(event.timestamp))
+            }
+        }
+    }
+
+    pub fn footer_mode(&self) -> FooterMode {
+        self.footer
+    }
+
+    /// Switch the footer between key help and live counters.
+    pub fn toggle_footer(&mut self) {
+        self.footer = self.footer.toggled();
+    }
+
+    /// Footer line shown in [`FooterMode::Counters`].
+    pub fn counters_line(&self) -> String {
+        format!(
+            "Events: {} | Dropped: {} | Suspicious: {} | Rate: {:.1}/s",
+            self.stats.event_count,
+            self.stats.events_dropped,
+            self.suspicious,
+            self.event_rate.rate()
+        )
+    }
+
+    /// Whether the category breakdown panel is shown.
+    pub fn chart_visible(&self) -> bool {
+        self.show_chart
+    }
+
+    pub fn toggle_chart(&mut self) {
+        self.show_chart = !self.show_chart;
+    }
+
+    /// Event count of every category across the cached events.
+    pub fn category_histogram(&self) -> Vec<(Category, usize)> {
+        Category::ALL.iter().copied().zip(self.categories).collect()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
     }
 
     pub fn state(&self) -> &ViewState {
         &self.state
     }
 
+    pub fn engine_state(&self) -> EngineState {
+        EngineState::from_view(&self.state)
+    }
+
+    /// Record that a frame was just rendered.
+    pub fn frame_rendered(&mut self) {
+        self.frames.record(Instant::now());
+        self.dirty = false;
+    }
+
+    /// Whether the screen is stale and the next loop iteration should draw.
+    ///
+    /// Set by [`App::tick`] when the engine state, event list, selection or
+    /// notification changed, by a resize, and by [`App::mark_dirty`] after
+    /// input; cleared by [`App::frame_rendered`].
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Force a redraw, e.g. after handling a key.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Moving-average render frame rate.
+    pub fn fps(&self) -> f32 {
+        self.frames.fps()
+    }
+
+    /// Progress shown in the gauge.
+    ///
+    /// While a target is monitored, or once it exited, this is the analysis
+    /// progress estimate as of the last tick, otherwise the engine's raw
+    /// task progress.
+    pub fn progress(&self) -> f32 {
+        self.analysis_progress.unwrap_or(self.state.progress)
+    }
+
+    /// Severity of an event according to the current classifier.
+    pub fn severity(&self, event: &Event) -> Severity {
+        self.classifier.classify(event)
+    }
+
+    /// Risk score of the cached events using the default weights and the
+    /// current classifier.
+    pub fn risk_score(&self) -> u32 {
+        u32::try_from(self.risk).unwrap_or(u32::MAX)
+    }
+
+    /// Generations per second over the recent ticks.
+    pub fn generation_rate(&self) -> f32 {
+        self.generations.rate()
+    }
+
     pub fn threads(&self) -> usize {
         self.engine.threads()
     }
+
+    /// Whether the target runs elevated (High or System integrity).
+    pub fn elevated_target(&self) -> bool {
+        self.elevated && self.engine.is_monitoring()
+    }
+
+    /// Whether the target was launched frozen and has not been resumed yet.
+    pub fn frozen_at_entry(&self) -> bool {
+        self.engine.frozen_at_entry()
+    }
 }
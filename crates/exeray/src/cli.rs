@@ -0,0 +1,40 @@
+//! Command-line options.
+
+/// Usage line printed for invalid arguments.
+pub const USAGE: &str = "usage: exeray [--freeze-on-start] [--replay CHECKPOINT] [TARGET]";
+
+/// Options parsed from the command line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    /// Keep the target suspended at its entry point after launch.
+    pub freeze_on_start: bool,
+    /// Executable to launch and monitor on startup.
+    pub target: Option<String>,
+    /// Checkpoint to replay on startup and with the `r` key.
+    pub replay: Option<String>,
+}
+
+impl Options {
+    /// Parse the arguments following the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--freeze-on-start" => options.freeze_on_start = true,
+                "--replay" => match args.next() {
+                    Some(path) => options.replay = Some(path),
+                    None => return Err(format!("--replay needs a checkpoint path\n{USAGE}")),
+                },
+                flag if flag.starts_with("--") => {
+                    return Err(format!("unknown option {flag}\n{USAGE}"));
+                }
+                _ if options.target.is_some() => {
+                    return Err(format!("unexpected argument {arg}\n{USAGE}"));
+                }
+                _ => options.target = Some(arg),
+            }
+        }
+        Ok(options)
+    }
+}
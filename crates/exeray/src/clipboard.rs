@@ -0,0 +1,21 @@
+//! System clipboard access for the `y` key.
+
+use anyhow::Result;
+
+/// Copy `text` to the system clipboard.
+///
+/// Fails when no clipboard is reachable (e.g. a headless session) or when
+/// the binary was built without the `clipboard` feature.
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    arboard::Clipboard::new()?.set_text(text)?;
+    Ok(())
+}
+
+/// Copy `text` to the system clipboard.
+///
+/// Always fails: the binary was built without the `clipboard` feature.
+#[cfg(not(feature = "clipboard"))]
+pub fn copy_to_clipboard(_text: &str) -> Result<()> {
+    anyhow::bail!("built without clipboard support")
+}
@@ -0,0 +1,254 @@
+//! Rebindable key bindings loaded from `keys.toml`.
+
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Key binding file looked up next to the binary.
+pub const KEYMAP_FILE: &str = "keys.toml";
+
+/// Keys with a fixed meaning in the run loop, which no action can take.
+///
+/// Keep in sync with the fallback key match in `main.rs`.
+pub const RESERVED_KEYS: [KeyCode; 16] = [
+    KeyCode::Char('e'),
+    KeyCode::Char('s'),
+    KeyCode::Char('y'),
+    KeyCode::Char('i'),
+    KeyCode::Char('c'),
+    KeyCode::Char('g'),
+    KeyCode::Char('n'),
+    KeyCode::Char('t'),
+    KeyCode::Char('r'),
+    KeyCode::Char('p'),
+    KeyCode::Char('x'),
+    KeyCode::Esc,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+];
+
+/// Rebindable actions of the run loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Start,
+    Freeze,
+    Kill,
+    Search,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 5] = [
+        Action::Start,
+        Action::Freeze,
+        Action::Kill,
+        Action::Search,
+        Action::Quit,
+    ];
+
+    /// Name used as the key in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Start => "start",
+            Action::Freeze => "freeze",
+            Action::Kill => "kill",
+            Action::Search => "search",
+            Action::Quit => "quit",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.name().eq_ignore_ascii_case(name))
+    }
+
+    fn default_key(self) -> KeyCode {
+        match self {
+            Action::Start => KeyCode::Char(' '),
+            Action::Freeze => KeyCode::Char('f'),
+            Action::Kill => KeyCode::Char('k'),
+            Action::Search => KeyCode::Char('/'),
+            Action::Quit => KeyCode::Char('q'),
+        }
+    }
+}
+
+/// Error loading a [`KeyMap`].
+#[derive(Debug)]
+pub enum KeyMapError {
+    Parse(toml::de::Error),
+    UnknownAction(String),
+    InvalidKey {
+        action: Action,
+        key: String,
+    },
+    Conflict {
+        key: String,
+        first: Action,
+        second: Action,
+    },
+    Reserved {
+        action: Action,
+        key: String,
+    },
+}
+
+impl fmt::Display for KeyMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyMapError::Parse(err) => write!(f, "{err}"),
+            KeyMapError::UnknownAction(name) => write!(f, "unknown action \"{name}\""),
+            KeyMapError::InvalidKey { action, key } => {
+                write!(f, "invalid key \"{key}\" for {}", action.name())
+            }
+            KeyMapError::Conflict { key, first, second } => write!(
+                f,
+                "{key} is bound to both {} and {}",
+                first.name(),
+                second.name()
+            ),
+            KeyMapError::Reserved { action, key } => {
+                write!(
+                    f,
+                    "{key} is reserved and cannot be bound to {}",
+                    action.name()
+                )
+            }
+        }
+    }
+}
+
+/// Key bound to each [`Action`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyMap {
+    keys: [KeyCode; Action::ALL.len()],
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            keys: Action::ALL.map(Action::default_key),
+        }
+    }
+}
+
+impl KeyMap {
+    /// Key bound to `action`.
+    pub fn key(&self, action: Action) -> KeyCode {
+        self.keys[action as usize]
+    }
+
+    /// Action bound to `key`, if any.
+    pub fn action(&self, key: KeyCode) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|&action| self.key(action) == key)
+    }
+
+    /// Help label of the key bound to `action`.
+    pub fn label(&self, action: Action) -> String {
+        key_label(self.key(action))
+    }
+
+    /// Parse a key map from a TOML table of action names to key names.
+    ///
+    /// Missing actions keep their default key. Keys are a single character
+    /// or one of `space`, `enter`, `esc`, `tab`, `backspace`, `up`, `down`,
+    /// `left`, `right`, `f1`-`f12`. Two actions on one key, or an action on
+    /// one of the [`RESERVED_KEYS`], are rejected.
+    pub fn from_toml(text: &str) -> Result<Self, KeyMapError> {
+        let entries: HashMap<String, String> = toml::from_str(text).map_err(KeyMapError::Parse)?;
+        let mut map = Self::default();
+
+        for (name, key) in entries {
+            let action =
+                Action::from_name(&name).ok_or_else(|| KeyMapError::UnknownAction(name.clone()))?;
+            map.keys[action as usize] =
+                parse_key(&key).ok_or(KeyMapError::InvalidKey { action, key })?;
+        }
+
+        if let Some(action) = Action::ALL
+            .into_iter()
+            .find(|&action| RESERVED_KEYS.contains(&map.key(action)))
+        {
+            return Err(KeyMapError::Reserved {
+                action,
+                key: map.label(action),
+            });
+        }
+
+        for (i, &first) in Action::ALL.iter().enumerate() {
+            if let Some(&second) = Action::ALL[i + 1..]
+                .iter()
+                .find(|&&other| map.key(other) == map.key(first))
+            {
+                return Err(KeyMapError::Conflict {
+                    key: map.label(first),
+                    first,
+                    second,
+                });
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Load a key map file, falling back to defaults if it is absent.
+    ///
+    /// Invalid files also fall back to defaults and are returned as a warning.
+    pub fn load(path: &Path) -> (Self, Vec<String>) {
+        let Ok(text) = std::fs::read_to_string(path) else {
+            return (Self::default(), Vec::new());
+        };
+        match Self::from_toml(&text) {
+            Ok(map) => (map, Vec::new()),
+            Err(err) => (
+                Self::default(),
+                vec![format!("keys: ignoring {}: {err}", path.display())],
+            ),
+        }
+    }
+}
+
+/// Parse a key name from the config file.
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let lower = name.to_ascii_lowercase();
+    let key = match lower.as_str() {
+        "space" => KeyCode::Char(' '),
+        "enter" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        _ => {
+            let n: u8 = lower.strip_prefix('f')?.parse().ok()?;
+            return (1..=12).contains(&n).then_some(KeyCode::F(n));
+        }
+    };
+    Some(key)
+}
+
+/// Short key name as shown in the help line.
+fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_uppercase().to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        other => format!("{other:?}"),
+    }
+}
@@ -1,44 +1,164 @@
 mod app;
+mod cli;
+mod clipboard;
+mod keymap;
+mod notification;
+mod scrubber;
+mod search;
+mod sort;
+mod tests;
+mod theme;
+mod timestamps;
 mod ui;
 
+use crate::keymap::Action;
 use anyhow::Result;
 use crossterm::{
     ExecutableCommand,
     event::{self, Event, KeyCode, KeyEventKind},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use exeray_ffi::Engine;
 use ratatui::prelude::*;
 use std::io::stdout;
+use std::path::Path;
 use std::time::Duration;
 
 fn main() -> Result<()> {
+    let options = cli::Options::parse(std::env::args().skip(1)).map_err(anyhow::Error::msg)?;
+    let theme = load_theme();
+    let keys = load_keymap();
+
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
 
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
-    let result = run(&mut terminal);
+    let result = run(&mut terminal, &theme, &keys, &options);
 
     disable_raw_mode()?;
     stdout().execute(LeaveAlternateScreen)?;
     result
 }
 
-fn run<B: Backend>(terminal: &mut Terminal<B>) -> Result<()> {
-    let mut app = app::App::new(64, 0);
+/// Load `theme.json` from next to the binary, printing any warnings.
+fn load_theme() -> theme::CategoryTheme {
+    let Ok(exe) = std::env::current_exe() else {
+        return theme::CategoryTheme::default();
+    };
+    let (theme, warnings) = theme::CategoryTheme::load(&exe.with_file_name(theme::THEME_FILE));
+    for warning in warnings {
+        eprintln!("{warning}");
+    }
+    theme
+}
+
+/// Load `keys.toml` from next to the binary, printing any warnings.
+fn load_keymap() -> keymap::KeyMap {
+    let Ok(exe) = std::env::current_exe() else {
+        return keymap::KeyMap::default();
+    };
+    let (keys, warnings) = keymap::KeyMap::load(&exe.with_file_name(keymap::KEYMAP_FILE));
+    for warning in warnings {
+        eprintln!("{warning}");
+    }
+    keys
+}
+
+fn run<B: Backend>(
+    terminal: &mut Terminal<B>,
+    theme: &theme::CategoryTheme,
+    keys: &keymap::KeyMap,
+    options: &cli::Options,
+) -> Result<()> {
+    let engine = Engine::builder()
+        .freeze_on_start(options.freeze_on_start)
+        .build();
+    let mut app = app::App::with_engine(engine);
+    if let Some(target) = &options.target {
+        app.monitor(target);
+    }
+    let replay_file = Path::new(options.replay.as_deref().unwrap_or(app::REPLAY_FILE));
+    if options.replay.is_some() {
+        app.load_replay(replay_file);
+    }
+    app.resize(ui::event_rows(terminal.size()?.height));
 
     loop {
-        terminal.draw(|f| ui::render(&app, f))?;
-
-        if event::poll(Duration::from_millis(16))?
-            && let Event::Key(key) = event::read()?
-            && key.kind == KeyEventKind::Press
-        {
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Char(' ') => app.start(),
+        if app.is_dirty() {
+            terminal.draw(|f| ui::render(&app, theme, keys, f))?;
+            app.frame_rendered();
+        }
+
+        if event::poll(Duration::from_millis(16))? {
+            match event::read()? {
+                Event::Resize(_, height) => {
+                    // Recompute the layout against the new size before the next draw
+                    terminal.autoresize()?;
+                    app.resize(ui::event_rows(height));
+                }
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    app.mark_dirty();
+                    if app.quit_state() == app::QuitState::Confirming {
+                        match key.code {
+                            KeyCode::Char('y' | 'Y') => app.answer_quit(true),
+                            KeyCode::Char('n' | 'N') | KeyCode::Esc => app.answer_quit(false),
+                            _ => {}
+                        }
+                    } else if app.note_draft().is_some() {
+                        match key.code {
+                            KeyCode::Esc => app.cancel_note(),
+                            KeyCode::Enter => app.commit_note(),
+                            KeyCode::Backspace => app.note_backspace(),
+                            KeyCode::Char(c) => app.note_input(c),
+                            _ => {}
+                        }
+                    } else if app.search().is_editing() {
+                        match key.code {
+                            KeyCode::Esc => app.clear_search(),
+                            KeyCode::Enter => app.commit_search(),
+                            KeyCode::Backspace => app.search_backspace(),
+                            KeyCode::Char(c) => app.search_input(c),
+                            _ => {}
+                        }
+                    } else if let Some(action) = keys.action(key.code) {
+                        match action {
+                            Action::Start => app.start(),
+                            Action::Freeze => app.toggle_freeze(),
+                            Action::Kill => app.kill(),
+                            Action::Search => app.begin_search(),
+                            Action::Quit => app.request_quit(),
+                        }
+                    } else {
+                        // Fixed keys; keep keymap::RESERVED_KEYS in sync
+                        match key.code {
+                            KeyCode::Esc if !app.search().query().is_empty() => app.clear_search(),
+                            KeyCode::Esc if app.replay().is_some() => app.stop_replay(),
+                            KeyCode::Esc => app.request_quit(),
+                            KeyCode::Char('e') => app.export_visible(),
+                            KeyCode::Char('s') => app.cycle_sort(),
+                            KeyCode::Char('y') => app.copy_selected(),
+                            KeyCode::Char('i') => app.toggle_footer(),
+                            KeyCode::Char('c') => app.toggle_chart(),
+                            KeyCode::Char('g') => app.toggle_follow(),
+                            KeyCode::Char('n') => app.begin_note(),
+                            KeyCode::Char('t') => app.toggle_time_mode(),
+                            KeyCode::Char('r') => app.load_replay(replay_file),
+                            KeyCode::Char('p') => app.toggle_replay_play(),
+                            KeyCode::Char('x') => app.cycle_replay_speed(),
+                            KeyCode::Down => app.select_next(),
+                            KeyCode::Up => app.select_prev(),
+                            KeyCode::Left => app.scrub_left(),
+                            KeyCode::Right => app.scrub_right(),
+                            _ => {}
+                        }
+                    }
+                }
                 _ => {}
             }
         }
+        if app.quit_state() == app::QuitState::Quit {
+            break;
+        }
 
         app.tick();
     }
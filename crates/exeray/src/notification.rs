@@ -0,0 +1,56 @@
+//! Transient footer messages.
+
+use std::time::{Duration, Instant};
+
+/// How long a notification stays in the footer.
+pub const NOTIFICATION_TTL: Duration = Duration::from_secs(3);
+
+/// A footer message shown for [`NOTIFICATION_TTL`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub message: String,
+    pub is_error: bool,
+    shown_at: Instant,
+}
+
+/// The current footer message, if any.
+#[derive(Debug, Default)]
+pub struct Notifications {
+    current: Option<Notification>,
+}
+
+impl Notifications {
+    /// Show an informational message, replacing any current one.
+    pub fn info(&mut self, message: impl Into<String>, now: Instant) {
+        self.show(message.into(), false, now);
+    }
+
+    /// Show an error message, replacing any current one.
+    pub fn error(&mut self, message: impl Into<String>, now: Instant) {
+        self.show(message.into(), true, now);
+    }
+
+    fn show(&mut self, message: String, is_error: bool, now: Instant) {
+        self.current = Some(Notification {
+            message,
+            is_error,
+            shown_at: now,
+        });
+    }
+
+    /// Drop the message once it has been shown for the full TTL.
+    pub fn expire(&mut self, now: Instant) {
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|n| now.saturating_duration_since(n.shown_at) >= NOTIFICATION_TTL)
+        {
+            self.current = None;
+        }
+    }
+
+    /// The message to display, if any.
+    pub fn current(&self) -> Option<&Notification> {
+        self.current.as_ref()
+    }
+}
@@ -0,0 +1,25 @@
+//! Timeline scrubber over the capture duration.
+
+/// Number of cursor steps across the full capture.
+pub const SCRUB_STEPS: u64 = 100;
+
+/// Distance the cursor moves per key press for a capture spanning
+/// `[min_ns, max_ns]` (at least 1 ns).
+pub fn step_ns(min_ns: u64, max_ns: u64) -> u64 {
+    (max_ns.saturating_sub(min_ns) / SCRUB_STEPS).max(1)
+}
+
+/// Map a timestamp to a column of a `width`-column bar spanning
+/// `[min_ns, max_ns]`.
+///
+/// Timestamps outside the span are clamped to the first or last column.
+/// A zero-length span maps everything to column 0.
+pub fn timestamp_to_column(ts: u64, min_ns: u64, max_ns: u64, width: u16) -> u16 {
+    if width == 0 || max_ns <= min_ns {
+        return 0;
+    }
+    let span = (max_ns - min_ns) as u128;
+    let offset = ts.clamp(min_ns, max_ns) - min_ns;
+    let last = (width - 1) as u128;
+    ((offset as u128 * last + span / 2) / span) as u16
+}
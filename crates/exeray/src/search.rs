@@ -0,0 +1,97 @@
+//! Event list search box state.
+
+use std::ops::Range;
+
+/// Text search over the rendered event rows.
+///
+/// `/` starts editing, typed characters extend the query, Enter commits it
+/// and Escape clears it. While editing, matches are only highlighted; the
+/// committed query filters the list.
+#[derive(Debug, Default)]
+pub struct Search {
+    query: String,
+    editing: bool,
+}
+
+impl Search {
+    /// Start editing the query.
+    pub fn begin(&mut self) {
+        self.editing = true;
+    }
+
+    /// Append a character to the query.
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+    }
+
+    /// Remove the last character of the query.
+    pub fn pop(&mut self) {
+        self.query.pop();
+    }
+
+    /// Stop editing and keep the query applied.
+    pub fn commit(&mut self) {
+        self.editing = false;
+    }
+
+    /// Stop editing and drop the query.
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+    }
+
+    /// Check if the search box is receiving input.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Current query text.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Check if a rendered row matches the query.
+    pub fn matches(&self, row: &str) -> bool {
+        matches(row, &self.query)
+    }
+}
+
+/// Case-insensitive substring match; an empty query matches everything.
+pub fn matches(row: &str, query: &str) -> bool {
+    query.is_empty() || row.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// Byte ranges of the non-overlapping, case-insensitive occurrences of
+/// `query` in `row`, left to right. Empty for an empty query.
+pub fn match_ranges(row: &str, query: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    if query.is_empty() {
+        return ranges;
+    }
+    let mut from = 0;
+    while let Some((start, end)) = row[from..]
+        .char_indices()
+        .find_map(|(i, _)| match_at(&row[from + i..], query).map(|len| (from + i, from + i + len)))
+    {
+        ranges.push(start..end);
+        from = end;
+    }
+    ranges
+}
+
+/// Length in bytes of the prefix of `text` equal to `query`, ignoring case.
+fn match_at(text: &str, query: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for q in query.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(q.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(i, _)| i))
+}
+
+/// Clamp a selection index into a list of `len` rows.
+pub fn clamp_selection(selected: usize, len: usize) -> usize {
+    selected.min(len.saturating_sub(1))
+}
@@ -0,0 +1,47 @@
+//! Event list sort order.
+
+use exeray_ffi::Event;
+use std::cmp::Ordering;
+
+/// Order of the rows in the event list, cycled with `s`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortMode {
+    #[default]
+    Time,
+    Category,
+    Status,
+}
+
+impl SortMode {
+    /// Mode the `s` key switches to.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Time => Self::Category,
+            Self::Category => Self::Status,
+            Self::Status => Self::Time,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Time => "time",
+            Self::Category => "category",
+            Self::Status => "status",
+        }
+    }
+
+    /// Compare two events under this mode.
+    ///
+    /// Ties fall back to timestamp, then id, so the order is total and
+    /// events within a category or status stay chronological.
+    pub fn compare(self, a: &Event, b: &Event) -> Ordering {
+        let primary = match self {
+            Self::Time => Ordering::Equal,
+            Self::Category => a.category.repr.cmp(&b.category.repr),
+            Self::Status => a.status.repr.cmp(&b.status.repr),
+        };
+        primary
+            .then(a.timestamp.cmp(&b.timestamp))
+            .then(a.id.cmp(&b.id))
+    }
+}
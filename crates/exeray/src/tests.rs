@@ -0,0 +1,982 @@
+//! Unit tests for the ExeRay TUI.
+
+#![cfg(test)]
+
+use crate::app::{
+    App, EngineState, EventRateTracker, FooterMode, FrameStats, GenerationTracker, QuitState,
+    REPLAY_SPEEDS, ReplayController, clamp_scroll, risk_color,
+};
+use crate::cli::Options;
+use crate::notification::{NOTIFICATION_TTL, Notifications};
+use crate::scrubber::{self, timestamp_to_column};
+use crate::search::{self, Search};
+use crate::sort::SortMode;
+use crate::theme::CategoryTheme;
+use crate::timestamps::{TimeMode, format_absolute, format_relative};
+use crate::ui::{category_bars, highlight_row};
+use exeray_ffi::{Category, Engine, Event, EventAttributes, EventSnapshot, Status, ViewState};
+use ratatui::style::Color;
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_fps_first_frame_is_zero() {
+    let mut stats = FrameStats::default();
+    assert_eq!(stats.fps(), 0.0);
+    stats.record(Instant::now());
+    assert_eq!(stats.fps(), 0.0);
+}
+
+#[test]
+fn test_fps_moving_average() {
+    let mut stats = FrameStats::default();
+    let start = Instant::now();
+
+    // 10 frames at 20ms, then 10 frames at 30ms -> average 25ms -> 40 FPS
+    let mut now = start;
+    stats.record(now);
+    for i in 0..20 {
+        now += Duration::from_millis(if i < 10 { 20 } else { 30 });
+        stats.record(now);
+    }
+    assert!((stats.fps() - 40.0).abs() < 0.01);
+
+    // Window slides: 30 more 10ms frames push out the older intervals
+    for _ in 0..30 {
+        now += Duration::from_millis(10);
+        stats.record(now);
+    }
+    assert!((stats.fps() - 100.0).abs() < 0.01);
+}
+
+#[test]
+fn test_search_matches_case_insensitive_substring() {
+    assert!(search::matches("#1 Network(Connect) [Success]", "network"));
+    assert!(search::matches("#1 Network(Connect) [Success]", "CONN"));
+    assert!(!search::matches(
+        "#1 Network(Connect) [Success]",
+        "registry"
+    ));
+    assert!(search::matches("anything", ""));
+}
+
+#[test]
+fn test_search_state_transitions() {
+    let mut search = Search::default();
+    search.begin();
+    search.push('n');
+    search.push('e');
+    assert!(search.is_editing());
+    assert_eq!(search.query(), "ne");
+
+    search.commit();
+    assert!(!search.is_editing());
+    assert!(search.matches("Network"));
+    assert!(!search.matches("File"));
+
+    search.clear();
+    assert_eq!(search.query(), "");
+    assert!(search.matches("File"));
+}
+
+#[test]
+fn test_selection_clamping() {
+    assert_eq!(search::clamp_selection(5, 3), 2);
+    assert_eq!(search::clamp_selection(1, 3), 1);
+    assert_eq!(search::clamp_selection(4, 0), 0);
+}
+
+#[test]
+fn test_theme_from_json() {
+    let json = r##"{
+        "Process": "#ff8000",
+        "network": "#00FF00",
+        "Bogus": "#ffffff",
+        "Registry": "red"
+    }"##;
+    let (theme, warnings) = CategoryTheme::from_json(json).unwrap();
+
+    assert_eq!(theme.color(Category::Process), Color::Rgb(0xff, 0x80, 0x00));
+    assert_eq!(theme.color(Category::Network), Color::Rgb(0x00, 0xff, 0x00));
+    // Invalid color and missing entries keep the defaults
+    let defaults = CategoryTheme::default();
+    assert_eq!(
+        theme.color(Category::Registry),
+        defaults.color(Category::Registry)
+    );
+    assert_eq!(theme.color(Category::Dns), defaults.color(Category::Dns));
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings.iter().any(|w| w.contains("Bogus")));
+}
+
+#[test]
+fn test_theme_rejects_non_object() {
+    assert!(CategoryTheme::from_json("[1, 2]").is_err());
+}
+
+#[test]
+fn test_notification_transitions() {
+    let mut notifications = Notifications::default();
+    let start = Instant::now();
+    assert!(notifications.current().is_none());
+
+    notifications.info("Exported 3 events", start);
+    let current = notifications.current().unwrap();
+    assert_eq!(current.message, "Exported 3 events");
+    assert!(!current.is_error);
+
+    // Still visible just before the TTL
+    notifications.expire(start + NOTIFICATION_TTL - Duration::from_millis(1));
+    assert!(notifications.current().is_some());
+
+    // An error replaces the message and restarts the TTL
+    let later = start + Duration::from_secs(1);
+    notifications.error("Export failed", later);
+    notifications.expire(start + NOTIFICATION_TTL);
+    assert!(notifications.current().unwrap().is_error);
+
+    notifications.expire(later + NOTIFICATION_TTL);
+    assert!(notifications.current().is_none());
+}
+
+#[test]
+fn test_engine_state_colors() {
+    let resolve = |flags| {
+        EngineState::from_view(&ViewState {
+            generation: 0,
+            timestamp_ns: 0,
+            flags,
+            progress: 0.0,
+        })
+    };
+
+    assert_eq!(resolve(ViewState::IDLE).color(), Color::DarkGray);
+    assert_eq!(resolve(ViewState::PENDING).color(), Color::Yellow);
+    assert_eq!(resolve(ViewState::READY).color(), Color::Blue);
+    assert_eq!(resolve(ViewState::COMPLETE).color(), Color::Green);
+    assert_eq!(resolve(ViewState::ERROR).color(), Color::Red);
+    // Errors win over completion
+    assert_eq!(
+        resolve(ViewState::COMPLETE | ViewState::ERROR),
+        EngineState::Error
+    );
+}
+
+#[test]
+fn test_timestamp_to_column_mapping() {
+    // Endpoints map to the first and last column
+    assert_eq!(timestamp_to_column(1_000, 1_000, 2_000, 11), 0);
+    assert_eq!(timestamp_to_column(2_000, 1_000, 2_000, 11), 10);
+    assert_eq!(timestamp_to_column(1_500, 1_000, 2_000, 11), 5);
+
+    // Out-of-range timestamps are clamped
+    assert_eq!(timestamp_to_column(0, 1_000, 2_000, 11), 0);
+    assert_eq!(timestamp_to_column(9_000, 1_000, 2_000, 11), 10);
+
+    // Degenerate span and width
+    assert_eq!(timestamp_to_column(1_000, 1_000, 1_000, 11), 0);
+    assert_eq!(timestamp_to_column(1_500, 1_000, 2_000, 0), 0);
+
+    // Large spans do not overflow
+    assert_eq!(timestamp_to_column(u64::MAX, 0, u64::MAX, 80), 79);
+}
+
+#[test]
+fn test_scrub_step_at_least_one() {
+    assert_eq!(scrubber::step_ns(5, 5), 1);
+    assert_eq!(scrubber::step_ns(0, 1_000), 1_000 / scrubber::SCRUB_STEPS);
+}
+
+#[test]
+fn test_filter_clamps_scrub_cursor() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+
+    // Park the cursor on the last event, then filter it out of range
+    for _ in 0..=2 * scrubber::SCRUB_STEPS {
+        app.scrub_right();
+    }
+    let (_, last) = app.time_range().unwrap();
+    assert_eq!(app.scrub_cursor(), Some(last));
+
+    app.begin_search();
+    for c in "network".chars() {
+        app.search_input(c);
+    }
+    app.commit_search();
+    let (min, max) = app.time_range().unwrap();
+    let cursor = app.scrub_cursor().unwrap();
+    assert!((min..=max).contains(&cursor));
+
+    // Nothing visible: the cursor resets
+    app.begin_search();
+    app.search_input('!');
+    app.commit_search();
+    assert_eq!(app.time_range(), None);
+    assert_eq!(app.scrub_cursor(), None);
+}
+
+#[test]
+fn test_risk_color_bands() {
+    assert_eq!(risk_color(0), Color::Green);
+    assert_eq!(risk_color(19), Color::Green);
+    assert_eq!(risk_color(20), Color::Yellow);
+    assert_eq!(risk_color(49), Color::Yellow);
+    assert_eq!(risk_color(50), Color::Red);
+}
+
+#[test]
+fn test_shrunk_viewport_clamps_scroll() {
+    // Selection near the bottom of a tall viewport stays put
+    assert_eq!(clamp_scroll(40, 45, 50, 10), 40);
+
+    // Shrinking the viewport scrolls so the selection stays visible
+    assert_eq!(clamp_scroll(40, 45, 50, 3), 43);
+
+    // Growing past the end pulls the offset back to the last full page
+    assert_eq!(clamp_scroll(40, 45, 50, 20), 30);
+    assert_eq!(clamp_scroll(40, 0, 5, 10), 0);
+
+    // A zero-height viewport is treated as one row
+    assert_eq!(clamp_scroll(0, 7, 50, 0), 7);
+}
+
+#[test]
+fn test_sort_mode_cycles() {
+    let mut mode = SortMode::default();
+    assert_eq!(mode, SortMode::Time);
+    mode = mode.next();
+    assert_eq!(mode, SortMode::Category);
+    mode = mode.next();
+    assert_eq!(mode, SortMode::Status);
+    assert_eq!(mode.next(), SortMode::Time);
+}
+
+#[test]
+fn test_sort_mode_comparators() {
+    let event = |id, timestamp, category, status| Event {
+        id,
+        parent_id: 0,
+        timestamp,
+        category,
+        status,
+        operation: 0,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    };
+    let a = event(1, 300, Category::Network, Status::Success);
+    let b = event(2, 100, Category::Registry, Status::Suspicious);
+    let c = event(3, 200, Category::Registry, Status::Success);
+
+    let sorted = |mode: SortMode| {
+        let mut events = [a, b, c];
+        events.sort_by(|x, y| mode.compare(x, y));
+        events.map(|e| e.id)
+    };
+    assert_eq!(sorted(SortMode::Time), [2, 3, 1]);
+    // Registry before Network, chronological within a category
+    assert_eq!(sorted(SortMode::Category), [2, 3, 1]);
+    assert_eq!(sorted(SortMode::Status), [3, 1, 2]);
+
+    // Equal timestamps fall back to id
+    let d = event(4, 300, Category::Network, Status::Success);
+    assert_eq!(SortMode::Time.compare(&a, &d), Ordering::Less);
+}
+
+#[test]
+fn test_selected_summary_follows_selection() {
+    let mut engine = Engine::new(16, 1);
+    let root = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    engine.inject_event(Category::Network, 0, Status::Suspicious, root);
+
+    let mut app = App::with_engine(engine);
+    assert_eq!(app.selected_summary(), None);
+
+    app.tick();
+    let first = app.selected_summary().unwrap();
+    assert!(first.starts_with("#1 Process/"));
+
+    app.select_next();
+    let second = app.selected_summary().unwrap();
+    assert!(second.starts_with("#2 ◂#1 Network/Connect [Suspicious]"));
+}
+
+#[test]
+fn test_event_rate_counts_evicted_events() {
+    let mut tracker = EventRateTracker::default();
+    let start = Instant::now();
+    assert_eq!(tracker.rate(), 0.0);
+
+    // A full ring keeps the live count flat while the committed total
+    // grows by 20 per 100ms tick -> 200/s
+    for i in 0..=10 {
+        tracker.record(start + Duration::from_millis(100 * i), 1_000 + 20 * i);
+    }
+    assert!((tracker.rate() - 200.0).abs() < 0.01);
+
+    // Clearing the events restarts the window instead of going negative
+    tracker.record(start + Duration::from_millis(1_100), 0);
+    assert_eq!(tracker.rate(), 0.0);
+    tracker.record(start + Duration::from_millis(1_200), 10);
+    assert!((tracker.rate() - 100.0).abs() < 0.01);
+}
+
+#[test]
+fn test_generation_rate() {
+    let mut tracker = GenerationTracker::default();
+    let start = Instant::now();
+    assert_eq!(tracker.rate(), 0.0);
+    tracker.record(start, 10);
+    assert_eq!(tracker.rate(), 0.0);
+
+    // 5 generations per 100ms tick -> 50/s
+    for i in 1..=10 {
+        tracker.record(start + Duration::from_millis(100 * i), 10 + 5 * i);
+    }
+    assert!((tracker.rate() - 50.0).abs() < 0.01);
+
+    // Once the window slides past the busy period, a stalled engine reads 0
+    let stalled = start + Duration::from_secs(1);
+    for i in 1..=32 {
+        tracker.record(stalled + Duration::from_millis(100 * i), 60);
+    }
+    assert_eq!(tracker.rate(), 0.0);
+}
+
+#[test]
+fn test_footer_mode_toggle() {
+    assert_eq!(FooterMode::default(), FooterMode::Help);
+    assert_eq!(FooterMode::Help.toggled(), FooterMode::Counters);
+    assert_eq!(FooterMode::Counters.toggled(), FooterMode::Help);
+
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Network, 0, Status::Suspicious, 0);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+
+    assert_eq!(app.footer_mode(), FooterMode::Help);
+    app.toggle_footer();
+    assert_eq!(app.footer_mode(), FooterMode::Counters);
+    assert!(
+        app.counters_line()
+            .starts_with("Events: 2 | Dropped: 0 | Suspicious: 1 | Rate: ")
+    );
+    app.toggle_footer();
+    assert_eq!(app.footer_mode(), FooterMode::Help);
+}
+
+#[test]
+fn test_category_bars_from_histogram() {
+    let events: Vec<Event> = [Category::Network, Category::FileSystem, Category::Network]
+        .into_iter()
+        .enumerate()
+        .map(|(i, category)| Event {
+            id: i as u64 + 1,
+            parent_id: 0,
+            timestamp: 0,
+            category,
+            status: Status::Success,
+            operation: 0,
+            target_pid: 0,
+            attributes: EventAttributes::empty(),
+        })
+        .collect();
+    let histogram = exeray_ffi::analysis::category_histogram(&events);
+    assert_eq!(histogram.len(), Category::ALL.len());
+    assert_eq!(
+        category_bars(&histogram),
+        vec![(Category::FileSystem, 1), (Category::Network, 2)]
+    );
+
+    // No events: an empty chart, not a row of zero bars
+    let empty = exeray_ffi::analysis::category_histogram(&[]);
+    assert!(empty.iter().all(|&(_, count)| count == 0));
+    assert!(category_bars(&empty).is_empty());
+}
+
+#[test]
+fn test_follow_mode_tracks_newest() {
+    let mut engine = Engine::new(16, 1);
+    for _ in 0..5 {
+        engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+    }
+    let mut app = App::with_engine(engine);
+    app.resize(3);
+    app.tick();
+    assert!(!app.following());
+    assert_eq!(app.selected(), 0);
+
+    // Off: new events leave the selection pinned
+    app.engine_mut()
+        .inject_event(Category::Network, 0, Status::Success, 0);
+    app.tick();
+    assert_eq!(app.selected(), 0);
+    assert_eq!(app.scroll_offset(), 0);
+
+    // Turning follow on from the top jumps to the bottom
+    app.toggle_follow();
+    assert!(app.following());
+    assert_eq!(app.selected(), 5);
+    assert_eq!(app.scroll_offset(), 3);
+
+    // On: the selection keeps up with arriving events
+    app.engine_mut()
+        .inject_event(Category::Network, 0, Status::Success, 0);
+    app.tick();
+    assert_eq!(app.selected(), 6);
+    assert_eq!(app.scroll_offset(), 4);
+
+    // Scrolling up leaves follow mode and pins the view again
+    app.select_prev();
+    assert!(!app.following());
+    app.engine_mut()
+        .inject_event(Category::Network, 0, Status::Success, 0);
+    app.tick();
+    assert_eq!(app.selected(), 5);
+}
+
+#[test]
+fn test_highlight_spans() {
+    use ratatui::style::{Modifier, Style};
+    use ratatui::text::Span;
+
+    let base = Style::default().fg(Color::Cyan);
+    let matched = base.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let row = "#3 Network/Connect [Success] @net";
+
+    assert_eq!(search::match_ranges(row, "NET"), vec![3..6, 30..33]);
+    assert_eq!(
+        highlight_row(row, "net", base).spans,
+        vec![
+            Span::styled("#3 ", base),
+            Span::styled("Net", matched),
+            Span::styled("work/Connect [Success] @", base),
+            Span::styled("net", matched),
+        ]
+    );
+
+    // No query: one plain span; no match: dimmed
+    assert_eq!(
+        highlight_row(row, "", base).spans,
+        vec![Span::styled(row, base)]
+    );
+    assert_eq!(
+        highlight_row(row, "registry", base).spans,
+        vec![Span::styled(row, base.add_modifier(Modifier::DIM))]
+    );
+}
+
+#[test]
+fn test_editing_search_highlights_without_filtering() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+
+    app.begin_search();
+    for c in "network".chars() {
+        app.search_input(c);
+    }
+    assert_eq!(app.visible_events().count(), 2);
+
+    app.commit_search();
+    assert_eq!(app.visible_events().count(), 1);
+}
+
+#[test]
+fn test_keymap_from_toml() {
+    use crate::keymap::{Action, KeyMap, KeyMapError};
+    use crossterm::event::KeyCode;
+
+    let keys = KeyMap::from_toml("quit = \"z\"\nfreeze = \"F5\"\nstart = \"enter\"\n").unwrap();
+    assert_eq!(keys.action(KeyCode::Char('z')), Some(Action::Quit));
+    assert_eq!(keys.action(KeyCode::F(5)), Some(Action::Freeze));
+    assert_eq!(keys.action(KeyCode::Enter), Some(Action::Start));
+    // Unlisted actions keep their defaults; unbound keys map to nothing
+    assert_eq!(keys.key(Action::Search), KeyCode::Char('/'));
+    assert_eq!(keys.action(KeyCode::Char('q')), None);
+    assert_eq!(KeyMap::default().label(Action::Start), "Space");
+
+    // Kill moved onto the default search key
+    let err = KeyMap::from_toml("kill = \"/\"").unwrap_err();
+    assert!(matches!(
+        err,
+        KeyMapError::Conflict {
+            first: Action::Kill,
+            second: Action::Search,
+            ..
+        }
+    ));
+    // Keys hard-coded in the run loop cannot be taken
+    for text in ["quit = \"x\"", "search = \"esc\"", "kill = \"up\""] {
+        assert!(matches!(
+            KeyMap::from_toml(text),
+            Err(KeyMapError::Reserved { .. })
+        ));
+    }
+    assert!(matches!(
+        KeyMap::from_toml("explode = \"x\""),
+        Err(KeyMapError::UnknownAction(_))
+    ));
+    assert!(matches!(
+        KeyMap::from_toml("kill = \"ctrl\""),
+        Err(KeyMapError::InvalidKey { .. })
+    ));
+}
+
+#[test]
+fn test_dirty_flag_transitions() {
+    let mut app = App::with_engine(Engine::new(16, 1));
+    // The first frame always draws
+    assert!(app.is_dirty());
+    app.frame_rendered();
+    assert!(!app.is_dirty());
+
+    // A static capture stays clean across ticks
+    app.tick();
+    app.frame_rendered();
+    app.tick();
+    assert!(!app.is_dirty());
+
+    // New events (and the selection moving onto them) need a redraw
+    app.engine_mut()
+        .inject_event(Category::Process, 0, Status::Success, 0);
+    app.tick();
+    assert!(app.is_dirty());
+    app.frame_rendered();
+
+    // Resizing and input force one regardless of the engine
+    app.resize(10);
+    assert!(app.is_dirty());
+    app.frame_rendered();
+    app.mark_dirty();
+    assert!(app.is_dirty());
+}
+
+#[test]
+fn test_notes_follow_event_id() {
+    let mut engine = Engine::new(16, 1);
+    let first = engine
+        .inject_event(Category::Process, 0, Status::Success, 0)
+        .unwrap();
+    let second = engine
+        .inject_event(Category::Network, 0, Status::Success, 0)
+        .unwrap();
+    let mut app = App::with_engine(engine);
+    app.tick();
+
+    // Typed through the overlay on the selected (first) event
+    app.begin_note();
+    for c in "dropper".chars() {
+        app.note_input(c);
+    }
+    app.note_backspace();
+    assert_eq!(app.note_draft(), Some((first, "droppe")));
+    app.note_input('r');
+    app.commit_note();
+    assert_eq!(app.note_draft(), None);
+    assert_eq!(app.note(first), Some("dropper"));
+
+    // Filtering the annotated row away and back keeps the note
+    app.set_note(second, "  c2 beacon ");
+    app.begin_search();
+    app.search_input('N');
+    app.commit_search();
+    assert_eq!(app.selected_note(), Some("c2 beacon"));
+    app.clear_search();
+    assert_eq!(app.note(first), Some("dropper"));
+
+    // Reopening prefills; cancelling keeps the old note; blank removes it
+    app.begin_note();
+    assert_eq!(app.note_draft().map(|(_, text)| text), app.selected_note());
+    app.note_input('!');
+    app.cancel_note();
+    assert!(!app.selected_note().unwrap_or_default().ends_with('!'));
+    app.set_note(first, "   ");
+    assert_eq!(app.note(first), None);
+}
+
+#[test]
+fn test_relative_timestamp_formatting() {
+    assert_eq!(format_relative(1_000, 1_000), "+0.000s");
+    assert_eq!(format_relative(1_250_000_000, 0), "+1.250s");
+    assert_eq!(
+        format_relative(5_000_000_000 + 61_999_999, 5_000_000_000),
+        "+0.061s"
+    );
+    assert_eq!(format_relative(3_600_000_000_000, 0), "+3600.000s");
+    // Events before the start do not go negative
+    assert_eq!(format_relative(10, 1_000), "+0.000s");
+
+    // 2024-01-01T13:05:09.250Z
+    assert_eq!(format_absolute(1_704_114_309_250_000_000), "13:05:09.250");
+}
+
+#[test]
+fn test_time_mode_toggle() {
+    assert_eq!(TimeMode::default(), TimeMode::Relative);
+    assert_eq!(TimeMode::Relative.toggled(), TimeMode::Absolute);
+    assert_eq!(TimeMode::Absolute.toggled(), TimeMode::Relative);
+
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+
+    // Without cached events an event is its own origin
+    let orphan = Event {
+        id: 9,
+        parent_id: 0,
+        timestamp: 42,
+        category: Category::Process,
+        status: Status::Success,
+        operation: 0,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    };
+    assert_eq!(app.event_time(&orphan), "+0.000s");
+
+    app.tick();
+    let first = *app.visible_events().next().unwrap();
+    assert_eq!(app.event_time(&first), "+0.000s");
+
+    app.toggle_time_mode();
+    assert_eq!(app.time_mode(), TimeMode::Absolute);
+    assert_eq!(app.event_time(&first).len(), "00:00:00.000".len());
+}
+
+#[test]
+fn test_quit_confirmation_state_machine() {
+    // Idle sessions quit straight away
+    assert_eq!(QuitState::Running.request(false), QuitState::Quit);
+
+    // An active capture asks first
+    let asking = QuitState::Running.request(true);
+    assert_eq!(asking, QuitState::Confirming);
+    assert_eq!(asking.request(true), QuitState::Confirming);
+    assert_eq!(asking.answer(false), QuitState::Running);
+    assert_eq!(asking.answer(true), QuitState::Quit);
+
+    // Answers outside the prompt are ignored
+    assert_eq!(QuitState::Running.answer(true), QuitState::Running);
+    assert_eq!(QuitState::Quit.request(true), QuitState::Quit);
+
+    let mut app = App::with_engine(Engine::new(16, 1));
+    assert_eq!(app.quit_state(), QuitState::Running);
+    app.request_quit();
+    assert_eq!(app.quit_state(), QuitState::Quit);
+}
+
+fn replay_snapshot() -> EventSnapshot {
+    // Events 0 ms, 100 ms and 1 s into the capture, stored out of order
+    let event = |id, timestamp| Event {
+        id,
+        parent_id: 0,
+        timestamp,
+        category: Category::Process,
+        status: Status::Success,
+        operation: 0,
+        target_pid: 0,
+        attributes: EventAttributes::empty(),
+    };
+    EventSnapshot::from_events(vec![
+        event(3, 5_000_000_000 + 1_000_000_000),
+        event(1, 5_000_000_000),
+        event(2, 5_000_000_000 + 100_000_000),
+    ])
+}
+
+#[test]
+fn test_replay_time_scaling() {
+    let mut replay = ReplayController::new(&replay_snapshot());
+    assert_eq!(replay.start(), Some(5_000_000_000));
+    assert_eq!(replay.speed(), 1);
+    let t0 = Instant::now();
+
+    // The first advance only anchors the clock; the first event is at offset 0
+    replay.advance(t0);
+    let ids =
+        |replay: &ReplayController| replay.revealed().iter().map(|e| e.id).collect::<Vec<_>>();
+    assert_eq!(ids(&replay), [1]);
+
+    replay.advance(t0 + Duration::from_millis(100));
+    assert_eq!(ids(&replay), [1, 2]);
+
+    // 2x: 300 ms wall time replays 600 ms, reaching 700 ms
+    replay.cycle_speed();
+    assert_eq!(replay.speed(), 2);
+    replay.advance(t0 + Duration::from_millis(400));
+    assert_eq!(ids(&replay), [1, 2]);
+
+    // 10x: 30 ms more replays 300 ms, passing 1 s
+    replay.cycle_speed();
+    assert_eq!(replay.speed(), 10);
+    replay.advance(t0 + Duration::from_millis(430));
+    assert_eq!(ids(&replay), [1, 2, 3]);
+
+    replay.cycle_speed();
+    assert_eq!(replay.speed(), REPLAY_SPEEDS[0]);
+}
+
+#[test]
+fn test_replay_pause_holds_clock() {
+    let mut replay = ReplayController::new(&replay_snapshot());
+    let t0 = Instant::now();
+    replay.advance(t0);
+
+    replay.toggle_play();
+    assert!(!replay.is_playing());
+    replay.advance(t0 + Duration::from_secs(5));
+    assert_eq!(replay.revealed().len(), 1);
+
+    // Resuming re-anchors, so the paused time is not replayed
+    replay.toggle_play();
+    replay.advance(t0 + Duration::from_secs(6));
+    replay.advance(t0 + Duration::from_secs(6) + Duration::from_millis(100));
+    assert_eq!(replay.revealed().len(), 2);
+
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+    app.start_replay(&replay_snapshot());
+    app.tick();
+    assert_eq!(app.visible_events().map(|e| e.id).collect::<Vec<_>>(), [1]);
+    assert!(app.replay().is_some_and(ReplayController::is_playing));
+
+    // Ending the replay brings the live events back
+    app.stop_replay();
+    assert!(app.replay().is_none());
+    assert_eq!(app.visible_events().count(), 2);
+    app.tick();
+    assert_eq!(app.visible_events().count(), 2);
+    assert!(
+        app.visible_events()
+            .all(|event| event.category == Category::Network)
+    );
+}
+
+#[test]
+fn test_tick_appends_new_and_drops_evicted_events() {
+    let mut engine = Engine::new(16, 1);
+    for _ in 0..3 {
+        engine.inject_event(Category::Process, 0, Status::Success, 0);
+    }
+    let mut app = App::with_engine(engine);
+    app.tick();
+    let ids = |app: &App| {
+        let mut ids: Vec<u64> = app.visible_events().map(|e| e.id).collect();
+        ids.sort_unstable();
+        ids
+    };
+    assert_eq!(ids(&app), [1, 2, 3]);
+
+    // The oldest goes while a new one arrives, leaving the count unchanged
+    app.engine_mut().drain_events().next();
+    app.engine_mut()
+        .inject_event(Category::Process, 0, Status::Success, 0);
+    app.tick();
+    assert_eq!(ids(&app), [2, 3, 4]);
+
+    app.engine_mut().clear_events_before(u64::MAX);
+    app.tick();
+    assert!(ids(&app).is_empty());
+}
+
+#[test]
+fn test_tick_merges_new_events_into_sorted_filtered_rows() {
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Registry, 0, Status::Denied, 0);
+    engine.inject_event(Category::Process, 0, Status::Denied, 0);
+    let mut app = App::with_engine(engine);
+    app.cycle_sort();
+    app.begin_search();
+    for c in "denied".chars() {
+        app.search_input(c);
+    }
+    app.commit_search();
+    app.tick();
+
+    // New rows land in category order among the old ones, filtered alike
+    let engine = app.engine_mut();
+    engine.inject_event(Category::Network, 0, Status::Denied, 0);
+    engine.inject_event(Category::FileSystem, 0, Status::Success, 0);
+    engine.inject_event(Category::Process, 0, Status::Denied, 0);
+    engine.drain_events().next();
+    app.tick();
+    let rows: Vec<u64> = app.visible_events().map(|event| event.id).collect();
+    let mut expected: Vec<Event> = app
+        .engine_mut()
+        .iter_events()
+        .filter(|event| event.status == Status::Denied)
+        .collect();
+    expected.sort_by(|a, b| SortMode::Category.compare(a, b));
+    let expected: Vec<u64> = expected.iter().map(|event| event.id).collect();
+    assert_eq!(rows, expected);
+    assert_eq!(rows.len(), 3);
+}
+
+#[test]
+fn test_risk_score_tracks_appended_and_evicted_events() {
+    use exeray_ffi::{DefaultClassifier, RiskWeights, risk::risk_score_classified};
+
+    let expected = |app: &mut App| {
+        let events: Vec<Event> = app.engine_mut().iter_events().collect();
+        risk_score_classified(&events, &RiskWeights::default(), &DefaultClassifier)
+    };
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Suspicious, 0);
+    engine.inject_event(Category::FileSystem, 0, Status::Denied, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+    assert_eq!(app.risk_score(), expected(&mut app));
+    assert!(app.risk_score() > 0);
+
+    app.engine_mut().drain_events().next();
+    app.engine_mut()
+        .inject_event(Category::Registry, 0, Status::Denied, 0);
+    app.tick();
+    assert_eq!(app.risk_score(), expected(&mut app));
+
+    app.engine_mut().clear_events_before(u64::MAX);
+    app.tick();
+    assert_eq!(app.risk_score(), 0);
+}
+
+#[test]
+fn test_suspicious_count_tracks_appended_and_evicted_events() {
+    let suspicious = |app: &App| app.counters_line().contains("Suspicious: 1 ");
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Process, 0, Status::Suspicious, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+    assert!(suspicious(&app));
+
+    app.engine_mut()
+        .inject_event(Category::Network, 0, Status::Suspicious, 0);
+    app.engine_mut().drain_events().next();
+    app.tick();
+    assert!(suspicious(&app));
+
+    app.engine_mut().clear_events_before(u64::MAX);
+    app.tick();
+    assert!(app.counters_line().contains("Suspicious: 0 "));
+}
+
+#[test]
+fn test_category_histogram_tracks_appended_and_evicted_events() {
+    let count = |app: &App, category: Category| {
+        app.category_histogram()
+            .into_iter()
+            .find(|&(c, _)| c == category)
+            .map_or(0, |(_, count)| count)
+    };
+    let mut engine = Engine::new(16, 1);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    engine.inject_event(Category::Network, 0, Status::Success, 0);
+    let mut app = App::with_engine(engine);
+    app.tick();
+    assert_eq!(app.category_histogram().len(), Category::ALL.len());
+    assert_eq!(count(&app, Category::Network), 2);
+
+    app.engine_mut().drain_events().next();
+    app.engine_mut()
+        .inject_event(Category::Registry, 0, Status::Success, 0);
+    app.tick();
+    assert_eq!(count(&app, Category::Network), 1);
+    assert_eq!(count(&app, Category::Registry), 1);
+
+    app.engine_mut().clear_events_before(u64::MAX);
+    app.tick();
+    assert!(
+        app.category_histogram()
+            .iter()
+            .all(|&(_, count)| count == 0)
+    );
+}
+
+#[test]
+fn test_replay_absolute_time_uses_snapshot_clock() {
+    use exeray_ffi::{SnapshotMetadata, TimestampEpoch};
+
+    // Captured on a clock 5 s behind Unix time; the live engine differs
+    let snapshot = replay_snapshot().with_metadata(SnapshotMetadata {
+        timestamp_epoch: Some(TimestampEpoch::QpcBootRelative),
+        unix_offset_ns: 1_704_114_304_000_000_000,
+        ..SnapshotMetadata::default()
+    });
+    let mut app = App::with_engine(Engine::new(16, 1));
+    app.start_replay(&snapshot);
+    app.toggle_time_mode();
+    app.tick();
+    let first = *app.visible_events().next().unwrap();
+    // 2024-01-01T13:05:09Z, the snapshot's first event
+    assert_eq!(app.event_time(&first), "13:05:09.000");
+
+    // Without a recorded clock the replay shows relative times
+    app.start_replay(&replay_snapshot());
+    app.tick();
+    let first = *app.visible_events().next().unwrap();
+    assert_eq!(app.event_time(&first), "+0.000s");
+}
+
+#[test]
+fn test_cli_options() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+
+    assert_eq!(parse(&[]), Ok(Options::default()));
+    assert_eq!(
+        parse(&["--freeze-on-start", "malware.exe"]),
+        Ok(Options {
+            freeze_on_start: true,
+            target: Some("malware.exe".to_string()),
+            replay: None,
+        })
+    );
+    assert_eq!(
+        parse(&["--replay", "old.toml"]),
+        Ok(Options {
+            replay: Some("old.toml".to_string()),
+            ..Options::default()
+        })
+    );
+    assert!(parse(&["--replay"]).is_err());
+    assert!(parse(&["--bogus"]).is_err());
+    assert!(parse(&["a.exe", "b.exe"]).is_err());
+}
+
+#[test]
+fn test_monitor_failure_is_not_frozen_at_entry() {
+    let engine = Engine::builder().arena_mb(16).freeze_on_start(true).build();
+    let mut app = App::with_engine(engine);
+
+    app.monitor("nonexistent.exe");
+    assert!(app.notification().is_some_and(|n| n.is_error));
+    assert!(!app.frozen_at_entry());
+}
+
+#[test]
+fn test_exit_notification_separates_kills_from_errors() {
+    let mut engine = Engine::new(16, 1);
+    engine.simulate_target_exit(7, 3);
+    let mut app = App::with_engine(engine);
+    app.tick();
+    let notification = app.notification().unwrap();
+    assert_eq!(notification.message, "Target 7 exited with code 3");
+    assert!(notification.is_error);
+
+    let mut engine = Engine::new(16, 1);
+    engine.kill_target();
+    engine.simulate_target_exit(7, 1);
+    let mut app = App::with_engine(engine);
+    app.tick();
+    let notification = app.notification().unwrap();
+    assert_eq!(notification.message, "Target 7 killed");
+    assert!(!notification.is_error);
+}
@@ -0,0 +1,97 @@
+//! Per-category event list colors loaded from `theme.json`.
+
+use exeray_ffi::Category;
+use ratatui::style::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Theme file looked up next to the binary.
+pub const THEME_FILE: &str = "theme.json";
+
+/// Per-category colors for the event list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryTheme {
+    colors: [Color; Category::ALL.len()],
+}
+
+impl Default for CategoryTheme {
+    fn default() -> Self {
+        Self {
+            colors: Category::ALL.map(default_color),
+        }
+    }
+}
+
+fn default_color(category: Category) -> Color {
+    match category {
+        Category::FileSystem => Color::Blue,
+        Category::Registry => Color::Magenta,
+        Category::Network => Color::Cyan,
+        Category::Process => Color::Green,
+        Category::Image | Category::Clr => Color::LightBlue,
+        Category::Thread | Category::Memory => Color::Gray,
+        Category::Script | Category::Amsi => Color::Yellow,
+        Category::Dns => Color::LightCyan,
+        Category::Security | Category::Service => Color::LightRed,
+        _ => Color::White,
+    }
+}
+
+impl CategoryTheme {
+    /// Color for events of `category`.
+    pub fn color(&self, category: Category) -> Color {
+        self.colors
+            .get(usize::from(category.repr))
+            .copied()
+            .unwrap_or(Color::White)
+    }
+
+    /// Parse a theme from a JSON object of category names to `#rrggbb` colors.
+    ///
+    /// Missing categories keep their default color. Unknown names and
+    /// malformed colors are skipped and reported as warnings.
+    pub fn from_json(text: &str) -> Result<(Self, Vec<String>), serde_json::Error> {
+        let entries: HashMap<String, String> = serde_json::from_str(text)?;
+        let mut theme = Self::default();
+        let mut warnings = Vec::new();
+
+        for (name, hex) in entries {
+            let Some(category) = Category::from_name(&name) else {
+                warnings.push(format!("theme: unknown category \"{name}\""));
+                continue;
+            };
+            let Some(color) = parse_hex(&hex) else {
+                warnings.push(format!("theme: invalid color \"{hex}\" for {name}"));
+                continue;
+            };
+            theme.colors[usize::from(category.repr)] = color;
+        }
+
+        Ok((theme, warnings))
+    }
+
+    /// Load a theme file, falling back to defaults if it is absent.
+    ///
+    /// Parse failures also fall back to defaults and are returned as a warning.
+    pub fn load(path: &Path) -> (Self, Vec<String>) {
+        let Ok(text) = std::fs::read_to_string(path) else {
+            return (Self::default(), Vec::new());
+        };
+        Self::from_json(&text).unwrap_or_else(|err| {
+            (
+                Self::default(),
+                vec![format!("theme: failed to parse {}: {err}", path.display())],
+            )
+        })
+    }
+}
+
+/// Parse `#rrggbb` into an RGB color.
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits = hex.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+    Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
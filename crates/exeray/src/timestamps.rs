@@ -0,0 +1,56 @@
+//! Timestamp column of the event list.
+
+const NANOS_PER_MILLI: u64 = 1_000_000;
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const SECS_PER_DAY: u64 = 86_400;
+
+/// How the timestamp column is shown, toggled with `t`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeMode {
+    /// Offset from the first captured event.
+    #[default]
+    Relative,
+    /// Wall-clock time of day (UTC).
+    Absolute,
+}
+
+impl TimeMode {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Relative => Self::Absolute,
+            Self::Absolute => Self::Relative,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Relative => "rel",
+            Self::Absolute => "abs",
+        }
+    }
+}
+
+/// Format `ts` as an offset from `start`, e.g. `+1.250s`.
+///
+/// Both are raw timestamps on the same clock, in nanoseconds. Timestamps
+/// before `start` show as `+0.000s`.
+pub fn format_relative(ts: u64, start: u64) -> String {
+    let delta = ts.saturating_sub(start);
+    format!(
+        "+{}.{:03}s",
+        delta / NANOS_PER_SEC,
+        delta % NANOS_PER_SEC / NANOS_PER_MILLI
+    )
+}
+
+/// Format Unix nanoseconds as a UTC time of day, e.g. `13:05:09.250`.
+pub fn format_absolute(unix_ns: u64) -> String {
+    let secs = unix_ns / NANOS_PER_SEC % SECS_PER_DAY;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        unix_ns % NANOS_PER_SEC / NANOS_PER_MILLI
+    )
+}
@@ -1,71 +1,354 @@
-use crate::app::App;
-use exeray_ffi::ViewState;
+use crate::app::{App, EngineState, FooterMode, QuitState, event_row, risk_color};
+use crate::keymap::{Action, KeyMap};
+use crate::scrubber;
+use crate::search;
+use crate::theme::CategoryTheme;
+use exeray_ffi::{Category, Severity};
 use ratatui::{
+    layout::Flex,
     prelude::*,
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{
+        Bar, BarChart, BarGroup, Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph,
+    },
 };
 
-pub fn render(app: &App, frame: &mut Frame) {
+/// Rows above and below the event list: layout margins, the fixed-height
+/// widgets and the list's own borders.
+const EVENT_LIST_CHROME: u16 = 2 * 2 + 3 + 3 + 3 + 3 + 1 + 2;
+
+/// Prefix of annotated rows and of the selected event's note.
+const NOTE_MARKER: &str = "✎ ";
+
+/// Width of the note editor overlay.
+const NOTE_EDITOR_WIDTH: u16 = 60;
+
+/// Width of the category breakdown panel beside the event list.
+const CHART_WIDTH: u16 = 30;
+
+/// Event list rows available in a terminal `height` rows tall.
+pub fn event_rows(height: u16) -> usize {
+    usize::from(height.saturating_sub(EVENT_LIST_CHROME))
+}
+
+pub fn render(app: &App, theme: &CategoryTheme, keys: &KeyMap, frame: &mut Frame) {
     let layout = Layout::vertical([
         Constraint::Length(3),
         Constraint::Length(3),
-        Constraint::Min(1),
+        Constraint::Length(3),
+        Constraint::Min(3),
+        Constraint::Length(3),
         Constraint::Length(1),
     ])
     .margin(2)
     .split(frame.area());
 
     header(app, frame, layout[0]);
-    progress(app.state(), frame, layout[1]);
-    status(app.state(), frame, layout[2]);
-    help(frame, layout[3]);
+    progress(app.engine_state(), app.progress(), frame, layout[1]);
+    status(app.engine_state(), app.selected_note(), frame, layout[2]);
+    if app.chart_visible() {
+        let [list, chart] =
+            Layout::horizontal([Constraint::Min(20), Constraint::Length(CHART_WIDTH)])
+                .areas(layout[3]);
+        events(app, theme, frame, list);
+        categories(app, theme, frame, chart);
+    } else {
+        events(app, theme, frame, layout[3]);
+    }
+    timeline(app, frame, layout[4]);
+    help(app, keys, frame, layout[5]);
+    if let Some((id, text)) = app.note_draft() {
+        note_editor(id, text, frame);
+    }
+    if app.quit_state() == QuitState::Confirming {
+        quit_prompt(frame);
+    }
+}
+
+fn events(app: &App, theme: &CategoryTheme, frame: &mut Frame, area: Rect) {
+    // Only the rows on screen are built; the list scrolls through the app
+    let offset = app.scroll_offset();
+    let rows = usize::from(area.height.saturating_sub(2));
+    let items: Vec<ListItem> = app
+        .visible_window(offset, rows)
+        .map(|event| {
+            let mut row = event_row(event);
+            if app.note(event.id).is_some() {
+                row.insert_str(0, NOTE_MARKER);
+            }
+            if let Some(parent) = app.parent_category(event) {
+                row.push_str(" · child of ");
+                row.push_str(parent.name());
+            }
+            let mut style = Style::default().fg(theme.color(event.category));
+            if app.severity(event) == Severity::High {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            let mut line = highlight_row(&row, app.search().query(), style);
+            line.spans.insert(
+                0,
+                Span::styled(
+                    format!("{} ", app.event_time(event)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            );
+            ListItem::new(line)
+        })
+        .collect();
+
+    let search = app.search();
+    let title = if search.is_editing() {
+        format!("Events │ /{}▏", search.query())
+    } else if !search.query().is_empty() {
+        format!("Events │ /{}", search.query())
+    } else {
+        "Events".to_string()
+    };
+    let title = if app.following() {
+        format!("{title} │ Following")
+    } else {
+        title
+    };
+
+    let selected = app
+        .selected()
+        .checked_sub(offset)
+        .filter(|&row| row < items.len());
+    let mut state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+        area,
+        &mut state,
+    );
+}
+
+/// Split a row into spans with the matches of `query` emphasized.
+///
+/// Matches are bold and underlined on top of `style`; a row without a match
+/// is dimmed. An empty query leaves the row as a single plain span.
+pub fn highlight_row(row: &str, query: &str, style: Style) -> Line<'static> {
+    let ranges = search::match_ranges(row, query);
+    if query.is_empty() {
+        return Line::from(Span::styled(row.to_string(), style));
+    }
+    if ranges.is_empty() {
+        return Line::from(Span::styled(
+            row.to_string(),
+            style.add_modifier(Modifier::DIM),
+        ));
+    }
+
+    let matched = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::with_capacity(ranges.len() * 2 + 1);
+    let mut at = 0;
+    for range in ranges {
+        if range.start > at {
+            spans.push(Span::styled(row[at..range.start].to_string(), style));
+        }
+        spans.push(Span::styled(row[range.clone()].to_string(), matched));
+        at = range.end;
+    }
+    if at < row.len() {
+        spans.push(Span::styled(row[at..].to_string(), style));
+    }
+    Line::from(spans)
+}
+
+/// Bars for the category breakdown: categories with events, as
+/// `(category, count)`, in histogram order. Empty when nothing was captured.
+pub fn category_bars(histogram: &[(Category, usize)]) -> Vec<(Category, u64)> {
+    histogram
+        .iter()
+        .filter(|&&(_, count)| count > 0)
+        .map(|&(category, count)| (category, count as u64))
+        .collect()
+}
+
+fn categories(app: &App, theme: &CategoryTheme, frame: &mut Frame, area: Rect) {
+    let bars: Vec<Bar> = category_bars(&app.category_histogram())
+        .into_iter()
+        .map(|(category, count)| {
+            Bar::default()
+                .label(Line::from(category.name()))
+                .value(count)
+                .style(Style::default().fg(theme.color(category)))
+        })
+        .collect();
+
+    frame.render_widget(
+        BarChart::default()
+            .block(Block::default().borders(Borders::ALL).title("Categories"))
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .data(BarGroup::default().bars(&bars)),
+        area,
+    );
+}
+
+fn timeline(app: &App, frame: &mut Frame, area: Rect) {
+    let block = Block::default().borders(Borders::ALL);
+    let width = block.inner(area).width;
+
+    let (bar, title) = match app.time_range() {
+        Some((min, max)) if width > 0 => {
+            let mut bar = vec!['─'; width as usize];
+            for event in app.visible_events() {
+                bar[scrubber::timestamp_to_column(event.timestamp, min, max, width) as usize] = '┼';
+            }
+            let cursor = app.scrub_cursor().unwrap_or(min).clamp(min, max);
+            bar[scrubber::timestamp_to_column(cursor, min, max, width) as usize] = '┃';
+            let offset_ms = (cursor - min) as f64 / 1e6;
+            (
+                bar.into_iter().collect(),
+                format!("Timeline │ +{offset_ms:.3} ms"),
+            )
+        }
+        _ => (String::new(), "Timeline".to_string()),
+    };
+
+    frame.render_widget(
+        Paragraph::new(bar)
+            .block(block.title(title))
+            .style(Style::default().fg(Color::Magenta)),
+        area,
+    );
 }
 
 fn header(app: &App, frame: &mut Frame, area: Rect) {
-    let text = format!(
-        "ExeRay │ Gen: {} │ Threads: {}",
+    let mut text = format!(
+        "ExeRay │ Gen: {} ({:.1}/s) │ Threads: {}",
         app.state().generation,
+        app.generation_rate(),
         app.threads()
     );
+    if app.frozen_at_entry() {
+        text.push_str(" │ ❄ Frozen at entry");
+    }
+    if app.elevated_target() {
+        text.push_str(" │ ⚠ Elevated target");
+    }
+    let score = app.risk_score();
 
     frame.render_widget(
-        Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("Engine"))
-            .style(Style::default().fg(Color::Cyan)),
+        Paragraph::new(Line::from(vec![
+            Span::raw(text),
+            Span::raw(" │ Risk: "),
+            Span::styled(score.to_string(), Style::default().fg(risk_color(score))),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("Engine"))
+        .style(Style::default().fg(Color::Cyan)),
         area,
     );
 }
 
-fn progress(state: &ViewState, frame: &mut Frame, area: Rect) {
+fn progress(state: EngineState, progress: f32, frame: &mut Frame, area: Rect) {
     frame.render_widget(
         Gauge::default()
             .block(Block::default().borders(Borders::ALL).title("Progress"))
-            .gauge_style(Style::default().fg(Color::Green))
-            .percent((state.progress * 100.0).min(100.0) as u16),
+            .gauge_style(Style::default().fg(state.color()))
+            .percent((progress * 100.0).min(100.0) as u16),
         area,
     );
 }
 
-fn status(state: &ViewState, frame: &mut Frame, area: Rect) {
-    let (text, color) = if state.is_complete() {
-        ("Complete", Color::Green)
-    } else if state.is_pending() {
-        ("Running", Color::Yellow)
-    } else {
-        ("Idle", Color::DarkGray)
-    };
+/// Centered modal asking whether to end an active capture.
+fn quit_prompt(frame: &mut Frame) {
+    let [area] = Layout::horizontal([Constraint::Length(NOTE_EDITOR_WIDTH)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(3)])
+        .flex(Flex::Center)
+        .areas(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new("Stop monitoring and kill the target? (y/n)")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Capture active"),
+            )
+            .style(Style::default().fg(Color::Red)),
+        area,
+    );
+}
 
+/// Centered single-line input box for the selected event's note.
+fn note_editor(id: u64, text: &str, frame: &mut Frame) {
+    let [area] = Layout::horizontal([Constraint::Length(NOTE_EDITOR_WIDTH)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(3)])
+        .flex(Flex::Center)
+        .areas(area);
+    frame.render_widget(Clear, area);
     frame.render_widget(
-        Paragraph::new(text)
+        Paragraph::new(format!("{text}▏")).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Note for #{id} │ Enter: Save │ Esc: Cancel")),
+        ),
+        area,
+    );
+}
+
+fn status(state: EngineState, note: Option<&str>, frame: &mut Frame, area: Rect) {
+    let mut spans = vec![Span::raw(state.label())];
+    if let Some(note) = note {
+        spans.push(Span::raw(" │ "));
+        spans.push(Span::styled(
+            format!("{NOTE_MARKER}{note}"),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans))
             .block(Block::default().borders(Borders::ALL).title("Status"))
-            .style(Style::default().fg(color)),
+            .style(Style::default().fg(state.color())),
         area,
     );
 }
 
-fn help(frame: &mut Frame, area: Rect) {
+fn help(app: &App, keys: &KeyMap, frame: &mut Frame, area: Rect) {
+    if let Some(notification) = app.notification() {
+        let color = if notification.is_error {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        frame.render_widget(
+            Paragraph::new(notification.message.as_str()).style(Style::default().fg(color)),
+            area,
+        );
+        return;
+    }
+
+    let replay = match app.replay() {
+        Some(replay) => format!(
+            "P: {} │ X: Speed ({}x) │ Esc: End replay",
+            if replay.is_playing() { "Pause" } else { "Play" },
+            replay.speed()
+        ),
+        None => "R: Replay".to_string(),
+    };
+    let text = match app.footer_mode() {
+        FooterMode::Counters => app.counters_line(),
+        FooterMode::Help => format!(
+            "{}: Start │ {}: Search │ {}: Freeze │ {}: Kill │ E: Export │ Y: Copy │ S: Sort ({}) │ I: Counters │ C: Chart │ G: Follow │ N: Note │ T: Time ({}) │ {replay} │ ↑↓: Select │ ←→: Scrub │ {}: Quit │ {:.1} FPS",
+            keys.label(Action::Start),
+            keys.label(Action::Search),
+            keys.label(Action::Freeze),
+            keys.label(Action::Kill),
+            app.sort_mode().label(),
+            app.time_mode().label(),
+            keys.label(Action::Quit),
+            app.fps()
+        ),
+    };
+
     frame.render_widget(
-        Paragraph::new("Space: Start │ Q: Quit").style(Style::default().fg(Color::DarkGray)),
+        Paragraph::new(text).style(Style::default().fg(Color::DarkGray)),
         area,
     );
 }